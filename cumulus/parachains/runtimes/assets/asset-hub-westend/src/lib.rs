@@ -1199,7 +1199,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_revive::migrations::v1::Migration<Runtime>;
+	type Migrations = (
+		pallet_revive::migrations::v1::Migration<Runtime>,
+		pallet_staking_async::migrations::v20::LazyMigrationV19ToV20<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1453,6 +1456,14 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	// unreleased
+	pallet_staking_async::migrations::v18::MigrateV17ToV18<Runtime>,
+	pallet_staking_async::migrations::v19::MigrateV18ToV19<Runtime>,
+	pallet_staking_async::migrations::v20::MigrateV19ToV20<Runtime>,
+	pallet_staking_async::migrations::v21::MigrateV20ToV21<Runtime>,
+	pallet_staking_async::migrations::v22::MigrateV21ToV22<Runtime>,
+	pallet_staking_async::migrations::v23::MigrateV22ToV23<Runtime>,
+	pallet_staking_async_rc_client::migrations::v2::MigrateV1ToV2<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
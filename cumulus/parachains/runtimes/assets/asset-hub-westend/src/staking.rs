@@ -36,6 +36,12 @@ parameter_types! {
 
 	/// Maximum number of validators that we may want to elect. 1000 is the end target.
 	pub const MaxValidatorSet: u32 = 1000;
+	/// What to do if an election yields too few validators. With `MinimumValidatorCount` of 0,
+	/// this never applies.
+	pub const UnderSubscribedBehavior: pallet_staking_async::UnderSubscribedBehavior =
+		pallet_staking_async::UnderSubscribedBehavior::UseWhatWeGot;
+	pub const DeadPayeeBehavior: pallet_staking_async::DeadPayeeBehavior =
+		pallet_staking_async::DeadPayeeBehavior::Burn;
 
 	/// Number of nominators per page of the snapshot, and consequently number of backers in the solution.
 	pub VoterSnapshotPerBlock: u32 = MaxElectingVoters::get() / Pages::get();
@@ -278,20 +284,41 @@ impl pallet_staking_async::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type MaxSlashesPerBlock = ConstU32<1>;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
 	type EraPayout = EraPayout;
 	type MaxExposurePageSize = MaxExposurePageSize;
+	type MaxBackersExposed = ConstU32<{ u32::MAX }>;
 	type ElectionProvider = MultiBlockElection;
 	type VoterList = VoterList;
 	type TargetList = UseValidatorsMap<Self>;
 	type MaxValidatorSet = MaxValidatorSet;
+	type MinimumValidatorCount = frame_support::traits::ConstU32<0>;
+	type UnderSubscribedBehavior = UnderSubscribedBehavior;
+	type DeadPayeeBehavior = DeadPayeeBehavior;
 	type NominationsQuota = pallet_staking_async::FixedNominationsQuota<{ MaxNominations::get() }>;
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
+	type UnlockingChunkMergeWindow = frame_support::traits::ConstU32<0>;
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type MaxKicks = frame_support::traits::ConstU32<128>;
+	type MaxBlockedUpdates = frame_support::traits::ConstU32<64>;
 	type EventListeners = (NominationPools, DelegatedStaking);
 	type WeightInfo = weights::pallet_staking_async::WeightInfo<Runtime>;
 	type MaxInvulnerables = frame_support::traits::ConstU32<20>;
+	type AutoPayoutPerBlock = frame_support::traits::ConstU32<0>;
+	type PostSlashDustThreshold = ();
+	type MaxSlashReward = ();
+	type MaxReporters = frame_support::traits::ConstU32<16>;
+	type AllowAutoCompoundUnlocking = frame_support::traits::ConstBool<true>;
+	type SuppressSlashedNominations = frame_support::traits::ConstBool<false>;
+	type MaxRecentRewards = ConstU32<16>;
+	type RewardPointsCarryOver = ();
+	type SkipZeroRewardEras = frame_support::traits::ConstBool<false>;
+	type MaxNominatorsPerValidator = frame_support::traits::ConstU32<0>;
+	type AutoPayoutExpiring = frame_support::traits::ConstU32<0>;
+	type RoleChangeCooldown = frame_support::traits::ConstU32<0>;
+	type MinEraPointsForReward = frame_support::traits::ConstU32<0>;
 	type PlanningEraOffset =
 		pallet_staking_async::PlanningEraOffsetOf<Runtime, RelaySessionDuration, ConstU32<5>>;
 	type RcClientInterface = StakingRcClient;
@@ -302,6 +329,9 @@ impl pallet_staking_async_rc_client::Config for Runtime {
 	type RelayChainOrigin = EnsureRoot<AccountId>;
 	type AHStakingInterface = Staking;
 	type SendToRelayChain = StakingXcmToRelayChain;
+	type CompressSessionReports = ConstBool<false>;
+	// must match the relay chain's `MaxSkippedSessionReports`.
+	type MaxSkippedSessionReports = ConstU32<0>;
 }
 
 #[derive(Encode, Decode)]
@@ -354,7 +384,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			RelayLocation,
 			rc_client::ValidatorSetReport<Self::AccountId>,
 			ValidatorSetToXcm,
-		>::split_then_send(report, Some(8));
+		>::split_then_send(report, Some(8), None);
 	}
 }
 
@@ -531,6 +531,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = session_historical::NoteHistoricalRoot<Self, StakingAhClient>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	// Not `UpToStakeLimitDisablingStrategy`: stakes live on AssetHub, not here.
 	type DisablingStrategy = pallet_session::disabling::UpToLimitWithReEnablingDisablingStrategy;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
@@ -791,10 +792,14 @@ enum AssetHubRuntimePallets<AccountId> {
 
 #[derive(Encode, Decode)]
 enum RcClientCalls<AccountId> {
-	#[codec(index = 0)]
+	// Audit: only accepted by asset-hub-westend once it is upgraded, see
+	// `rc_client::migrations::v2`.
+	#[codec(index = 3)]
 	RelaySessionReport(rc_client::SessionReport<AccountId>),
 	#[codec(index = 1)]
 	RelayNewOffence(SessionIndex, Vec<rc_client::Offence<AccountId>>),
+	#[codec(index = 2)]
+	RelayCompressedSessionReport(rc_client::CompressedSessionReport<AccountId>),
 }
 
 pub struct AssetHubLocation;
@@ -843,17 +848,61 @@ impl sp_runtime::traits::Convert<rc_client::SessionReport<AccountId>, Xcm<()>>
 	}
 }
 
+pub struct CompressedSessionReportToXcm;
+impl sp_runtime::traits::Convert<rc_client::CompressedSessionReport<AccountId>, Xcm<()>>
+	for CompressedSessionReportToXcm
+{
+	fn convert(a: rc_client::CompressedSessionReport<AccountId>) -> Xcm<()> {
+		Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			Instruction::Transact {
+				origin_kind: OriginKind::Superuser,
+				fallback_max_weight: None,
+				call: AssetHubRuntimePallets::RcClient(
+					RcClientCalls::RelayCompressedSessionReport(a),
+				)
+				.encode()
+				.into(),
+			},
+		])
+	}
+}
+
 pub struct StakingXcmToAssetHub;
 impl ah_client::SendToAssetHub for StakingXcmToAssetHub {
 	type AccountId = AccountId;
 
-	fn relay_session_report(session_report: rc_client::SessionReport<Self::AccountId>) {
+	fn relay_session_report(
+		session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
 		rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			rc_client::SessionReport<AccountId>,
 			SessionReportToXcm,
-		>::split_then_send(session_report, Some(8));
+		>::split_then_send(
+			session_report,
+			Some(8),
+			Some(<Runtime as ah_client::Config>::MaxXcmMessageBytes::get()),
+		)
+	}
+
+	fn relay_compressed_session_report(
+		session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		rc_client::XCMSender::<
+			xcm_config::XcmRouter,
+			AssetHubLocation,
+			rc_client::CompressedSessionReport<AccountId>,
+			CompressedSessionReportToXcm,
+		>::split_then_send(
+			session_report,
+			Some(8),
+			Some(<Runtime as ah_client::Config>::MaxXcmMessageBytes::get()),
+		)
 	}
 
 	fn relay_new_offence(
@@ -892,7 +941,16 @@ impl ah_client::Config for Runtime {
 	type MinimumValidatorSetSize = ConstU32<1>;
 	type UnixTime = Timestamp;
 	type PointsPerBlock = ConstU32<20>;
+	type ExtraRewardPoints = ();
+	type MaxExtraPointsPerBlock = ConstU32<0>;
 	type MaxOffenceBatchSize = ConstU32<50>;
+	type MinOffenceDeliveryInterval = ConstU32<0>;
+	type AggregateOffences = ConstBool<false>;
+	type MaxXcmMessageBytes = ConstU32<{ 50 * 1024 }>;
+	type AlwaysReport = ConstBool<true>;
+	type MaxSkippedSessionReports = ConstU32<0>;
+	// AssetHub does not accept compressed session reports yet.
+	type CompressSessionReports = ConstBool<false>;
 	type Fallback = Staking;
 	type WeightInfo = ah_client::weights::SubstrateWeight<Runtime>;
 }
@@ -88,11 +88,8 @@ impl<Offender: Clone> Offence<Offender> for EquivocationOffence<Offender> {
 		self.slot
 	}
 
-	// The formula is min((3k / n)^2, 1)
-	// where k = offenders_number and n = validators_number
 	fn slash_fraction(&self, offenders_count: u32) -> Perbill {
-		// Perbill type domain is [0, 1] by definition
-		Perbill::from_rational(3 * offenders_count, self.validator_set_count).square()
+		sp_staking::offence::equivocation_slash_fraction(offenders_count, self.validator_set_count)
 	}
 }
 
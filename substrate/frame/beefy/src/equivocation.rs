@@ -113,10 +113,7 @@ where
 			return slash_fraction;
 		}
 
-		// `Perbill` type domain is [0, 1] by definition
-		// The formula is min((3k / n)^2, 1)
-		// where k = offenders_number and n = validators_number
-		Perbill::from_rational(3 * offenders_count, self.validator_set_count).square()
+		sp_staking::offence::equivocation_slash_fraction(offenders_count, self.validator_set_count)
 	}
 }
 
@@ -1206,6 +1206,24 @@ impl<T: Config> Pallet<T> {
 		(Self::lsp()..Self::msp() + 1).rev().take(length).rev().collect::<Vec<_>>()
 	}
 
+	/// Return the progress of the snapshot of the ongoing election, as `(pages_done,
+	/// total_pages)`.
+	///
+	/// The snapshot consists of one target page and [`Config::Pages`] voter pages. Returns `None`
+	/// if no election is ongoing.
+	pub fn snapshot_progress() -> Option<(PageIndex, PageIndex)> {
+		if Self::current_phase().is_off() {
+			return None
+		}
+
+		let round = Self::round();
+		let targets_done = PagedTargetSnapshot::<T>::contains_key(round, Self::msp()) as PageIndex;
+		let voters_done = (Self::lsp()..=Self::msp())
+			.filter(|page| PagedVoterSnapshot::<T>::contains_key(round, page))
+			.count() as PageIndex;
+		Some((targets_done + voters_done, T::Pages::get().saturating_add(1)))
+	}
+
 	pub(crate) fn phase_transition(to: Phase<T>) {
 		let from = Self::current_phase();
 		use sp_std::mem::discriminant;
@@ -1841,6 +1859,39 @@ mod phase_rotation {
 			})
 	}
 
+	#[test]
+	fn snapshot_progress_works() {
+		ExtBuilder::full()
+			.pages(2)
+			.fallback_mode(FallbackModes::Onchain)
+			.election_start(12)
+			.build_and_execute(|| {
+				// no election ongoing.
+				roll_to(11);
+				assert_eq!(MultiBlock::snapshot_progress(), None);
+
+				// targets first, then voters, one page per block.
+				roll_to(12);
+				assert_eq!(MultiBlock::current_phase(), Phase::Snapshot(2));
+				assert_eq!(MultiBlock::snapshot_progress(), Some((0, 3)));
+
+				roll_to(13);
+				assert_eq!(MultiBlock::snapshot_progress(), Some((1, 3)));
+
+				roll_to(14);
+				assert_eq!(MultiBlock::snapshot_progress(), Some((2, 3)));
+
+				roll_to(15);
+				assert!(matches!(MultiBlock::current_phase(), Phase::Signed(_)));
+				assert_eq!(MultiBlock::snapshot_progress(), Some((3, 3)));
+
+				// snapshot is gone once the election is over.
+				roll_to_done();
+				MultiBlock::elect(0).unwrap();
+				assert_eq!(MultiBlock::snapshot_progress(), None);
+			})
+	}
+
 	#[test]
 	fn multi_page_3() {
 		ExtBuilder::full()
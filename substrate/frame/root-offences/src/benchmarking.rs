@@ -0,0 +1,75 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Root offences pallet benchmarking.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_staking::{
+	testing_utils::create_stash_controller, ActiveEra, ActiveEraInfo, BondedEras, EraInfo,
+	Pallet as Staking, RewardDestination,
+};
+use sp_staking::{Exposure, IndividualExposure, StakingAccount};
+
+fn active_stake<T: Config>(stash: &T::AccountId) -> pallet_staking::BalanceOf<T> {
+	Staking::<T>::ledger(StakingAccount::Stash(stash.clone()))
+		.map(|l| l.active)
+		.unwrap_or_default()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn root_slash_in_era(
+		n: Linear<0, { <T as pallet_staking::Config>::MaxExposurePageSize::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (validator, _) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
+		let own = active_stake::<T>(&validator);
+		let mut exposure = Exposure { total: own, own, others: Vec::new() };
+		for i in 0..n {
+			let (nominator, _) =
+				create_stash_controller::<T>(i + 1, 100, RewardDestination::Staked)?;
+			let value = active_stake::<T>(&nominator);
+			exposure.total += value;
+			exposure.others.push(IndividualExposure { who: nominator, value });
+		}
+
+		EraInfo::<T>::set_exposure(0, &validator, exposure);
+		BondedEras::<T>::put(vec![(0, 0)]);
+		ActiveEra::<T>::put(ActiveEraInfo { index: 0, start: None });
+
+		let offenders = vec![(validator.clone(), Perbill::from_percent(10))];
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, offenders.clone());
+
+		let event: <T as Config>::RuntimeEvent =
+			Event::OffenceCreatedInEra { era: 0, offenders }.into();
+		frame_system::Pallet::<T>::assert_last_event(event.into());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Test
+	);
+}
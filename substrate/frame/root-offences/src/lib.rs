@@ -22,25 +22,32 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod weights;
 
 extern crate alloc;
 
 use alloc::vec::Vec;
 pub use pallet::*;
 use pallet_session::historical::IdentificationTuple;
-use sp_runtime::{traits::Convert, Perbill};
-use sp_staking::offence::OnOffenceHandler;
+use sp_runtime::{
+	traits::{Convert, Saturating, Zero},
+	Perbill,
+};
+use sp_staking::offence::{self, OnOffenceHandler};
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_staking::SessionIndex;
+	use sp_staking::{EraIndex, SessionIndex};
 
 	#[pallet::config]
 	pub trait Config:
@@ -53,6 +60,8 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The offence handler provided by the runtime.
 		type OffenceHandler: OnOffenceHandler<Self::AccountId, IdentificationTuple<Self>, Weight>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -63,12 +72,20 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An offence was created by root.
 		OffenceCreated { offenders: Vec<(T::AccountId, Perbill)> },
+		/// An offence committed in `era` was created by root.
+		OffenceCreatedInEra { era: EraIndex, offenders: Vec<(T::AccountId, Perbill)> },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Failed to get the active era from the staking pallet.
 		FailedToGetActiveEra,
+		/// The era is in the future, or older than `HistoryDepth`.
+		EraOutsideHistoryDepth,
+		/// The era is no longer within the bonding period, and can thus not be slashed.
+		EraNotBonded,
+		/// An offender was not exposed as a validator in the given era.
+		NotExposedInEra,
 	}
 
 	type OffenceDetails<T> = sp_staking::offence::OffenceDetails<
@@ -116,6 +133,65 @@ pub mod pallet {
 			Self::deposit_event(Event::OffenceCreated { offenders });
 			Ok(())
 		}
+
+		/// Allows the `root` to slash `offenders` for an offence committed in `era`.
+		///
+		/// The slashes are computed against the exposure of each offender in `era`, which must be
+		/// within `HistoryDepth` and the bonding period. They are handed to the staking pallet
+		/// directly, as the session identification of past validators may no longer be available.
+		///
+		/// The weight assumes each offender has a full exposure page; the actual weight refunds
+		/// the exposure pages that were not read and what slashing took.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::root_slash_in_era(
+				<T as pallet_staking::Config>::MaxExposurePageSize::get()
+			)
+			.saturating_mul(offenders.len().max(1) as u64)
+		)]
+		pub fn root_slash_in_era(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			offenders: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let active_era =
+				pallet_staking::ActiveEra::<T>::get().ok_or(Error::<T>::FailedToGetActiveEra)?;
+			let history_depth = <T as pallet_staking::Config>::HistoryDepth::get();
+			ensure!(
+				era <= active_era.index && era >= active_era.index.saturating_sub(history_depth),
+				Error::<T>::EraOutsideHistoryDepth
+			);
+			let session_index = pallet_staking::BondedEras::<T>::get()
+				.into_iter()
+				.find_map(|(e, session)| (e == era).then_some(session))
+				.ok_or(Error::<T>::EraNotBonded)?;
+
+			// `ActiveEra` and `BondedEras`, then the overview and pages of each exposure.
+			let mut reads = 2u64;
+			for (who, _) in offenders.iter() {
+				let exposure = pallet_staking::EraInfo::<T>::get_full_exposure(era, who);
+				ensure!(!exposure.total.is_zero(), Error::<T>::NotExposedInEra);
+				let pages = pallet_staking::ErasStakersOverview::<T>::get(era, who)
+					.map_or(1, |overview| overview.page_count);
+				reads.saturating_accrue(1 + pages as u64);
+			}
+
+			let slash_fraction =
+				offenders.clone().into_iter().map(|(_, fraction)| fraction).collect::<Vec<_>>();
+			let offence_details = offenders.clone().into_iter().map(|(offender, _)| {
+				offence::OffenceDetails { offender, reporters: Default::default() }
+			});
+			let slash_weight = pallet_staking::Pallet::<T>::on_offence(
+				offence_details,
+				&slash_fraction,
+				session_index,
+			);
+
+			Self::deposit_event(Event::OffenceCreatedInEra { era, offenders });
+			Ok(Some(T::DbWeight::get().reads(reads).saturating_add(slash_weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
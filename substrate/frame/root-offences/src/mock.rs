@@ -82,6 +82,7 @@ impl sp_runtime::BoundToRuntimeAppPublic for OtherSessionHandler {
 impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<u64>;
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -182,6 +183,7 @@ impl pallet_timestamp::Config for Test {
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OffenceHandler = Staking;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -203,7 +205,7 @@ impl Default for ExtBuilder {
 }
 
 impl ExtBuilder {
-	fn build(self) -> sp_io::TestExternalities {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
 		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 		pallet_balances::GenesisConfig::<Test> {
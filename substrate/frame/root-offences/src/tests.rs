@@ -16,12 +16,13 @@
 // limitations under the License.
 
 use super::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, traits::Get};
 use mock::{
-	active_era, advance_blocks, start_session, ExtBuilder, RootOffences, RuntimeOrigin, System,
-	Test as T,
+	active_era, advance_blocks, start_session, ExtBuilder, RootOffences, RuntimeOrigin, Staking,
+	System, Test as T,
 };
 use pallet_staking::asset;
+use sp_staking::IndividualExposure;
 
 #[test]
 fn create_offence_fails_given_signed_origin() {
@@ -102,3 +103,99 @@ fn create_offence_wont_slash_idle() {
 		);
 	})
 }
+
+#[test]
+fn root_slash_in_era_fails_given_signed_origin() {
+	use sp_runtime::traits::BadOrigin;
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			RootOffences::root_slash_in_era(RuntimeOrigin::signed(1), 0, vec![]),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn root_slash_in_era_uses_historical_exposure() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 41 nominates 11 in era 0, and is thus exposed in era 1.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(41), vec![11]));
+		start_session(3);
+		assert_eq!(active_era(), 1);
+		assert_eq!(
+			pallet_staking::EraInfo::<T>::get_full_exposure(1, &11).others,
+			vec![IndividualExposure { who: 41, value: 1000 }]
+		);
+
+		// 41 stops nominating, and is no longer exposed from era 2 onwards.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(41)));
+		start_session(9);
+		assert_eq!(active_era(), 3);
+		assert!(pallet_staking::EraInfo::<T>::get_full_exposure(3, &11).others.is_empty());
+
+		// slashing 11 for era 1 slashes its nominator of that era as well.
+		let offenders = vec![(11, Perbill::from_percent(50))];
+		let post_info =
+			RootOffences::root_slash_in_era(RuntimeOrigin::root(), 1, offenders.clone()).unwrap();
+		System::assert_last_event(Event::OffenceCreatedInEra { era: 1, offenders }.into());
+
+		// the eras, the overview and single page of the exposure, and what slashing took.
+		let actual_weight = post_info.actual_weight.unwrap();
+		assert!(actual_weight.all_gte(<T as frame_system::Config>::DbWeight::get().reads(4)));
+		assert!(actual_weight.all_lte(<T as Config>::WeightInfo::root_slash_in_era(
+			<T as pallet_staking::Config>::MaxExposurePageSize::get()
+		)));
+
+		assert_eq!(asset::staked::<T>(&11), 500);
+		assert_eq!(asset::staked::<T>(&41), 500);
+		assert_eq!(asset::staked::<T>(&21), 1000);
+	})
+}
+
+#[test]
+fn root_slash_in_era_rejects_invalid_eras_and_offenders() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(12);
+		assert_eq!(active_era(), 4);
+		let offenders = |who| vec![(who, Perbill::from_percent(50))];
+
+		// future era.
+		assert_noop!(
+			RootOffences::root_slash_in_era(RuntimeOrigin::root(), 5, offenders(11)),
+			Error::<T>::EraOutsideHistoryDepth
+		);
+		// within history depth, but out of the bonding period.
+		assert_noop!(
+			RootOffences::root_slash_in_era(RuntimeOrigin::root(), 0, offenders(11)),
+			Error::<T>::EraNotBonded
+		);
+		// 31 was never elected.
+		assert_noop!(
+			RootOffences::root_slash_in_era(RuntimeOrigin::root(), 1, offenders(31)),
+			Error::<T>::NotExposedInEra
+		);
+
+		assert_ok!(RootOffences::root_slash_in_era(RuntimeOrigin::root(), 1, offenders(11)));
+		assert_eq!(asset::staked::<T>(&11), 500);
+	})
+}
+
+#[test]
+fn root_slash_in_era_rejects_eras_beyond_history_depth() {
+	ExtBuilder::default().build_and_execute(|| {
+		let history_depth: u32 = <T as pallet_staking::Config>::HistoryDepth::get();
+		pallet_staking::ActiveEra::<T>::put(pallet_staking::ActiveEraInfo {
+			index: history_depth + 1,
+			start: None,
+		});
+
+		assert_noop!(
+			RootOffences::root_slash_in_era(
+				RuntimeOrigin::root(),
+				0,
+				vec![(11, Perbill::from_percent(50))]
+			),
+			Error::<T>::EraOutsideHistoryDepth
+		);
+	})
+}
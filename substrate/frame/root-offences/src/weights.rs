@@ -0,0 +1,89 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_root_offences`.
+//!
+//! These are not benchmark output: they are estimated by hand from the storage items that
+//! `root_slash_in_era` and `pallet_staking::Pallet::on_offence` touch, and should be replaced by
+//! the output of the `root_slash_in_era` benchmark once it is run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_root_offences`.
+pub trait WeightInfo {
+	fn root_slash_in_era(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_root_offences` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Staking::ActiveEra` (r:1 w:0)
+	/// Storage: `Staking::BondedEras` (r:1 w:0)
+	/// Storage: `Staking::ErasStakersOverview` (r:1 w:0)
+	/// Storage: `Staking::ErasStakersPaged` (r:1 w:0)
+	/// Storage: `Staking::ErasStartSessionIndex` (r:1 w:0)
+	/// Storage: `Staking::Invulnerables` (r:1 w:0)
+	/// Storage: `Staking::ValidatorSlashInEra` (r:1 w:1)
+	/// Storage: `Staking::SlashingSpans` (r:1 w:1)
+	/// Storage: `Staking::SpanSlash` (r:1 w:1)
+	/// Storage: `Staking::NominatorSlashInEra` (r:512 w:512)
+	/// Storage: `Staking::Ledger` (r:513 w:513)
+	/// Storage: `Balances::Holds` (r:513 w:513)
+	/// The range of component `n` is `[0, 512]`.
+	fn root_slash_in_era(n: u32, ) -> Weight {
+		Weight::from_parts(98_000_000, 5584)
+			.saturating_add(Weight::from_parts(61_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Staking::ActiveEra` (r:1 w:0)
+	/// Storage: `Staking::BondedEras` (r:1 w:0)
+	/// Storage: `Staking::ErasStakersOverview` (r:1 w:0)
+	/// Storage: `Staking::ErasStakersPaged` (r:1 w:0)
+	/// Storage: `Staking::ErasStartSessionIndex` (r:1 w:0)
+	/// Storage: `Staking::Invulnerables` (r:1 w:0)
+	/// Storage: `Staking::ValidatorSlashInEra` (r:1 w:1)
+	/// Storage: `Staking::SlashingSpans` (r:1 w:1)
+	/// Storage: `Staking::SpanSlash` (r:1 w:1)
+	/// Storage: `Staking::NominatorSlashInEra` (r:512 w:512)
+	/// Storage: `Staking::Ledger` (r:513 w:513)
+	/// Storage: `Balances::Holds` (r:513 w:513)
+	/// The range of component `n` is `[0, 512]`.
+	fn root_slash_in_era(n: u32, ) -> Weight {
+		Weight::from_parts(98_000_000, 5584)
+			.saturating_add(Weight::from_parts(61_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(n.into()))
+	}
+}
@@ -17,6 +17,7 @@
 
 use crate::*;
 use frame_support::defensive;
+use sp_runtime::Perbill;
 /// Controls validator disabling
 pub trait DisablingStrategy<T: Config> {
 	/// Make a disabling decision. Returning a [`DisablingDecision`]
@@ -197,3 +198,75 @@ impl<T: Config, const DISABLING_LIMIT_FACTOR: usize> DisablingStrategy<T>
 		}
 	}
 }
+
+/// Implementation of [`DisablingStrategy`] which caps the disabled validators by their share of the
+/// total stake of the active set, rather than by their count.
+///
+/// An offender is disabled only if the stake of all disabled validators, including the offender,
+/// stays within `Limit` of the total stake of the active set. This prevents disabling from
+/// concentrating in low stake validators while large offenders remain active. The stake of each
+/// validator is provided by `StakeOf`, e.g. from its exposure in the current era. If no validator
+/// has any stake, nobody is disabled.
+///
+/// Never re-enables any validators.
+///
+/// Note that each decision calls `StakeOf` once for every validator in the active set. If stakes
+/// are read from storage, as exposures are, this is one read per active validator on every
+/// reported offence, which must be accounted for in the weight of the offence reporting path.
+///
+/// This strategy needs the stakes to be known where the session pallet lives. It does not suit a
+/// relay chain whose staking runs on AssetHub, as with `pallet-staking-async-ah-client`: the relay
+/// chain keeps no exposures, so every stake is zero and nobody would be disabled.
+pub struct UpToStakeLimitDisablingStrategy<StakeOf, Limit>(PhantomData<(StakeOf, Limit)>);
+
+impl<T: Config, StakeOf, Limit> DisablingStrategy<T>
+	for UpToStakeLimitDisablingStrategy<StakeOf, Limit>
+where
+	StakeOf: Convert<T::ValidatorId, u128>,
+	Limit: Get<Perbill>,
+{
+	fn decision(
+		offender_stash: &T::ValidatorId,
+		_offender_slash_severity: OffenceSeverity,
+		currently_disabled: &Vec<(u32, OffenceSeverity)>,
+	) -> DisablingDecision {
+		let active_set = Validators::<T>::get();
+
+		let offender_idx = if let Some(idx) = active_set.iter().position(|i| i == offender_stash) {
+			idx as u32
+		} else {
+			log!(debug, "Won't disable: offender not in active set",);
+			return DisablingDecision { disable: None, reenable: None }
+		};
+
+		let stakes = active_set.into_iter().map(StakeOf::convert).collect::<Vec<_>>();
+		let total_stake = stakes.iter().fold(0u128, |acc, stake| acc.saturating_add(*stake));
+		if total_stake.is_zero() {
+			log!(debug, "Won't disable: active set has no stake");
+			return DisablingDecision { disable: None, reenable: None }
+		}
+
+		// the offender might already be disabled, in which case its stake is only counted once.
+		let disabled_stake = currently_disabled
+			.iter()
+			.filter(|(idx, _)| *idx != offender_idx)
+			.filter_map(|(idx, _)| stakes.get(*idx as usize))
+			.fold(0u128, |acc, stake| acc.saturating_add(*stake))
+			.saturating_add(stakes[offender_idx as usize]);
+
+		// We don't disable more than the limit of the total stake
+		let stake_limit = Limit::get() * total_stake;
+		if disabled_stake > stake_limit {
+			log!(
+				debug,
+				"Won't disable: disabled stake {:?} would exceed limit {:?}",
+				disabled_stake,
+				stake_limit
+			);
+			return DisablingDecision { disable: None, reenable: None }
+		}
+
+		log!(debug, "Will disable {:?}", offender_idx);
+		DisablingDecision { disable: Some(offender_idx), reenable: None }
+	}
+}
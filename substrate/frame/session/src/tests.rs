@@ -671,6 +671,104 @@ mod disabling_byzantine_threshold {
 	}
 }
 
+mod disabling_stake_limit {
+	use super::*;
+	use crate::disabling::{DisablingStrategy, UpToStakeLimitDisablingStrategy};
+	use sp_staking::offence::OffenceSeverity;
+
+	const SEVERITY: OffenceSeverity = OffenceSeverity(Perbill::from_percent(100));
+	const ACTIVE_SET: [<Test as Config>::ValidatorId; 7] = [1, 2, 3, 4, 5, 6, 7];
+
+	frame_support::parameter_types! {
+		pub static StakeLimit: Perbill = Perbill::from_percent(25);
+	}
+
+	/// Validator 1 holds most of the stake, all others 10 each. 1060 in total.
+	pub struct SkewedStake;
+	impl Convert<u64, u128> for SkewedStake {
+		fn convert(validator: u64) -> u128 {
+			if validator == 1 {
+				1000
+			} else {
+				10
+			}
+		}
+	}
+
+	pub struct NoStake;
+	impl Convert<u64, u128> for NoStake {
+		fn convert(_: u64) -> u128 {
+			0
+		}
+	}
+
+	type Strategy = UpToStakeLimitDisablingStrategy<SkewedStake, StakeLimit>;
+
+	#[test]
+	fn disables_low_stake_validators_beyond_count_limit() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			// 5 out of 7 validators, but only 60 out of 1060 stake.
+			let initially_disabled = (1..=5).map(|idx| (idx, SEVERITY)).collect::<Vec<_>>();
+			Validators::<Test>::put(ACTIVE_SET.to_vec());
+
+			let disabling_decision =
+				<Strategy as DisablingStrategy<Test>>::decision(&7, SEVERITY, &initially_disabled);
+
+			assert_eq!(disabling_decision.disable, Some(6));
+			assert!(disabling_decision.reenable.is_none());
+		});
+	}
+
+	#[test]
+	fn dont_disable_beyond_stake_limit() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Validators::<Test>::put(ACTIVE_SET.to_vec());
+
+			// 1000 out of 1060 stake is above the 25% limit on its own.
+			let disabling_decision =
+				<Strategy as DisablingStrategy<Test>>::decision(&1, SEVERITY, &vec![]);
+			assert!(disabling_decision.disable.is_none() && disabling_decision.reenable.is_none());
+		});
+	}
+
+	#[test]
+	fn stake_limit_boundary() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Validators::<Test>::put(ACTIVE_SET.to_vec());
+			StakeLimit::set(Perbill::from_rational(60u32, 1060));
+
+			// 60 out of 1060 is exactly at the limit.
+			let initially_disabled = (1..=5).map(|idx| (idx, SEVERITY)).collect::<Vec<_>>();
+			let disabling_decision =
+				<Strategy as DisablingStrategy<Test>>::decision(&7, SEVERITY, &initially_disabled);
+			assert_eq!(disabling_decision.disable, Some(6));
+
+			// an already disabled offender is not counted twice.
+			let initially_disabled = (1..=6).map(|idx| (idx, SEVERITY)).collect::<Vec<_>>();
+			let disabling_decision =
+				<Strategy as DisablingStrategy<Test>>::decision(&7, SEVERITY, &initially_disabled);
+			assert_eq!(disabling_decision.disable, Some(6));
+
+			// one more is above the limit.
+			StakeLimit::set(Perbill::from_rational(59u32, 1060));
+			let initially_disabled = (1..=5).map(|idx| (idx, SEVERITY)).collect::<Vec<_>>();
+			let disabling_decision =
+				<Strategy as DisablingStrategy<Test>>::decision(&7, SEVERITY, &initially_disabled);
+			assert!(disabling_decision.disable.is_none());
+		});
+	}
+
+	#[test]
+	fn dont_disable_without_stake() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Validators::<Test>::put(ACTIVE_SET.to_vec());
+
+			let disabling_decision = <UpToStakeLimitDisablingStrategy<NoStake, StakeLimit> as DisablingStrategy<Test>>::decision(&7, SEVERITY, &vec![]);
+			assert!(disabling_decision.disable.is_none());
+		});
+	}
+}
+
 mod disabling_with_reenabling {
 	use super::*;
 	use crate::disabling::{DisablingStrategy, UpToLimitWithReEnablingDisablingStrategy};
@@ -110,7 +110,20 @@ pub trait SendToAssetHub {
 	type AccountId;
 
 	/// Report a session change to AssetHub.
-	fn relay_session_report(session_report: rc_client::SessionReport<Self::AccountId>);
+	///
+	/// Returns the number of chunks the report was sent in and their total encoded size in bytes,
+	/// or `None` if nothing was sent.
+	fn relay_session_report(
+		session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)>;
+
+	/// Report a session change to AssetHub, with the validator points delta-encoded.
+	///
+	/// Only used if [`Config::CompressSessionReports`] is enabled. Returns the same as
+	/// [`Self::relay_session_report`].
+	fn relay_compressed_session_report(
+		session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)>;
 
 	/// Report new offences.
 	fn relay_new_offence(
@@ -124,10 +137,18 @@ pub trait SendToAssetHub {
 impl SendToAssetHub for () {
 	type AccountId = u64;
 
-	fn relay_session_report(_session_report: rc_client::SessionReport<Self::AccountId>) {
+	fn relay_session_report(
+		_session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
 		panic!("relay_session_report not implemented");
 	}
 
+	fn relay_compressed_session_report(
+		_session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		panic!("relay_compressed_session_report not implemented");
+	}
+
 	fn relay_new_offence(
 		_session_index: SessionIndex,
 		_offences: Vec<rc_client::Offence<Self::AccountId>>,
@@ -169,6 +190,19 @@ impl<T: Config + pallet_session::Config + pallet_session::historical::Config> Se
 	}
 }
 
+/// Source of validator reward points for duties other than authoring blocks, such as
+/// availability or approval voting.
+pub trait ExtraRewardPoints<AccountId> {
+	/// The points to award validators for the current block.
+	fn points() -> Vec<(AccountId, u32)>;
+}
+
+impl<AccountId> ExtraRewardPoints<AccountId> for () {
+	fn points() -> Vec<(AccountId, u32)> {
+		Vec::new()
+	}
+}
+
 /// Represents the operating mode of the pallet.
 #[derive(
 	Default,
@@ -262,7 +296,7 @@ pub mod pallet {
 	use frame_support::traits::{Hooks, UnixTime};
 	use frame_system::pallet_prelude::*;
 	use pallet_session::{historical, SessionManager};
-	use sp_runtime::{Perbill, Saturating};
+	use sp_runtime::{traits::Zero, Perbill, Saturating};
 	use sp_staking::{
 		offence::{OffenceSeverity, OnOffenceHandler},
 		SessionIndex,
@@ -305,6 +339,17 @@ pub mod pallet {
 		/// Number of points to award a validator per block authored.
 		type PointsPerBlock: Get<u32>;
 
+		/// Reward points awarded on top of [`Config::PointsPerBlock`], queried once per block.
+		///
+		/// Use `()` to only reward block authoring.
+		type ExtraRewardPoints: ExtraRewardPoints<Self::AccountId>;
+
+		/// Maximum number of points that [`Config::ExtraRewardPoints`] can award in a single block,
+		/// across all validators.
+		///
+		/// Points beyond this are dropped, in the order they are returned.
+		type MaxExtraPointsPerBlock: Get<u32>;
+
 		/// Maximum number of offences to batch in a single message to AssetHub.
 		///
 		/// Used during `Active` mode to limit batch size when processing buffered offences
@@ -319,6 +364,58 @@ pub mod pallet {
 		/// - At batch size 50: ~34.6ms total (~1.7% of 2-second compute allowance)
 		type MaxOffenceBatchSize: Get<u32>;
 
+		/// Minimum number of blocks between two offence messages sent to AssetHub.
+		///
+		/// In `Active` mode, offences reported within this interval of the last message are
+		/// buffered in [`BufferedOffences`] instead of being sent right away. Once the interval has
+		/// passed, `on_initialize` sends them in batches of up to [`Config::MaxOffenceBatchSize`].
+		/// This coalesces a burst of offences into a few messages, rather than flooding the XCM
+		/// router.
+		///
+		/// Set to zero to send every offence report right away.
+		type MinOffenceDeliveryInterval: Get<BlockNumberFor<Self>>;
+
+		/// Whether offences are aggregated before being sent to AssetHub.
+		///
+		/// If set, offences reported in `Active` mode are always buffered in [`BufferedOffences`]
+		/// and sent by `on_initialize`, rather than right away. Offences of the same validator in
+		/// the same session reported in the meantime are coalesced into one, carrying the maximum
+		/// slash fraction.
+		type AggregateOffences: Get<bool>;
+
+		/// Maximum encoded size, in bytes, of the payload of a single message sent to AssetHub.
+		///
+		/// Offences are split into as many messages as needed to stay within this bound, on top
+		/// of [`Config::MaxOffenceBatchSize`]. An offence that does not fit on its own is dropped,
+		/// see [`UnexpectedKind::OffenceTooLarge`]. [`Config::SendToAssetHub`] should pass it to
+		/// [`rc_client::XCMSender::split_then_send`] to bound session reports as well.
+		type MaxXcmMessageBytes: Get<u32>;
+
+		/// Whether a session report should be sent to AssetHub even if it carries no
+		/// information, i.e. no validator points and no validator set activation.
+		///
+		/// Offences are sent in separate messages, and are not affected by this.
+		type AlwaysReport: Get<bool>;
+
+		/// Maximum number of consecutive empty session reports that can be skipped, if
+		/// [`Config::AlwaysReport`] is `false`.
+		///
+		/// Once exceeded, the next session report is sent regardless of its content. This acts as a
+		/// heartbeat, and ensures that AssetHub never goes more than this many sessions without
+		/// hearing from us. AssetHub replays the skipped sessions once the next report arrives, so
+		/// this must not exceed the `MaxSkippedSessionReports` of its
+		/// `pallet-staking-async-rc-client`.
+		type MaxSkippedSessionReports: Get<u32>;
+
+		/// Whether session reports are sent delta-encoded, via
+		/// [`SendToAssetHub::relay_compressed_session_report`].
+		///
+		/// Points are encoded against the validator set that AssetHub elected for the session, so
+		/// reports are only compressed once such a set is activated with this enabled. AssetHub
+		/// must accept compressed reports, see the `CompressSessionReports` of its
+		/// `pallet-staking-async-rc-client`, before this is enabled.
+		type CompressSessionReports: Get<bool>;
+
 		/// Interface to talk to the local Session pallet.
 		type SessionInterface: SessionInterface<ValidatorId = Self::AccountId>;
 
@@ -383,6 +480,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextSessionChangesValidators<T: Config> = StorageValue<_, u32, OptionQuery>;
 
+	/// The validator set that AssetHub elected, from the moment it is given to the session pallet
+	/// in `new_session` until it is activated.
+	///
+	/// Only kept if [`Config::CompressSessionReports`] is enabled.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type QueuedElectedValidators<T: Config> = StorageValue<_, Vec<T::AccountId>, OptionQuery>;
+
+	/// The validator set that AssetHub elected for the active session, against which session
+	/// reports are compressed.
+	///
+	/// Only kept if [`Config::CompressSessionReports`] is enabled.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ActiveElectedValidators<T: Config> = StorageValue<_, Vec<T::AccountId>, OptionQuery>;
+
 	/// The session index at which the latest elected validator set was applied.
 	///
 	/// This is used to determine if an offence, given a session index, is in the current active era
@@ -404,6 +517,19 @@ pub mod pallet {
 	#[pallet::unbounded]
 	pub type BufferedOffences<T: Config> = StorageValue<_, BufferedOffencesMap<T>, ValueQuery>;
 
+	/// Number of consecutive session reports that were not sent to AssetHub, because they carried
+	/// no information.
+	///
+	/// Reset every time a session report is sent. See [`Config::MaxSkippedSessionReports`].
+	#[pallet::storage]
+	pub type SkippedSessionReports<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The block at which the last offence message was sent to AssetHub.
+	///
+	/// Only tracked if [`Config::MinOffenceDeliveryInterval`] is non-zero.
+	#[pallet::storage]
+	pub type LastOffenceDelivery<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound, frame_support::DebugNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -444,6 +570,12 @@ pub mod pallet {
 		/// The validator set received is way too small, as per
 		/// [`Config::MinimumValidatorSetSize`].
 		SetTooSmallAndDropped,
+		/// A session report was not sent to AssetHub, because it carried no information. See
+		/// [`Config::AlwaysReport`].
+		SessionReportSkipped { end_index: SessionIndex },
+		/// A session report was sent to AssetHub in `chunks` messages, with a total encoded size
+		/// of `bytes`.
+		SessionReportSent { session_index: SessionIndex, chunks: u32, bytes: u32 },
 		/// Something occurred that should never happen under normal operation. Logged as an event
 		/// for fail-safe observability.
 		Unexpected(UnexpectedKind),
@@ -463,6 +595,9 @@ pub mod pallet {
 		///
 		/// Expected transitions are linear and forward-only: `Passive` → `Buffered` → `Active`.
 		UnexpectedModeTransition,
+
+		/// An offence was dropped, since it alone exceeds [`Config::MaxXcmMessageBytes`].
+		OffenceTooLarge,
 	}
 
 	#[pallet::call]
@@ -590,6 +725,12 @@ pub mod pallet {
 				return weight;
 			}
 
+			// Respect the minimum interval between offence messages.
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if !Self::can_deliver_offences() {
+				return weight;
+			}
+
 			let processing_weight = Self::process_buffered_offences();
 			weight = weight.saturating_add(processing_weight);
 
@@ -773,6 +914,9 @@ pub mod pallet {
 			ValidatorSet::<T>::take().map(|(id, val_set)| {
 				// store the id to be sent back in the next session back to AH
 				NextSessionChangesValidators::<T>::put(id);
+				if T::CompressSessionReports::get() {
+					QueuedElectedValidators::<T>::put(&val_set);
+				}
 				val_set
 			})
 		}
@@ -781,9 +925,16 @@ pub mod pallet {
 			use sp_runtime::SaturatedConversion;
 
 			let validator_points = ValidatorPoints::<T>::iter().drain().collect::<Vec<_>>();
+			// the validators that earned the points of the ending session.
+			let maybe_elected =
+				ActiveElectedValidators::<T>::get().filter(|_| T::CompressSessionReports::get());
 			let activation_timestamp = NextSessionChangesValidators::<T>::take().map(|id| {
 				// keep track of starting session index at which the validator set was applied.
 				ValidatorSetAppliedAt::<T>::put(session_index + 1);
+				match QueuedElectedValidators::<T>::take() {
+					Some(elected) => ActiveElectedValidators::<T>::put(elected),
+					None => ActiveElectedValidators::<T>::kill(),
+				}
 				// set the timestamp and the identifier of the validator set.
 				(T::UnixTime::now().as_millis().saturated_into::<u64>(), id)
 			});
@@ -793,9 +944,31 @@ pub mod pallet {
 				validator_points,
 				activation_timestamp,
 				leftover: false,
+				chunk: (0, 1),
 			};
 
-			T::SendToAssetHub::relay_session_report(session_report);
+			let is_empty = session_report.validator_points.is_empty() &&
+				session_report.activation_timestamp.is_none();
+			if is_empty && !T::AlwaysReport::get() {
+				let skipped = SkippedSessionReports::<T>::get();
+				if skipped < T::MaxSkippedSessionReports::get() {
+					log!(debug, "Skipping empty session report for session {}", session_index);
+					SkippedSessionReports::<T>::put(skipped.saturating_add(1));
+					Self::deposit_event(Event::SessionReportSkipped { end_index: session_index });
+					return;
+				}
+			}
+
+			SkippedSessionReports::<T>::kill();
+			let maybe_sent = match maybe_elected {
+				Some(elected) => T::SendToAssetHub::relay_compressed_session_report(
+					session_report.compress(elected),
+				),
+				None => T::SendToAssetHub::relay_session_report(session_report),
+			};
+			if let Some((chunks, bytes)) = maybe_sent {
+				Self::deposit_event(Event::SessionReportSent { session_index, chunks, bytes });
+			}
 		}
 
 		fn do_reward_by_ids(rewards: impl IntoIterator<Item = (T::AccountId, u32)>) {
@@ -810,6 +983,18 @@ pub mod pallet {
 			ValidatorPoints::<T>::mutate(author, |points| {
 				points.saturating_accrue(T::PointsPerBlock::get());
 			});
+
+			let mut budget = T::MaxExtraPointsPerBlock::get();
+			for (validator_id, points) in T::ExtraRewardPoints::points() {
+				let points = points.min(budget);
+				if points.is_zero() {
+					continue
+				}
+				budget.saturating_reduce(points);
+				ValidatorPoints::<T>::mutate(validator_id, |balance| {
+					balance.saturating_accrue(points);
+				});
+			}
 		}
 
 		/// Process buffered offences and send them to AssetHub in batches.
@@ -859,7 +1044,8 @@ pub mod pallet {
 				);
 
 				let batch_size = offences_to_send.len();
-				T::SendToAssetHub::relay_new_offence(slash_session, offences_to_send);
+				Self::send_offences(slash_session, offences_to_send);
+				Self::note_offence_delivery();
 
 				T::WeightInfo::process_buffered_offences(batch_size as u32)
 			} else {
@@ -867,6 +1053,84 @@ pub mod pallet {
 			}
 		}
 
+		/// Send `offences` to AssetHub, split into as many messages as needed to keep each within
+		/// [`Config::MaxXcmMessageBytes`].
+		fn send_offences(session: SessionIndex, offences: Vec<rc_client::Offence<T::AccountId>>) {
+			let max_bytes = T::MaxXcmMessageBytes::get() as usize;
+			// the encoded size of `(session, offences)` with `count` offences of `size` bytes.
+			let payload_size = |count: usize, size: usize| {
+				session.encoded_size() + codec::Compact(count as u32).encoded_size() + size
+			};
+
+			let mut batches: Vec<Vec<_>> = Vec::new();
+			let (mut batch, mut batch_size) = (Vec::new(), 0);
+			for offence in offences {
+				let size = offence.encoded_size();
+				if payload_size(1, size) > max_bytes {
+					log!(error, "dropping offence of {:?}, too large to send", offence.offender);
+					Self::deposit_event(Event::Unexpected(UnexpectedKind::OffenceTooLarge));
+					continue;
+				}
+				if payload_size(batch.len() + 1, batch_size + size) > max_bytes {
+					batches.push(core::mem::take(&mut batch));
+					batch_size = 0;
+				}
+				batch.push(offence);
+				batch_size += size;
+			}
+			if !batch.is_empty() {
+				batches.push(batch);
+			}
+
+			for batch in batches {
+				T::SendToAssetHub::relay_new_offence(session, batch);
+			}
+		}
+
+		/// Whether an offence message can be sent to AssetHub in this block, as per
+		/// [`Config::MinOffenceDeliveryInterval`].
+		fn can_deliver_offences() -> bool {
+			LastOffenceDelivery::<T>::get().is_none_or(|last| {
+				frame_system::Pallet::<T>::block_number() >=
+					last.saturating_add(T::MinOffenceDeliveryInterval::get())
+			})
+		}
+
+		/// Record that an offence message was sent to AssetHub in this block.
+		fn note_offence_delivery() {
+			if !T::MinOffenceDeliveryInterval::get().is_zero() {
+				LastOffenceDelivery::<T>::put(frame_system::Pallet::<T>::block_number());
+			}
+		}
+
+		/// Buffer an offence to be sent to AssetHub later, keeping only the highest slash fraction
+		/// for each offender per session.
+		fn buffer_offence(
+			slash_session: SessionIndex,
+			offender: T::AccountId,
+			reporters: &[T::AccountId],
+			fraction: Perbill,
+		) {
+			BufferedOffences::<T>::mutate(|buffered| {
+				let session_offences = buffered.entry(slash_session).or_default();
+				let entry = session_offences.entry(offender);
+
+				entry
+					.and_modify(|existing| {
+						if existing.slash_fraction < fraction {
+							*existing = BufferedOffence {
+								reporter: reporters.first().cloned(),
+								slash_fraction: fraction,
+							};
+						}
+					})
+					.or_insert(BufferedOffence {
+						reporter: reporters.first().cloned(),
+						slash_fraction: fraction,
+					});
+			});
+		}
+
 		/// Check if an offence is from the active validator set.
 		fn is_ongoing_offence(slash_session: SessionIndex) -> bool {
 			ValidatorSetAppliedAt::<T>::get()
@@ -899,25 +1163,7 @@ pub mod pallet {
 					let reporters = offence.reporters;
 
 					// In `Buffered` mode, we buffer the offences for later processing.
-					// We only keep the highest slash fraction for each offender per session.
-					BufferedOffences::<T>::mutate(|buffered| {
-						let session_offences = buffered.entry(slash_session).or_default();
-						let entry = session_offences.entry(offender);
-
-						entry
-							.and_modify(|existing| {
-								if existing.slash_fraction < *fraction {
-									*existing = BufferedOffence {
-										reporter: reporters.first().cloned(),
-										slash_fraction: *fraction,
-									};
-								}
-							})
-							.or_insert(BufferedOffence {
-								reporter: reporters.first().cloned(),
-								slash_fraction: *fraction,
-							});
-					});
+					Self::buffer_offence(slash_session, offender, &reporters, *fraction);
 
 					// Return unit for the map operation
 				})
@@ -956,10 +1202,27 @@ pub mod pallet {
 				})
 				.collect();
 
-			// Send offence report to Asset Hub
-			if !offenders_and_slashes_message.is_empty() {
+			if offenders_and_slashes_message.is_empty() {
+				return Weight::zero()
+			}
+
+			if !T::AggregateOffences::get() && Self::can_deliver_offences() {
+				// Send offence report to Asset Hub
 				log!(info, "sending offence report to AH");
-				T::SendToAssetHub::relay_new_offence(slash_session, offenders_and_slashes_message);
+				Self::send_offences(slash_session, offenders_and_slashes_message);
+				Self::note_offence_delivery();
+			} else {
+				// Aggregating, or too soon after the last message: send it later along with other
+				// offences.
+				log!(debug, "buffering offence report");
+				for offence in offenders_and_slashes_message {
+					Self::buffer_offence(
+						slash_session,
+						offence.offender,
+						&offence.reporters,
+						offence.slash_fraction,
+					);
+				}
 			}
 
 			Weight::zero()
@@ -42,7 +42,16 @@ impl frame_system::Config for Test {
 pub struct MockSendToAssetHub;
 impl SendToAssetHub for MockSendToAssetHub {
 	type AccountId = u64;
-	fn relay_session_report(_session_report: rc_client::SessionReport<Self::AccountId>) {}
+	fn relay_session_report(
+		_session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		None
+	}
+	fn relay_compressed_session_report(
+		_session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		None
+	}
 	fn relay_new_offence(_session: u32, _offences: Vec<rc_client::Offence<Self::AccountId>>) {}
 }
 
@@ -107,7 +116,15 @@ impl Config for Test {
 	type MinimumValidatorSetSize = MinimumValidatorSetSize;
 	type UnixTime = MockUnixTime;
 	type PointsPerBlock = PointsPerBlock;
+	type ExtraRewardPoints = ();
+	type MaxExtraPointsPerBlock = frame_support::traits::ConstU32<0>;
 	type MaxOffenceBatchSize = MaxOffenceBatchSize;
+	type MinOffenceDeliveryInterval = frame_support::traits::ConstU64<0>;
+	type AggregateOffences = frame_support::traits::ConstBool<false>;
+	type MaxXcmMessageBytes = frame_support::traits::ConstU32<{ 50 * 1024 }>;
+	type AlwaysReport = frame_support::traits::ConstBool<true>;
+	type MaxSkippedSessionReports = frame_support::traits::ConstU32<0>;
+	type CompressSessionReports = frame_support::traits::ConstBool<false>;
 	type SessionInterface = MockSessionInterface;
 	type Fallback = MockFallback;
 	type WeightInfo = ();
@@ -91,6 +91,7 @@ pub(crate) fn roll_until_next_active(mut end_index: SessionIndex) -> Vec<Account
 			end_index,
 			activation_timestamp: None,
 			leftover: false,
+			chunk: (0, 1),
 			validator_points: Default::default(),
 		};
 		assert_ok!(pallet_staking_async_rc_client::Pallet::<Runtime>::relay_session_report(
@@ -142,6 +143,7 @@ pub(crate) fn roll_until_next_active(mut end_index: SessionIndex) -> Vec<Account
 		end_index,
 		activation_timestamp: Some((1000, planned_era + 1)),
 		leftover: false,
+		chunk: (0, 1),
 		validator_points: Default::default(),
 	};
 	assert_ok!(pallet_staking_async_rc_client::Pallet::<Runtime>::relay_session_report(
@@ -237,6 +239,10 @@ impl multi_block::unsigned::miner::MinerConfig for Runtime {
 
 parameter_types! {
 	pub Bounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+	pub const UnderSubscribedBehavior: pallet_staking_async::UnderSubscribedBehavior =
+		pallet_staking_async::UnderSubscribedBehavior::UseWhatWeGot;
+	pub const DeadPayeeBehavior: pallet_staking_async::DeadPayeeBehavior =
+		pallet_staking_async::DeadPayeeBehavior::Burn;
 }
 
 pub struct OnChainConfig;
@@ -316,6 +322,7 @@ parameter_types! {
 	pub static SlashDeferredDuration: u32 = 2;
 	pub static SessionsPerEra: u32 = 6;
 	pub static PlanningEraOffset: u32 = 2;
+	pub static CompressSessionReports: bool = false;
 }
 
 impl pallet_staking_async::Config for Runtime {
@@ -340,15 +347,36 @@ impl pallet_staking_async::Config for Runtime {
 	type RewardRemainder = ();
 	type Slash = ();
 	type SlashDeferDuration = SlashDeferredDuration;
+	type MaxSlashesPerBlock = ConstU32<1>;
 	type MaxEraDuration = ();
 
 	type HistoryDepth = ConstU32<7>;
 	type MaxControllersInDeprecationBatch = ();
+	type MaxKicks = ConstU32<128>;
+	type MaxBlockedUpdates = ConstU32<16>;
 
 	type MaxValidatorSet = MaxValidators;
+	type MinimumValidatorCount = ConstU32<0>;
+	type UnderSubscribedBehavior = UnderSubscribedBehavior;
+	type DeadPayeeBehavior = DeadPayeeBehavior;
 	type MaxExposurePageSize = MaxExposurePageSize;
+	type MaxBackersExposed = ConstU32<{ u32::MAX }>;
 	type MaxInvulnerables = MaxValidators;
+	type AutoPayoutPerBlock = ConstU32<0>;
+	type PostSlashDustThreshold = ();
+	type MaxSlashReward = ();
+	type MaxReporters = ConstU32<16>;
+	type AllowAutoCompoundUnlocking = ConstBool<false>;
+	type SuppressSlashedNominations = ConstBool<false>;
+	type MaxRecentRewards = ConstU32<0>;
+	type RewardPointsCarryOver = ();
+	type SkipZeroRewardEras = ConstBool<false>;
+	type MaxNominatorsPerValidator = ConstU32<0>;
+	type AutoPayoutExpiring = ConstU32<0>;
+	type RoleChangeCooldown = ConstU32<0>;
+	type MinEraPointsForReward = ConstU32<0>;
 	type MaxUnlockingChunks = ConstU32<16>;
+	type UnlockingChunkMergeWindow = ConstU32<0>;
 	type NominationsQuota = pallet_staking_async::FixedNominationsQuota<16>;
 
 	type VoterList = pallet_staking_async::UseNominatorsAndValidatorsMap<Self>;
@@ -363,6 +391,8 @@ impl pallet_staking_async_rc_client::Config for Runtime {
 	type AHStakingInterface = Staking;
 	type SendToRelayChain = DeliverToRelay;
 	type RelayChainOrigin = EnsureRoot<AccountId>;
+	type CompressSessionReports = CompressSessionReports;
+	type MaxSkippedSessionReports = crate::rc::MaxSkippedSessionReports;
 }
 
 pub struct DeliverToRelay;
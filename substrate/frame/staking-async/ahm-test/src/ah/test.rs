@@ -16,14 +16,20 @@
 // limitations under the License.
 
 use crate::ah::mock::*;
+use codec::{Decode, Encode};
 use frame::prelude::Perbill;
-use frame_support::assert_ok;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use pallet_election_provider_multi_block::{Event as ElectionEvent, Phase};
 use pallet_staking_async::{
 	self as staking_async, session_rotation::Rotator, ActiveEra, ActiveEraInfo, CurrentEra,
 	Event as StakingEvent,
 };
-use pallet_staking_async_rc_client::{self as rc_client, UnexpectedKind, ValidatorSetReport};
+use pallet_staking_async_rc_client::{
+	self as rc_client, SplittableMessage, UnexpectedKind, ValidatorSetReport,
+};
 
 // Tests that are specific to Asset Hub.
 #[test]
@@ -41,6 +47,7 @@ fn on_receive_session_report() {
 			validator_points: (1..9).into_iter().map(|v| (v as AccountId, v * 10)).collect(),
 			activation_timestamp: None,
 			leftover: false,
+			chunk: (0, 1),
 		};
 
 		assert_ok!(rc_client::Pallet::<T>::relay_session_report(
@@ -85,6 +92,7 @@ fn on_receive_session_report() {
 					validator_points: vec![(1, 10)],
 					activation_timestamp: None,
 					leftover: false,
+					chunk: (0, 1),
 				}
 			));
 
@@ -110,6 +118,7 @@ fn on_receive_session_report() {
 				validator_points: vec![(1, 10)],
 				activation_timestamp: None,
 				leftover: false,
+				chunk: (0, 1),
 			}
 		));
 
@@ -222,6 +231,7 @@ fn roll_many_eras() {
 					validator_points: vec![(1, 10)],
 					activation_timestamp,
 					leftover: false,
+					chunk: (0, 1),
 				}
 			));
 
@@ -281,6 +291,7 @@ fn receives_old_session_report() {
 			validator_points: vec![(5, 50)],
 			activation_timestamp: None,
 			leftover: false,
+			chunk: (0, 1),
 		};
 
 		assert_ok!(rc_client::Pallet::<T>::relay_session_report(
@@ -323,6 +334,237 @@ fn receives_old_session_report() {
 	})
 }
 
+#[test]
+fn receives_split_session_report_with_duplicate_chunks() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		let chunks =
+			rc_client::SessionReport::new_terminal(0, vec![(5, 50), (3, 30), (4, 40)], None)
+				.split(1);
+		assert_eq!(
+			chunks.iter().map(|c| c.chunk).collect::<Vec<_>>(),
+			vec![(0, 3), (1, 3), (2, 3)]
+		);
+		let receive = |chunk: &rc_client::SessionReport<AccountId>| {
+			assert_ok!(rc_client::Pallet::<T>::relay_session_report(
+				RuntimeOrigin::root(),
+				chunk.clone()
+			));
+		};
+
+		// a chunk other than the first one cannot start a report.
+		receive(&chunks[1]);
+		assert_eq!(
+			rc_client_events_since_last_call().last(),
+			Some(&rc_client::Event::Unexpected(UnexpectedKind::SessionReportIntegrityFailed))
+		);
+		assert_eq!(rc_client::IncompleteSessionReport::<T>::get(), None);
+
+		// chunks that are received again are ignored.
+		receive(&chunks[0]);
+		receive(&chunks[0]);
+		receive(&chunks[1]);
+		receive(&chunks[0]);
+		assert_eq!(
+			rc_client_events_since_last_call()
+				.into_iter()
+				.filter(|e| matches!(e, rc_client::Event::Unexpected(_)))
+				.collect::<Vec<_>>(),
+			vec![
+				rc_client::Event::Unexpected(UnexpectedKind::SessionReportChunkDuplicate),
+				rc_client::Event::Unexpected(UnexpectedKind::SessionReportChunkDuplicate),
+			]
+		);
+		assert_eq!(
+			rc_client::IncompleteSessionReport::<T>::get().map(|r| r.validator_points),
+			Some(vec![(3, 30), (4, 40)])
+		);
+
+		// and the report is processed once complete.
+		receive(&chunks[2]);
+		assert_eq!(rc_client::IncompleteSessionReport::<T>::get(), None);
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(0));
+		assert_eq!(staking_async::ErasRewardPoints::<T>::get(&0).total, 120);
+	})
+}
+
+#[test]
+fn receives_legacy_session_report_in_chunks() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		// a session report in the encoding that relay chains used before chunks were numbered.
+		let encode_legacy = |points: Vec<(AccountId, u32)>, leftover: bool| {
+			(0u32, points, Option::<(u64, u32)>::None, leftover).encode()
+		};
+		let receive = |encoded: Vec<u8>| {
+			let report =
+				rc_client::LegacySessionReport::<AccountId>::decode(&mut &encoded[..]).unwrap();
+			assert_ok!(rc_client::Pallet::<T>::relay_legacy_session_report(
+				RuntimeOrigin::root(),
+				report
+			));
+		};
+
+		// each chunk follows the one that is buffered.
+		receive(encode_legacy(vec![(3, 30)], true));
+		assert_eq!(rc_client::IncompleteSessionReport::<T>::get().map(|r| r.chunk), Some((0, 1)));
+		receive(encode_legacy(vec![(4, 40)], true));
+		assert_eq!(rc_client::IncompleteSessionReport::<T>::get().map(|r| r.chunk), Some((1, 1)));
+		receive(encode_legacy(vec![(5, 50)], false));
+
+		assert_eq!(rc_client::IncompleteSessionReport::<T>::get(), None);
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(0));
+		assert_eq!(staking_async::ErasRewardPoints::<T>::get(&0).total, 120);
+	})
+}
+
+#[test]
+fn migrates_buffered_legacy_session_report() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		// a report that was partially received before the upgrade.
+		let legacy = rc_client::LegacySessionReport::<AccountId> {
+			end_index: 0,
+			validator_points: vec![(3, 30), (4, 40)],
+			activation_timestamp: None,
+			leftover: true,
+		};
+		frame_support::storage::unhashed::put_raw(
+			&rc_client::IncompleteSessionReport::<T>::hashed_key(),
+			&legacy.encode(),
+		);
+		StorageVersion::new(1).put::<rc_client::Pallet<T>>();
+
+		rc_client::migrations::v2::MigrateV1ToV2::<T>::on_runtime_upgrade();
+		assert_eq!(
+			rc_client::IncompleteSessionReport::<T>::get(),
+			Some(legacy.clone().with_chunk((0, 1)))
+		);
+		assert_eq!(rc_client::Pallet::<T>::on_chain_storage_version(), 2);
+
+		// the rest of the report is still received from a relay chain that is not upgraded yet.
+		assert_ok!(rc_client::Pallet::<T>::relay_legacy_session_report(
+			RuntimeOrigin::root(),
+			rc_client::LegacySessionReport {
+				validator_points: vec![(5, 50)],
+				leftover: false,
+				..legacy
+			}
+		));
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(0));
+		assert_eq!(staking_async::ErasRewardPoints::<T>::get(&0).total, 120);
+	})
+}
+
+#[test]
+fn compressed_session_report_round_trip() {
+	// a full report of 1000 validators, most of which authored the same number of blocks.
+	let validators = (1..=1000).collect::<Vec<AccountId>>();
+	let validator_points = validators
+		.iter()
+		.map(|v| match v % 100 {
+			0 => (*v, 0),
+			1 => (*v, 80 + *v as u32),
+			_ => (*v, 60),
+		})
+		.collect::<Vec<_>>();
+	let report = rc_client::SessionReport {
+		end_index: 7,
+		validator_points,
+		activation_timestamp: Some((42, 3)),
+		leftover: false,
+		chunk: (0, 1),
+	};
+
+	// only the deviating validators are sent.
+	let compressed = report.clone().compress(validators.clone());
+	assert_eq!(compressed.baseline, 60);
+	assert_eq!(compressed.deviations.len(), 20);
+	assert!(compressed.encoded_size() * 20 < report.encoded_size());
+	assert_eq!(compressed.clone().decompress(validators.clone()), Ok(report.clone()));
+
+	// the rest cannot be reconstructed from a different validator set.
+	assert_eq!(
+		compressed.clone().decompress((2..=1001).collect()),
+		Err(UnexpectedKind::SessionReportBaselineMismatch)
+	);
+
+	// only the deviations are split into chunks, and they merge back into the full report.
+	let chunks = compressed.clone().split_by(6);
+	assert_eq!(chunks.len(), 4);
+	assert!(chunks.iter().all(|c| c.baseline_digest == compressed.baseline_digest));
+	let (mut merged, baseline) = chunks
+		.into_iter()
+		.map(|chunk| chunk.into_deviations())
+		.reduce(|(acc, _), (chunk, baseline)| (acc.merge(chunk).unwrap(), baseline))
+		.unwrap();
+	assert_eq!(merged.chunk, (3, 4));
+	assert_ok!(merged.add_baseline(baseline, validators));
+	assert_eq!(merged.validator_points, report.validator_points);
+
+	// validators that earned nothing deviate from the baseline.
+	let partial = rc_client::SessionReport::new_terminal(8, vec![(1, 20), (2, 20)], None)
+		.compress(vec![1, 2, 3]);
+	assert_eq!(partial.deviations, vec![(3, codec::Compact(0))]);
+	assert_eq!(partial.clone().split_by(1), vec![partial]);
+
+	// a report where all validators are at the baseline is sent as a whole.
+	let uniform = rc_client::SessionReport::new_terminal(8, vec![(1, 20), (2, 20)], None)
+		.compress(vec![1, 2]);
+	assert!(uniform.deviations.is_empty());
+	assert_eq!(uniform.clone().split_by(0), vec![uniform]);
+
+	// an empty report has nothing to deviate from.
+	let empty = rc_client::SessionReport::<AccountId>::new_terminal(8, vec![], None);
+	assert_eq!(empty.clone().compress(vec![]).baseline, 0);
+	assert_eq!(empty.clone().compress(vec![]).decompress(vec![]), Ok(empty));
+}
+
+#[test]
+fn receives_compressed_session_report() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		let active = roll_until_next_active(0);
+		assert_eq!(active, vec![3, 5, 6, 8]);
+		let report = rc_client::SessionReport::new_terminal(
+			5,
+			vec![(3, 35), (5, 20), (6, 20), (8, 20)],
+			None,
+		);
+
+		// compressed reports are rejected unless enabled.
+		assert_noop!(
+			rc_client::Pallet::<T>::relay_compressed_session_report(
+				RuntimeOrigin::root(),
+				report.clone().compress(active.clone())
+			),
+			rc_client::Error::<T>::CompressionDisabled
+		);
+
+		CompressSessionReports::set(true);
+		assert_ok!(rc_client::Pallet::<T>::relay_compressed_session_report(
+			RuntimeOrigin::root(),
+			report.compress(active.clone())
+		));
+
+		// then the full report is processed, the rest of the active validators at the baseline.
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(5));
+		let era_points = staking_async::ErasRewardPoints::<T>::get(&1);
+		assert_eq!(era_points.total, 95);
+		assert_eq!(era_points.individual.get(&3), Some(&35));
+		assert_eq!(era_points.individual.get(&8), Some(&20));
+
+		// a report that was compressed against other validators only rewards its deviations.
+		let other = rc_client::SessionReport::new_terminal(6, vec![(3, 35), (5, 20)], None);
+		assert_ok!(rc_client::Pallet::<T>::relay_compressed_session_report(
+			RuntimeOrigin::root(),
+			other.compress(vec![3, 5, 6])
+		));
+		assert_eq!(
+			rc_client_events_since_last_call().last(),
+			Some(&rc_client::Event::Unexpected(UnexpectedKind::SessionReportBaselineMismatch))
+		);
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(6));
+		assert_eq!(staking_async::ErasRewardPoints::<T>::get(&1).total, 95 + 35 + 20);
+	})
+}
+
 #[test]
 fn receives_session_report_in_future() {
 	ExtBuilder::default().local_queue().build().execute_with(|| {
@@ -341,6 +583,7 @@ fn receives_session_report_in_future() {
 				validator_points: vec![(5, 50)],
 				activation_timestamp: None,
 				leftover: false,
+				chunk: (0, 1),
 			},
 		));
 
@@ -375,6 +618,7 @@ fn receives_session_report_in_future() {
 				validator_points: vec![(5, 50)],
 				activation_timestamp: None,
 				leftover: false,
+				chunk: (0, 1),
 			},
 		));
 
@@ -407,6 +651,78 @@ fn receives_session_report_in_future() {
 	})
 }
 
+#[test]
+fn replays_session_reports_skipped_by_relay_chain() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		// given the relay chain may skip up to 2 empty session reports.
+		crate::rc::MaxSkippedSessionReports::set(2);
+		assert_ok!(rc_client::Pallet::<T>::relay_session_report(
+			RuntimeOrigin::root(),
+			rc_client::SessionReport::new_terminal(0, vec![(5, 50)], None),
+		));
+		let _ = rc_client_events_since_last_call();
+		let _ = staking_events_since_last_call();
+
+		// when the reports ending 1 and 2 were skipped as empty.
+		assert_ok!(rc_client::Pallet::<T>::relay_session_report(
+			RuntimeOrigin::root(),
+			rc_client::SessionReport::new_terminal(3, vec![(5, 50)], None),
+		));
+
+		// then the gap is expected, and staking rotates every skipped session too, planning the
+		// next era on time.
+		assert_eq!(rc_client::LastSessionReportEndingIndex::<T>::get(), Some(3));
+		assert_eq!(
+			rc_client_events_since_last_call(),
+			vec![rc_client::Event::SessionReportReceived {
+				end_index: 3,
+				activation_timestamp: None,
+				validator_points_counts: 1,
+				leftover: false
+			}]
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				staking_async::Event::SessionRotated {
+					starting_session: 2,
+					active_era: 0,
+					planned_era: 0
+				},
+				staking_async::Event::SessionRotated {
+					starting_session: 3,
+					active_era: 0,
+					planned_era: 0
+				},
+				staking_async::Event::SessionRotated {
+					starting_session: 4,
+					active_era: 0,
+					planned_era: 1
+				}
+			]
+		);
+		assert_eq!(staking_async::ErasRewardPoints::<T>::get(&0).total, 100);
+
+		// but a gap larger than what the relay chain may skip is still unexpected.
+		assert_ok!(rc_client::Pallet::<T>::relay_session_report(
+			RuntimeOrigin::root(),
+			rc_client::SessionReport::new_terminal(7, vec![], None),
+		));
+		assert_eq!(
+			rc_client_events_since_last_call(),
+			vec![
+				rc_client::Event::Unexpected(UnexpectedKind::SessionSkipped),
+				rc_client::Event::SessionReportReceived {
+					end_index: 7,
+					activation_timestamp: None,
+					validator_points_counts: 0,
+					leftover: false
+				}
+			]
+		);
+	})
+}
+
 #[test]
 fn session_report_burst() {
 	// note: there is also an e2e `session_report_burst` test
@@ -427,6 +743,7 @@ fn session_report_burst() {
 					validator_points: vec![(5, 50)],
 					activation_timestamp: None,
 					leftover: false,
+					chunk: (0, 1),
 				},
 			));
 			// all are processed fine, in one go
@@ -464,7 +464,7 @@ mod tests {
 			assert_eq!(
 				offence_record_v1,
 				pallet_staking_async::slashing::OffenceRecord {
-					reporter: None,
+					reporters: Default::default(),
 					reported_era: 1,
 					exposure_page: 0,
 					slash_fraction: Perbill::from_percent(85), /* Should be the highest slash
@@ -478,7 +478,7 @@ mod tests {
 			assert_eq!(
 				offence_record_v2,
 				pallet_staking_async::slashing::OffenceRecord {
-					reporter: None,
+					reporters: Default::default(),
 					reported_era: 1,
 					exposure_page: 0,
 					slash_fraction: Perbill::from_percent(100), /* Should be the highest slash
@@ -492,7 +492,7 @@ mod tests {
 			assert_eq!(
 				offence_record_v5,
 				pallet_staking_async::slashing::OffenceRecord {
-					reporter: None,
+					reporters: Default::default(),
 					reported_era: 1,
 					exposure_page: 0,
 					slash_fraction: Perbill::from_percent(55), /* Should be the highest slash
@@ -792,12 +792,15 @@ mod tests {
 		shared::in_rc(|| {
 			assert_eq!(
 				rc::ah_client_events_since_last_call(),
-				vec![ah_client::Event::ValidatorSetReceived {
-					id: 2,
-					new_validator_set_count: 4,
-					prune_up_to: None,
-					leftover: false
-				}]
+				vec![
+					ah_client::Event::SessionReportSent { session_index: 14, chunks: 1, bytes: 27 },
+					ah_client::Event::ValidatorSetReceived {
+						id: 2,
+						new_validator_set_count: 4,
+						prune_up_to: None,
+						leftover: false
+					}
+				]
 			);
 
 			let (planned_era, next_validator_set) =
@@ -821,10 +824,45 @@ mod tests {
 
 	#[test]
 	fn election_result_on_ah_reported_to_rc() {
-		// when election result is complete
-		// staking stores all exposures
-		// validators reported to rc
-		// validators enacted for next session
+		shared::put_ah_state(ah::ExtBuilder::default().build());
+		shared::put_rc_state(
+			rc::ExtBuilder::default().session_keys(vec![1, 2, 3, 4, 5, 6, 7, 8]).build(),
+		);
+
+		// roll until AH has elected era 1 and sent the new validator set to RC.
+		let mut elected = vec![];
+		shared::in_rc(|| {
+			rc::roll_until_matches(
+				|| ah_client::ValidatorSet::<rc::Runtime>::get().is_some(),
+				true,
+			);
+			assert_eq!(shared::CounterAHRCValidatorSet::get(), 1);
+			let (id, validators) = ah_client::ValidatorSet::<rc::Runtime>::get().unwrap();
+			assert_eq!(id, 1);
+			elected = validators;
+		});
+
+		// then roll until RC has enacted it in `pallet_session`.
+		let mut applied_at = 0;
+		shared::in_rc(|| {
+			rc::roll_until_matches(
+				|| ah_client::ValidatorSetAppliedAt::<rc::Runtime>::get().is_some(),
+				true,
+			);
+			applied_at = ah_client::ValidatorSetAppliedAt::<rc::Runtime>::get().unwrap();
+			assert_eq!(pallet_session::CurrentIndex::<rc::Runtime>::get(), applied_at);
+			assert_eq!(pallet_session::Validators::<rc::Runtime>::get(), elected);
+		});
+
+		// the session report that activates the set reaches AH, which starts era 1 at the very
+		// session the set took effect in.
+		shared::in_ah(|| {
+			assert_eq!(ActiveEra::<ah::Runtime>::get().unwrap().index, 1);
+			assert_eq!(
+				pallet_staking_async::BondedEras::<ah::Runtime>::get().last(),
+				Some(&(1, applied_at))
+			);
+		});
 	}
 
 	#[test]
@@ -247,17 +247,27 @@ impl pallet_staking::Config for Runtime {
 impl pallet_root_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OffenceHandler = StakingAhClient;
+	type WeightInfo = ();
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum OutgoingMessages {
 	SessionReport(rc_client::SessionReport<AccountId>),
+	CompressedSessionReport(rc_client::CompressedSessionReport<AccountId>),
 	OffenceReport(SessionIndex, Vec<rc_client::Offence<AccountId>>),
 }
 
 parameter_types! {
 	pub static MinimumValidatorSetSize: u32 = 4;
 	pub static MaxOffenceBatchSize: u32 = 50;
+	pub static MinOffenceDeliveryInterval: BlockNumber = 0;
+	pub static AggregateOffences: bool = false;
+	pub static MaxXcmMessageBytes: u32 = 50 * 1024;
+	pub static AlwaysReport: bool = true;
+	pub static MaxSkippedSessionReports: u32 = 0;
+	pub static CompressSessionReports: bool = false;
+	pub static ExtraPoints: Vec<(AccountId, u32)> = vec![];
+	pub static MaxExtraPointsPerBlock: u32 = 0;
 	pub static LocalQueue: Option<Vec<(BlockNumber, OutgoingMessages)>> = None;
 	pub static LocalQueueLastIndex: usize = 0;
 }
@@ -278,6 +288,13 @@ impl LocalQueue {
 	}
 }
 
+pub struct MockExtraRewardPoints;
+impl ah_client::ExtraRewardPoints<AccountId> for MockExtraRewardPoints {
+	fn points() -> Vec<(AccountId, u32)> {
+		ExtraPoints::get()
+	}
+}
+
 impl ah_client::Config for Runtime {
 	type CurrencyBalance = Balance;
 	type AdminOrigin = EnsureRoot<AccountId>;
@@ -286,7 +303,15 @@ impl ah_client::Config for Runtime {
 	type UnixTime = Timestamp;
 	type MinimumValidatorSetSize = MinimumValidatorSetSize;
 	type PointsPerBlock = ConstU32<20>;
+	type ExtraRewardPoints = MockExtraRewardPoints;
+	type MaxExtraPointsPerBlock = MaxExtraPointsPerBlock;
 	type MaxOffenceBatchSize = MaxOffenceBatchSize;
+	type MinOffenceDeliveryInterval = MinOffenceDeliveryInterval;
+	type AggregateOffences = AggregateOffences;
+	type MaxXcmMessageBytes = MaxXcmMessageBytes;
+	type AlwaysReport = AlwaysReport;
+	type MaxSkippedSessionReports = MaxSkippedSessionReports;
+	type CompressSessionReports = CompressSessionReports;
 	type SessionInterface = Self;
 	type WeightInfo = ();
 	type Fallback = Staking;
@@ -320,7 +345,10 @@ impl ah_client::SendToAssetHub for DeliverToAH {
 		}
 	}
 
-	fn relay_session_report(session_report: rc_client::SessionReport<Self::AccountId>) {
+	fn relay_session_report(
+		session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		let bytes = session_report.encoded_size() as u32;
 		if let Some(mut local_queue) = LocalQueue::get() {
 			local_queue
 				.push((System::block_number(), OutgoingMessages::SessionReport(session_report)));
@@ -336,6 +364,31 @@ impl ah_client::SendToAssetHub for DeliverToAH {
 				.unwrap();
 			});
 		}
+		Some((1, bytes))
+	}
+
+	fn relay_compressed_session_report(
+		session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		let bytes = session_report.encoded_size() as u32;
+		if let Some(mut local_queue) = LocalQueue::get() {
+			local_queue.push((
+				System::block_number(),
+				OutgoingMessages::CompressedSessionReport(session_report),
+			));
+			LocalQueue::set(Some(local_queue));
+		} else {
+			shared::CounterRCAHSessionReport::mutate(|x| *x += 1);
+			shared::in_ah(|| {
+				let origin = crate::ah::RuntimeOrigin::root();
+				rc_client::Pallet::<crate::ah::Runtime>::relay_compressed_session_report(
+					origin,
+					session_report.clone(),
+				)
+				.unwrap();
+			});
+		}
+		Some((1, bytes))
 	}
 }
 
@@ -422,6 +475,24 @@ impl ExtBuilder {
 		self
 	}
 
+	/// Set the minimum number of blocks between two offence messages sent to AH
+	pub fn min_offence_delivery_interval(self, interval: BlockNumber) -> Self {
+		MinOffenceDeliveryInterval::set(interval);
+		self
+	}
+
+	/// Aggregate offences before sending them to AH
+	pub fn aggregate_offences(self) -> Self {
+		AggregateOffences::set(true);
+		self
+	}
+
+	/// Set the maximum encoded size of a single offence message sent to AH
+	pub fn max_xcm_message_bytes(self, bytes: u32) -> Self {
+		MaxXcmMessageBytes::set(bytes);
+		self
+	}
+
 	pub fn build(self) -> TestState {
 		let _ = sp_tracing::try_init_simple();
 		let mut t = frame_system::GenesisConfig::<T>::default().build_storage().unwrap();
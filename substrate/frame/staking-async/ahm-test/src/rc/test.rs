@@ -16,7 +16,9 @@
 // limitations under the License.
 
 use crate::rc::mock::*;
+use codec::Compact;
 use frame::testing_prelude::*;
+use frame_support::traits::RewardsReporter;
 use pallet_staking_async_ah_client::{self as ah_client, Mode, OperatingMode};
 use pallet_staking_async_rc_client::{
 	self as rc_client, Offence, SessionReport, ValidatorSetReport,
@@ -36,7 +38,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 0,
 						validator_points: vec![(11, 580)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -45,7 +48,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 1,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -54,7 +58,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 2,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -63,7 +68,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 3,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -72,7 +78,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 4,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -81,7 +88,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 5,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -90,7 +98,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 6,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -99,7 +108,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 7,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -108,7 +118,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 8,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),
 				(
@@ -117,7 +128,8 @@ fn send_session_report_no_election_comes_in() {
 						end_index: 9,
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				)
 			]
@@ -154,7 +166,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 							end_index: 0,
 							validator_points: vec![],
 							activation_timestamp: None,
-							leftover: false
+							leftover: false,
+							chunk: (0, 1),
 						})
 					),
 					(
@@ -163,7 +176,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 							end_index: 1,
 							validator_points: vec![],
 							activation_timestamp: None,
-							leftover: false
+							leftover: false,
+							chunk: (0, 1),
 						})
 					),
 					(
@@ -172,7 +186,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 							end_index: 2,
 							validator_points: vec![],
 							activation_timestamp: None,
-							leftover: false
+							leftover: false,
+							chunk: (0, 1),
 						})
 					),
 				]
@@ -229,7 +244,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 						end_index: 3,
 						validator_points: vec![],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				)]
 			);
@@ -260,7 +276,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 						end_index: 4,
 						validator_points: vec![],
 						activation_timestamp: Some((150000, 1)),
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				),]
 			);
@@ -305,7 +322,8 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 						end_index: 5,
 						validator_points: vec![],
 						activation_timestamp: None,
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				)],
 			);
@@ -336,13 +354,58 @@ fn upon_receiving_election_queue_and_activate_next_session() {
 						end_index: 6,
 						validator_points: vec![],
 						activation_timestamp: Some((210000, 2)),
-						leftover: false
+						leftover: false,
+						chunk: (0, 1),
 					})
 				)]
 			);
 		})
 }
 
+#[test]
+fn skips_empty_session_reports_until_stale() {
+	// given no one authors blocks, so session reports carry no points.
+	ExtBuilder::default()
+		.local_queue()
+		.no_default_author()
+		.build()
+		.execute_with(|| {
+			AlwaysReport::set(false);
+			MaxSkippedSessionReports::set(2);
+
+			// when
+			roll_until_matches(|| pallet_session::CurrentIndex::<Runtime>::get() == 6, false);
+
+			// then every third report is sent as a heartbeat in a single chunk, the rest are
+			// skipped.
+			let empty_report = |end_index| {
+				OutgoingMessages::SessionReport(SessionReport {
+					end_index,
+					validator_points: vec![],
+					activation_timestamp: None,
+					leftover: false,
+					chunk: (0, 1),
+				})
+			};
+			assert_eq!(
+				LocalQueue::get().unwrap(),
+				vec![(90, empty_report(2)), (180, empty_report(5))]
+			);
+			assert_eq!(
+				ah_client_events_since_last_call(),
+				vec![
+					ah_client::Event::SessionReportSkipped { end_index: 0 },
+					ah_client::Event::SessionReportSkipped { end_index: 1 },
+					ah_client::Event::SessionReportSent { session_index: 2, chunks: 1, bytes: 15 },
+					ah_client::Event::SessionReportSkipped { end_index: 3 },
+					ah_client::Event::SessionReportSkipped { end_index: 4 },
+					ah_client::Event::SessionReportSent { session_index: 5, chunks: 1, bytes: 15 },
+				]
+			);
+			assert_eq!(ah_client::SkippedSessionReports::<Runtime>::get(), 0);
+		})
+}
+
 #[test]
 fn cleans_validator_points_upon_session_report() {
 	ExtBuilder::default().local_queue().build().execute_with(|| {
@@ -363,7 +426,8 @@ fn cleans_validator_points_upon_session_report() {
 					// first two are inserted by us, the other one by the test mock
 					validator_points: vec![(1, 100), (2, 200), (11, 580)],
 					activation_timestamp: None,
-					leftover: false
+					leftover: false,
+					chunk: (0, 1),
 				})
 			),]
 		);
@@ -374,6 +438,80 @@ fn cleans_validator_points_upon_session_report() {
 	})
 }
 
+#[test]
+fn awards_extra_reward_points_up_to_cap() {
+	ExtBuilder::default().local_queue().build().execute_with(|| {
+		// given 1 and 2 earn extra points every block, but only 25 can be awarded per block.
+		ExtraPoints::set(vec![(1, 10), (2, 20)]);
+		MaxExtraPointsPerBlock::set(25);
+
+		// when
+		roll_until_matches(|| pallet_session::CurrentIndex::<Runtime>::get() == 1, false);
+
+		// then 2 is capped in every block, on top of the authoring points of 11.
+		assert_eq!(
+			LocalQueue::get().unwrap(),
+			vec![(
+				30,
+				OutgoingMessages::SessionReport(SessionReport {
+					end_index: 0,
+					validator_points: vec![(1, 290), (2, 435), (11, 580)],
+					activation_timestamp: None,
+					leftover: false,
+					chunk: (0, 1),
+				})
+			),]
+		);
+	})
+}
+
+#[test]
+fn compresses_session_reports_against_the_elected_validators() {
+	ExtBuilder::default()
+		.session_keys(vec![1, 2, 3, 4, 5])
+		.local_queue()
+		.no_default_author()
+		.build()
+		.execute_with(|| {
+			CompressSessionReports::set(true);
+
+			// a validator set is elected, and 6 is dropped by the session pallet for having no
+			// keys.
+			let report = ValidatorSetReport {
+				id: 1,
+				prune_up_to: None,
+				leftover: false,
+				new_validator_set: vec![1, 2, 3, 4, 6],
+			};
+			assert_ok!(ah_client::Pallet::<Runtime>::validator_set(RuntimeOrigin::root(), report));
+			roll_until_matches(|| pallet_session::CurrentIndex::<Runtime>::get() == 2, false);
+			assert_eq!(pallet_session::Validators::<Runtime>::get(), vec![1, 2, 3, 4]);
+
+			// until then, session reports are sent uncompressed.
+			assert!(LocalQueue::get_since_last_call()
+				.into_iter()
+				.all(|(_, m)| matches!(m, OutgoingMessages::SessionReport(_))));
+
+			// when 4 earns more points than the rest in the next session.
+			ah_client::Pallet::<Runtime>::reward_by_ids(vec![(1, 20), (2, 20), (3, 20), (4, 35)]);
+			roll_until_matches(|| pallet_session::CurrentIndex::<Runtime>::get() == 3, false);
+
+			// then only 4, and 6 that earned nothing, are sent.
+			let messages = LocalQueue::get_since_last_call();
+			let [(_, OutgoingMessages::CompressedSessionReport(compressed))] = &messages[..] else {
+				panic!("expected one compressed session report, got {:?}", messages);
+			};
+			assert_eq!(compressed.baseline, 20);
+			assert_eq!(compressed.deviations, vec![(4, Compact(35)), (6, Compact(0))]);
+
+			// and AssetHub can reconstruct the rest from the validators it elected.
+			assert_eq!(
+				compressed.clone().decompress(vec![1, 2, 3, 4, 6]).map(|r| r.validator_points),
+				Ok(vec![(1, 20), (2, 20), (3, 20), (4, 35), (6, 0)])
+			);
+		})
+}
+
 #[test]
 fn drops_too_small_validator_set() {
 	ExtBuilder::default().local_queue().build().execute_with(|| {
@@ -772,6 +910,7 @@ mod blocking {
 						validator_points: vec![(11, 600)],
 						activation_timestamp: None,
 						leftover: false,
+						chunk: (0, 1),
 					})
 				)]
 			);
@@ -789,6 +928,7 @@ mod splitting {
 				activation_timestamp: None,
 				end_index: 0,
 				leftover: false,
+				chunk: (0, 1),
 				validator_points: vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)],
 			};
 
@@ -871,6 +1011,38 @@ mod splitting {
 		})
 	}
 
+	#[test]
+	fn splitting_session_report_is_deterministic() {
+		let report = |validator_points| SessionReport {
+			end_index: 0,
+			validator_points,
+			activation_timestamp: Some((42, 1)),
+			leftover: false,
+			chunk: (0, 1),
+		};
+		let one = report(vec![(3, 3), (1, 1), (5, 5), (2, 2), (4, 4)]);
+		let other = report(vec![(5, 5), (4, 4), (3, 3), (2, 2), (1, 1)]);
+
+		for c in 1..=6 {
+			let one = one.clone().split(c);
+			let other = other.clone().split(c);
+			assert_eq!(
+				one.iter().map(Encode::encode).collect::<Vec<_>>(),
+				other.iter().map(Encode::encode).collect::<Vec<_>>()
+			);
+
+			// chunks are sorted, and carry their position.
+			let total = one.len() as u32;
+			assert!(one.iter().enumerate().all(|(i, r)| r.chunk == (i as u32, total)));
+			assert_eq!(
+				one.into_iter()
+					.reduce(|acc, x| acc.merge(x).unwrap())
+					.map(|r| r.validator_points),
+				Some(vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)])
+			);
+		}
+	}
+
 	#[test]
 	fn splitting_and_merging_equal() {
 		let full_report = ValidatorSetReport {
@@ -1187,3 +1359,245 @@ mod key_proofs {
 			})
 	}
 }
+
+#[test]
+fn offence_reports_are_rate_limited() {
+	ExtBuilder::default()
+		.local_queue()
+		.session_keys(vec![1, 2, 3, 4])
+		.min_offence_delivery_interval(3)
+		.build()
+		.execute_with(|| {
+			receive_validator_set_at(3, 1, vec![1, 2, 3, 4], true);
+			assert_eq!(pallet_session::CurrentIndex::<Runtime>::get(), 5);
+			LocalQueue::flush();
+
+			let offence = |who: AccountId, percent: u32| Offence {
+				offender: who,
+				reporters: vec![],
+				slash_fraction: Perbill::from_percent(percent),
+			};
+			let report = |who: AccountId, percent: u32| {
+				assert_ok!(pallet_root_offences::Pallet::<Runtime>::create_offence(
+					RuntimeOrigin::root(),
+					vec![(who, Perbill::from_percent(percent))],
+					Some(vec![Default::default()]),
+					None
+				));
+			};
+
+			// the first offence is sent right away.
+			report(1, 10);
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(150, OutgoingMessages::OffenceReport(5, vec![offence(1, 10)]))]
+			);
+
+			// a burst of offences in the next blocks is buffered.
+			report(2, 10);
+			roll_next();
+			report(3, 10);
+			roll_next();
+			report(2, 20);
+			assert_eq!(LocalQueue::get_since_last_call(), vec![]);
+			assert_eq!(ah_client::BufferedOffences::<Runtime>::get()[&5].len(), 2);
+
+			// and sent in a single message once the interval has passed.
+			roll_next();
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(
+					153,
+					OutgoingMessages::OffenceReport(5, vec![offence(2, 20), offence(3, 10)])
+				)]
+			);
+			assert!(ah_client::BufferedOffences::<Runtime>::get().is_empty());
+
+			// the next one has to wait again.
+			report(4, 10);
+			roll_next();
+			roll_next();
+			assert_eq!(LocalQueue::get_since_last_call(), vec![]);
+			roll_next();
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(156, OutgoingMessages::OffenceReport(5, vec![offence(4, 10)]))]
+			);
+		})
+}
+
+#[test]
+fn buffered_offences_are_flushed_without_delivery_interval() {
+	ExtBuilder::default()
+		.local_queue()
+		.session_keys(vec![1, 2, 3, 4])
+		.build()
+		.execute_with(|| {
+			receive_validator_set_at(3, 1, vec![1, 2, 3, 4], true);
+			assert_eq!(pallet_session::CurrentIndex::<Runtime>::get(), 5);
+			LocalQueue::flush();
+
+			let offence = |who: AccountId, percent: u32| Offence {
+				offender: who,
+				reporters: vec![],
+				slash_fraction: Perbill::from_percent(percent),
+			};
+			let report = |who: AccountId, percent: u32| {
+				assert_ok!(pallet_root_offences::Pallet::<Runtime>::create_offence(
+					RuntimeOrigin::root(),
+					vec![(who, Perbill::from_percent(percent))],
+					Some(vec![Default::default()]),
+					None
+				));
+			};
+
+			// offences reported while buffering are held back.
+			Mode::<Runtime>::put(OperatingMode::Buffered);
+			report(1, 10);
+			report(2, 10);
+			assert_eq!(LocalQueue::get_since_last_call(), vec![]);
+			assert_eq!(ah_client::BufferedOffences::<Runtime>::get()[&5].len(), 2);
+
+			// and flushed in the first block after going active.
+			Mode::<Runtime>::put(OperatingMode::Active);
+			roll_next();
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(
+					151,
+					OutgoingMessages::OffenceReport(5, vec![offence(1, 10), offence(2, 10)])
+				)]
+			);
+			assert!(ah_client::BufferedOffences::<Runtime>::get().is_empty());
+
+			// from then on, every offence is sent right away, even in the same block.
+			report(3, 10);
+			report(4, 10);
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![
+					(151, OutgoingMessages::OffenceReport(5, vec![offence(3, 10)])),
+					(151, OutgoingMessages::OffenceReport(5, vec![offence(4, 10)]))
+				]
+			);
+			assert!(ah_client::BufferedOffences::<Runtime>::get().is_empty());
+			assert_eq!(ah_client::LastOffenceDelivery::<Runtime>::get(), None);
+		})
+}
+
+#[test]
+fn aggregated_offences_carry_the_max_severity() {
+	ExtBuilder::default()
+		.local_queue()
+		.session_keys(vec![1, 2, 3, 4])
+		.aggregate_offences()
+		.build()
+		.execute_with(|| {
+			receive_validator_set_at(3, 1, vec![1, 2, 3, 4], true);
+			assert_eq!(pallet_session::CurrentIndex::<Runtime>::get(), 5);
+			LocalQueue::flush();
+
+			let report = |who: AccountId, percent: u32| {
+				assert_ok!(pallet_root_offences::Pallet::<Runtime>::create_offence(
+					RuntimeOrigin::root(),
+					vec![(who, Perbill::from_percent(percent))],
+					Some(vec![Default::default()]),
+					None
+				));
+			};
+
+			// given two offences of 1 in the same session, with differing severity.
+			report(1, 30);
+			report(1, 10);
+
+			// then nothing is sent right away.
+			assert_eq!(LocalQueue::get_since_last_call(), vec![]);
+			assert_eq!(ah_client::BufferedOffences::<Runtime>::get()[&5].len(), 1);
+
+			// but a single offence with the max severity in the next block.
+			roll_next();
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(
+					151,
+					OutgoingMessages::OffenceReport(
+						5,
+						vec![Offence {
+							offender: 1,
+							reporters: vec![],
+							slash_fraction: Perbill::from_percent(30)
+						}]
+					)
+				)]
+			);
+			assert!(ah_client::BufferedOffences::<Runtime>::get().is_empty());
+		})
+}
+
+#[test]
+fn offence_reports_are_split_by_encoded_size() {
+	use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+	ExtBuilder::default()
+		.local_queue()
+		.session_keys(vec![1, 2, 3, 4])
+		.max_xcm_message_bytes(30)
+		.build()
+		.execute_with(|| {
+			receive_validator_set_at(3, 1, vec![1, 2, 3, 4], true);
+			assert_eq!(pallet_session::CurrentIndex::<Runtime>::get(), 5);
+			LocalQueue::flush();
+			let _ = ah_client_events_since_last_call();
+
+			let offence = |who: AccountId, reporters: Vec<AccountId>| Offence {
+				offender: who,
+				reporters,
+				slash_fraction: Perbill::from_percent(10),
+			};
+
+			// two offences reported together exceed the limit, so they go in two messages.
+			assert_ok!(pallet_root_offences::Pallet::<Runtime>::create_offence(
+				RuntimeOrigin::root(),
+				vec![(1, Perbill::from_percent(10)), (2, Perbill::from_percent(10))],
+				Some(vec![Default::default(), Default::default()]),
+				None
+			));
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![
+					(150, OutgoingMessages::OffenceReport(5, vec![offence(1, vec![])])),
+					(150, OutgoingMessages::OffenceReport(5, vec![offence(2, vec![])])),
+				]
+			);
+
+			// an offence that does not fit on its own is dropped.
+			let reporters = (100..110).collect::<Vec<AccountId>>();
+			<ah_client::Pallet<Runtime> as OnOffenceHandler<_, _, _>>::on_offence(
+				&[OffenceDetails {
+					offender: (3, sp_staking::Exposure::default()),
+					reporters: reporters.clone(),
+				}],
+				&[Perbill::from_percent(10)],
+				5,
+			);
+			assert_eq!(LocalQueue::get_since_last_call(), vec![]);
+			assert_eq!(
+				ah_client_events_since_last_call(),
+				vec![ah_client::Event::Unexpected(ah_client::UnexpectedKind::OffenceTooLarge)]
+			);
+
+			// while smaller ones still go through.
+			<ah_client::Pallet<Runtime> as OnOffenceHandler<_, _, _>>::on_offence(
+				&[OffenceDetails {
+					offender: (3, sp_staking::Exposure::default()),
+					reporters: vec![100],
+				}],
+				&[Perbill::from_percent(10)],
+				5,
+			);
+			assert_eq!(
+				LocalQueue::get_since_last_call(),
+				vec![(150, OutgoingMessages::OffenceReport(5, vec![offence(3, vec![100])]))]
+			);
+		})
+}
@@ -42,6 +42,10 @@
 //! > Note that in the code, due to historical reasons, planning of a new session is called
 //! > `new_session`.
 //!
+//! * [`Call::relay_legacy_session_report`]: The same, in the encoding that relay chains used before
+//!   session reports were sent in numbered chunks. See [`migrations::v2`] for the order in which
+//!   the two chains should be upgraded.
+//!
 //! * [`Call::relay_new_offence`]: A report of one or more offences on the relay chain.
 //!
 //! ## Outgoing Messages
@@ -117,6 +121,7 @@
 
 extern crate alloc;
 use alloc::{vec, vec::Vec};
+use codec::Compact;
 use core::fmt::Display;
 use frame_support::pallet_prelude::*;
 use sp_runtime::{traits::Convert, Perbill};
@@ -255,6 +260,9 @@ pub struct SessionReport<AccountId> {
 	///
 	/// This `id` is what was previously communicated to the RC as a part of
 	/// [`ValidatorSetReport::id`].
+	///
+	/// This is the acknowledgement that the validator set was installed in the RC's session
+	/// pallet, taking effect in session `end_index + 1`.
 	pub activation_timestamp: Option<(u64, u32)>,
 	/// If this session report is self-contained, then it is false.
 	///
@@ -266,6 +274,12 @@ pub struct SessionReport<AccountId> {
 	///
 	/// Upon processing, this should always be true, and it should be ignored.
 	pub leftover: bool,
+	/// The position of this report among the chunks it was split into, as `(chunk_index,
+	/// total_chunks)`. A report that is sent in one go is `(0, 1)`.
+	///
+	/// This allows the receiving side to drop chunks that it has already merged, e.g. when they
+	/// are sent again.
+	pub chunk: (u32, u32),
 }
 
 impl<AccountId: core::fmt::Debug> core::fmt::Debug for SessionReport<AccountId> {
@@ -275,6 +289,7 @@ impl<AccountId: core::fmt::Debug> core::fmt::Debug for SessionReport<AccountId>
 			.field("validator_points", &self.validator_points)
 			.field("activation_timestamp", &self.activation_timestamp)
 			.field("leftover", &self.leftover)
+			.field("chunk", &self.chunk)
 			.finish()
 	}
 }
@@ -286,6 +301,7 @@ impl<AccountId> core::fmt::Display for SessionReport<AccountId> {
 			.field("validator_points", &self.validator_points.len())
 			.field("activation_timestamp", &self.activation_timestamp)
 			.field("leftover", &self.leftover)
+			.field("chunk", &self.chunk)
 			.finish()
 	}
 }
@@ -298,31 +314,45 @@ impl<AccountId> SessionReport<AccountId> {
 		validator_points: Vec<(AccountId, u32)>,
 		activation_timestamp: Option<(u64, u32)>,
 	) -> Self {
-		Self { end_index, validator_points, activation_timestamp, leftover: false }
+		Self { end_index, validator_points, activation_timestamp, leftover: false, chunk: (0, 1) }
 	}
 
-	/// Merge oneself with another instance.
+	/// Merge oneself with another instance, which must be the chunk that directly follows.
 	pub fn merge(mut self, other: Self) -> Result<Self, UnexpectedKind> {
 		if self.end_index != other.end_index ||
-			self.activation_timestamp != other.activation_timestamp
+			self.activation_timestamp != other.activation_timestamp ||
+			self.chunk.1 != other.chunk.1 ||
+			self.chunk.0.saturating_add(1) != other.chunk.0
 		{
 			// Must be some bug -- don't merge.
 			return Err(UnexpectedKind::SessionReportIntegrityFailed);
 		}
 		self.validator_points.extend(other.validator_points);
 		self.leftover = other.leftover;
+		self.chunk = other.chunk;
 		Ok(self)
 	}
 
 	/// Split oneself into `count` number of pieces.
-	pub fn split(self, chunk_size: usize) -> Vec<Self>
+	///
+	/// The validator points are sorted by account before being split, so that splitting the same
+	/// report always yields the same chunks.
+	pub fn split(mut self, chunk_size: usize) -> Vec<Self>
 	where
-		AccountId: Clone,
+		AccountId: Clone + Ord,
 	{
+		self.validator_points.sort_by(|(a, _), (b, _)| a.cmp(b));
 		let splitted_points = self.validator_points.chunks(chunk_size.max(1)).map(|x| x.to_vec());
+		let total_chunks = splitted_points.len() as u32;
 		let mut parts = splitted_points
 			.into_iter()
-			.map(|validator_points| Self { validator_points, leftover: true, ..self })
+			.enumerate()
+			.map(|(index, validator_points)| Self {
+				validator_points,
+				leftover: true,
+				chunk: (index as u32, total_chunks),
+				..self
+			})
 			.collect::<Vec<_>>();
 		if let Some(x) = parts.last_mut() {
 			x.leftover = false
@@ -331,9 +361,203 @@ impl<AccountId> SessionReport<AccountId> {
 	}
 }
 
+/// A [`SessionReport`] in the encoding that predates its [`SessionReport::chunk`] field, as
+/// received via [`Call::relay_legacy_session_report`].
+///
+/// Relay chains that are not upgraded yet keep sending their session reports in this format. Such
+/// a report is taken to be the chunk that directly follows the one that is buffered for the same
+/// session, if any, which is how chunks were merged before they were numbered.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, TypeInfo, RuntimeDebug)]
+pub struct LegacySessionReport<AccountId> {
+	/// Same as [`SessionReport::end_index`].
+	pub end_index: SessionIndex,
+	/// Same as [`SessionReport::validator_points`].
+	pub validator_points: Vec<(AccountId, u32)>,
+	/// Same as [`SessionReport::activation_timestamp`].
+	pub activation_timestamp: Option<(u64, u32)>,
+	/// Same as [`SessionReport::leftover`].
+	pub leftover: bool,
+}
+
+impl<AccountId> LegacySessionReport<AccountId> {
+	/// Convert oneself into a [`SessionReport`] that is the given `chunk`.
+	pub fn with_chunk(self, chunk: (u32, u32)) -> SessionReport<AccountId> {
+		let Self { end_index, validator_points, activation_timestamp, leftover } = self;
+		SessionReport { end_index, validator_points, activation_timestamp, leftover, chunk }
+	}
+}
+
+/// A [`SessionReport`] whose validator points are delta-encoded against a common baseline.
+///
+/// Most validators earn the same points in a session, e.g. `PointsPerBlock` times the number of
+/// blocks they authored. Only the validators that deviate from this baseline are sent, with their
+/// points. All other validators of the session earned exactly `baseline` points, and the receiving
+/// side reconstructs them from the validator set it elected, see [`SessionReport::add_baseline`].
+///
+/// See [`SessionReport::compress`] and [`CompressedSessionReport::decompress`].
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, TypeInfo)]
+pub struct CompressedSessionReport<AccountId> {
+	/// Same as [`SessionReport::end_index`].
+	pub end_index: SessionIndex,
+	/// The points that each validator of the session that is not in `deviations` earned.
+	pub baseline: u32,
+	/// The digest of the validators that earned `baseline` points, see [`baseline_digest`].
+	///
+	/// This allows the receiving side to check that it reconstructs the same validators.
+	pub baseline_digest: [u8; 32],
+	/// The validators that did not earn `baseline` points, with their points.
+	pub deviations: Vec<(AccountId, Compact<u32>)>,
+	/// Same as [`SessionReport::activation_timestamp`].
+	pub activation_timestamp: Option<(u64, u32)>,
+	/// Same as [`SessionReport::leftover`].
+	pub leftover: bool,
+	/// Same as [`SessionReport::chunk`].
+	pub chunk: (u32, u32),
+}
+
+impl<AccountId: core::fmt::Debug> core::fmt::Debug for CompressedSessionReport<AccountId> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("CompressedSessionReport")
+			.field("end_index", &self.end_index)
+			.field("baseline", &self.baseline)
+			.field("baseline_digest", &self.baseline_digest)
+			.field("deviations", &self.deviations)
+			.field("activation_timestamp", &self.activation_timestamp)
+			.field("leftover", &self.leftover)
+			.field("chunk", &self.chunk)
+			.finish()
+	}
+}
+
+impl<AccountId> core::fmt::Display for CompressedSessionReport<AccountId> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("CompressedSessionReport")
+			.field("end_index", &self.end_index)
+			.field("baseline", &self.baseline)
+			.field("deviations", &self.deviations.len())
+			.field("activation_timestamp", &self.activation_timestamp)
+			.field("leftover", &self.leftover)
+			.field("chunk", &self.chunk)
+			.finish()
+	}
+}
+
+/// The digest of `validators`, sorted, that earned the baseline points of a
+/// [`CompressedSessionReport`].
+pub fn baseline_digest<AccountId: Encode>(validators: &[AccountId]) -> [u8; 32] {
+	sp_core::hashing::blake2_256(&validators.encode())
+}
+
+impl<AccountId: Clone + Ord + Encode> SessionReport<AccountId> {
+	/// Delta-encode the validator points of this report against the most common points value among
+	/// `validators`, the validator set that earned them.
+	///
+	/// Validators without any points in this report earned zero. If several values are equally
+	/// common, the lowest one is used as the baseline. Accounts that are not in `validators` are
+	/// always sent as deviations.
+	pub fn compress(self, validators: Vec<AccountId>) -> CompressedSessionReport<AccountId> {
+		use alloc::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+		let validators = validators.into_iter().collect::<BTreeSet<_>>();
+		let mut points = validators.iter().map(|v| (v.clone(), 0)).collect::<BTreeMap<_, u32>>();
+		for (who, earned) in self.validator_points {
+			let total = points.entry(who).or_default();
+			*total = total.saturating_add(earned);
+		}
+
+		let mut occurrences = BTreeMap::<u32, u32>::new();
+		for who in &validators {
+			*occurrences.entry(points[who]).or_default() += 1;
+		}
+		// `max_by_key` returns the last maximum, so iterate from the highest value down.
+		let baseline = occurrences
+			.into_iter()
+			.rev()
+			.max_by_key(|(_, count)| *count)
+			.map(|(points, _)| points)
+			.unwrap_or_default();
+
+		let (at_baseline, deviations): (Vec<_>, Vec<_>) = points
+			.into_iter()
+			.partition(|(who, points)| *points == baseline && validators.contains(who));
+		let at_baseline = at_baseline.into_iter().map(|(who, _)| who).collect::<Vec<_>>();
+		CompressedSessionReport {
+			end_index: self.end_index,
+			baseline,
+			baseline_digest: baseline_digest(&at_baseline),
+			deviations: deviations
+				.into_iter()
+				.map(|(who, points)| (who, Compact(points)))
+				.collect(),
+			activation_timestamp: self.activation_timestamp,
+			leftover: self.leftover,
+			chunk: self.chunk,
+		}
+	}
+
+	/// Give `baseline` points to each of `validators` that this report has no points for.
+	///
+	/// This completes a report that only holds the deviations of a [`CompressedSessionReport`],
+	/// see [`CompressedSessionReport::into_deviations`]. `validators` must be the same validator
+	/// set that the report was compressed against, which is checked against `digest`.
+	pub fn add_baseline(
+		&mut self,
+		(baseline, digest): (u32, [u8; 32]),
+		validators: Vec<AccountId>,
+	) -> Result<(), UnexpectedKind> {
+		use alloc::collections::btree_set::BTreeSet;
+
+		let deviating = self.validator_points.iter().map(|(who, _)| who).collect::<BTreeSet<_>>();
+		let mut at_baseline =
+			validators.into_iter().filter(|v| !deviating.contains(v)).collect::<Vec<_>>();
+		at_baseline.sort();
+		at_baseline.dedup();
+		if baseline_digest(&at_baseline) != digest {
+			return Err(UnexpectedKind::SessionReportBaselineMismatch);
+		}
+
+		// validators without points need not be reported.
+		if !baseline.is_zero() {
+			self.validator_points.extend(at_baseline.into_iter().map(|who| (who, baseline)));
+			self.validator_points.sort_by(|(a, _), (b, _)| a.cmp(b));
+		}
+		Ok(())
+	}
+}
+
+impl<AccountId: Clone + Ord + Encode> CompressedSessionReport<AccountId> {
+	/// Split oneself into a [`SessionReport`] of the deviating validators only, and the baseline
+	/// that all other validators earned, along with its digest.
+	pub fn into_deviations(self) -> (SessionReport<AccountId>, (u32, [u8; 32])) {
+		let report = SessionReport {
+			end_index: self.end_index,
+			validator_points: self
+				.deviations
+				.into_iter()
+				.map(|(who, points)| (who, points.0))
+				.collect(),
+			activation_timestamp: self.activation_timestamp,
+			leftover: self.leftover,
+			chunk: self.chunk,
+		};
+		(report, (self.baseline, self.baseline_digest))
+	}
+
+	/// Reconstruct the full [`SessionReport`], with its validator points sorted by account, given
+	/// `validators`, the validator set that it was compressed against.
+	pub fn decompress(
+		self,
+		validators: Vec<AccountId>,
+	) -> Result<SessionReport<AccountId>, UnexpectedKind> {
+		let (mut report, baseline) = self.into_deviations();
+		report.add_baseline(baseline, validators)?;
+		Ok(report)
+	}
+}
+
 /// A trait to encapsulate messages between RC and AH that can be splitted into smaller chunks.
 ///
-/// Implemented for [`SessionReport`] and [`ValidatorSetReport`].
+/// Implemented for [`SessionReport`], [`CompressedSessionReport`] and [`ValidatorSetReport`].
 #[allow(clippy::len_without_is_empty)]
 pub trait SplittableMessage: Sized {
 	/// Split yourself into pieces of `chunk_size` size.
@@ -343,7 +567,7 @@ pub trait SplittableMessage: Sized {
 	fn len(&self) -> usize;
 }
 
-impl<AccountId: Clone> SplittableMessage for SessionReport<AccountId> {
+impl<AccountId: Clone + Ord> SplittableMessage for SessionReport<AccountId> {
 	fn split_by(self, chunk_size: usize) -> Vec<Self> {
 		self.split(chunk_size)
 	}
@@ -352,6 +576,38 @@ impl<AccountId: Clone> SplittableMessage for SessionReport<AccountId> {
 	}
 }
 
+impl<AccountId: Clone + Ord + Encode> SplittableMessage for CompressedSessionReport<AccountId> {
+	fn split_by(self, chunk_size: usize) -> Vec<Self> {
+		if self.deviations.is_empty() {
+			// all validators are at the baseline, which is sent as a whole.
+			return vec![self];
+		}
+		// only the deviations are split, each chunk carries the baseline.
+		let (baseline, baseline_digest) = (self.baseline, self.baseline_digest);
+		let (deviations, _) = self.into_deviations();
+		deviations
+			.split(chunk_size)
+			.into_iter()
+			.map(|chunk| CompressedSessionReport {
+				end_index: chunk.end_index,
+				baseline,
+				baseline_digest,
+				deviations: chunk
+					.validator_points
+					.into_iter()
+					.map(|(who, points)| (who, Compact(points)))
+					.collect(),
+				activation_timestamp: chunk.activation_timestamp,
+				leftover: chunk.leftover,
+				chunk: chunk.chunk,
+			})
+			.collect()
+	}
+	fn len(&self) -> usize {
+		self.deviations.len()
+	}
+}
+
 impl<AccountId: Clone> SplittableMessage for ValidatorSetReport<AccountId> {
 	fn split_by(self, chunk_size: usize) -> Vec<Self> {
 		self.split(chunk_size)
@@ -378,24 +634,34 @@ where
 	ToXcm: Convert<Message, Xcm<()>>,
 {
 	/// Safe send method to send a `message`, while validating it and using [`SplittableMessage`] to
-	/// split it into smaller pieces if XCM validation fails with `ExceedsMaxMessageSize`. It will
-	/// fail on other errors.
+	/// split it into smaller pieces if XCM validation fails with `ExceedsMaxMessageSize`, or if any
+	/// piece is encoded in more than `maybe_max_bytes`. It will fail on other errors, or if a
+	/// single item does not fit.
 	///
-	/// It will only emit some logs, and has no return value. This is used in the runtime, so it
-	/// cannot deposit any events at this level.
-	pub fn split_then_send(message: Message, maybe_max_steps: Option<u32>) {
+	/// It will only emit some logs. This is used in the runtime, so it cannot deposit any events at
+	/// this level. Instead, it returns the number of chunks sent and their total encoded size in
+	/// bytes, or `None` if the message could not be split, so that the caller can report them.
+	pub fn split_then_send(
+		message: Message,
+		maybe_max_steps: Option<u32>,
+		maybe_max_bytes: Option<u32>,
+	) -> Option<(u32, u32)> {
 		let message_type_name = core::any::type_name::<Message>();
 		let dest = Destination::get();
-		let xcms = match Self::prepare(message, maybe_max_steps) {
+		let xcms = match Self::prepare(message, maybe_max_steps, maybe_max_bytes) {
 			Ok(x) => x,
 			Err(e) => {
 				log::error!(target: "runtime::rc-client", "📨 Failed to split message {}: {:?}", message_type_name, e);
-				return;
+				return None;
 			},
 		};
 
+		let chunks = xcms.len() as u32;
+		let mut bytes = 0u32;
 		for (idx, xcm) in xcms.into_iter().enumerate() {
-			log::debug!(target: "runtime::rc-client", "📨 sending {} message index {}, size: {:?}", message_type_name, idx, xcm.encoded_size());
+			let size = xcm.encoded_size();
+			bytes = bytes.saturating_add(size as u32);
+			log::debug!(target: "runtime::rc-client", "📨 sending {} message index {}, size: {:?}", message_type_name, idx, size);
 			let result = send_xcm::<Sender>(dest.clone(), xcm);
 			match result {
 				Ok(_) => {
@@ -406,9 +672,15 @@ where
 				},
 			}
 		}
+
+		Some((chunks, bytes))
 	}
 
-	fn prepare(message: Message, maybe_max_steps: Option<u32>) -> Result<Vec<Xcm<()>>, SendError> {
+	fn prepare(
+		message: Message,
+		maybe_max_steps: Option<u32>,
+		maybe_max_bytes: Option<u32>,
+	) -> Result<Vec<Xcm<()>>, SendError> {
 		// initial chunk size is the entire thing, so it will be a vector of 1 item.
 		let mut chunk_size = message.len();
 		let mut steps = 0;
@@ -432,9 +704,18 @@ where
 				first_message.encoded_size(),
 			);
 
-			let first_xcm = ToXcm::convert(first_message.clone());
-			match <Sender as SendXcm>::validate(&mut Some(Destination::get()), &mut Some(first_xcm))
-			{
+			// treat a piece above the byte ceiling as if it was rejected by the XCM router.
+			let within_max_bytes = maybe_max_bytes.is_none_or(|max_bytes| {
+				current_messages.iter().all(|m| m.encoded_size() <= max_bytes as usize)
+			});
+			let validation = if within_max_bytes {
+				let first_xcm = ToXcm::convert(first_message.clone());
+				<Sender as SendXcm>::validate(&mut Some(Destination::get()), &mut Some(first_xcm))
+			} else {
+				Err(SendError::ExceedsMaxMessageSize)
+			};
+
+			match validation {
 				Ok((_ticket, price)) => {
 					log::debug!(target: "runtime::staking-async::xcm", "📨 validated, price: {:?}", price);
 					return Ok(current_messages.into_iter().map(ToXcm::convert).collect::<Vec<_>>());
@@ -481,6 +762,13 @@ pub trait AHStakingInterface {
 	/// accurate amount.
 	fn weigh_on_relay_session_report(report: &SessionReport<Self::AccountId>) -> Weight;
 
+	/// The validators that earn the points of the session reports that are received now, i.e.
+	/// those of the active era.
+	///
+	/// This is used to reconstruct a [`CompressedSessionReport`]. Reading it is bounded by
+	/// [`Self::MaxValidatorSet`] storage reads.
+	fn active_validators() -> Vec<Self::AccountId>;
+
 	/// Report one or more offences on the relay chain.
 	fn on_new_offences(
 		slash_session: SessionIndex,
@@ -524,9 +812,11 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// An incomplete incoming session report that we have not acted upon yet.
+	///
+	/// Its encoding changed in storage version 2, see [`migrations::v2`].
 	// Note: this can remain unbounded, as the internals of `AHStakingInterface` is benchmarked, and
 	// is worst case.
 	#[pallet::storage]
@@ -561,6 +851,25 @@ pub mod pallet {
 
 		/// Our communication handle to the relay chain.
 		type SendToRelayChain: SendToRelayChain<AccountId = Self::AccountId>;
+
+		/// Whether session reports can be received delta-encoded, via
+		/// [`Call::relay_compressed_session_report`].
+		///
+		/// The relay chain should only send [`CompressedSessionReport`]s once this is enabled.
+		type CompressSessionReports: Get<bool>;
+
+		/// The maximum number of consecutive empty session reports that the relay chain may skip.
+		///
+		/// Should match the relay chain's `MaxSkippedSessionReports`. A gap of up to this many
+		/// sessions before an incoming report is expected, and the skipped sessions are replayed
+		/// to [`Config::AHStakingInterface`] as empty reports, so its session count stays in sync.
+		type MaxSkippedSessionReports: Get<u32>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Compressed session reports are not enabled. See [`Config::CompressSessionReports`].
+		CompressionDisabled,
 	}
 
 	#[pallet::event]
@@ -591,11 +900,19 @@ pub mod pallet {
 		SessionReportIntegrityFailed,
 		/// We could not merge the chunks, and therefore dropped the validator set.
 		ValidatorSetIntegrityFailed,
-		/// The received session index is more than what we expected.
+		/// The received session index is more than what we expected, even accounting for
+		/// [`Config::MaxSkippedSessionReports`].
 		SessionSkipped,
 		/// A session in the past was received. This will not raise any errors, just emit an event
 		/// and stop processing the report.
 		SessionAlreadyProcessed,
+		/// A chunk of the session report that is being received was already merged. This will not
+		/// raise any errors, just emit an event and ignore the chunk.
+		SessionReportChunkDuplicate,
+		/// The validators that we reconstructed at the baseline of a [`CompressedSessionReport`]
+		/// are not those that the relay chain compressed it against. Only the deviating validators
+		/// are rewarded for the session.
+		SessionReportBaselineMismatch,
 	}
 
 	impl<T: Config> RcClientInterface for Pallet<T> {
@@ -614,20 +931,107 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Called to indicate the start of a new session on the relay chain.
-		#[pallet::call_index(0)]
-		#[pallet::weight(
-			// `LastSessionReportEndingIndex`: rw
-			// `IncompleteSessionReport`: rw
-			T::DbWeight::get().reads_writes(2, 2) + T::AHStakingInterface::weigh_on_relay_session_report(report)
-		)]
+		///
+		/// This replaces [`Call::relay_legacy_session_report`], and is only sent by relay chains
+		/// that number the chunks of their session reports.
+		#[pallet::call_index(3)]
+		#[pallet::weight(Pallet::<T>::weigh_relay_session_report(report))]
 		pub fn relay_session_report(
 			origin: OriginFor<T>,
 			report: SessionReport<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			log!(debug, "Received session report: {}", report);
 			T::RelayChainOrigin::ensure_origin_or_root(origin)?;
+			Self::do_relay_session_report(report, None)
+		}
+
+		/// Called to report one or more new offenses on the relay chain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			// events are free
+			// origin check is negligible.
+			T::AHStakingInterface::weigh_on_new_offences(*slash_session, offences)
+		)]
+		pub fn relay_new_offence(
+			origin: OriginFor<T>,
+			slash_session: SessionIndex,
+			offences: Vec<Offence<T::AccountId>>,
+		) -> DispatchResultWithPostInfo {
+			log!(info, "Received new offence at slash_session: {:?}", slash_session);
+			T::RelayChainOrigin::ensure_origin_or_root(origin)?;
+
+			Self::deposit_event(Event::OffenceReceived {
+				slash_session,
+				offences_count: offences.len() as u32,
+			});
+
+			let weight = T::AHStakingInterface::on_new_offences(slash_session, offences);
+			Ok(Some(weight).into())
+		}
+
+		/// Same as [`Call::relay_session_report`], but with the validator points delta-encoded,
+		/// see [`CompressedSessionReport`].
+		///
+		/// Only allowed if [`Config::CompressSessionReports`] is enabled. Once the report is
+		/// complete, the validators at its baseline are those of the active era, as given by
+		/// [`AHStakingInterface::active_validators`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(Pallet::<T>::weigh_relay_compressed_session_report(report))]
+		pub fn relay_compressed_session_report(
+			origin: OriginFor<T>,
+			report: CompressedSessionReport<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			log!(debug, "Received compressed session report: {}", report);
+			T::RelayChainOrigin::ensure_origin_or_root(origin)?;
+			ensure!(T::CompressSessionReports::get(), Error::<T>::CompressionDisabled);
+			let (deviations, baseline) = report.into_deviations();
+			Self::do_relay_session_report(deviations, Some(baseline))
+		}
+
+		/// Same as [`Call::relay_session_report`], but with a [`LegacySessionReport`], whose chunk
+		/// is not numbered.
+		///
+		/// This is what relay chains that are not upgraded yet send, and should be removed once
+		/// all of them send [`Call::relay_session_report`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			// `IncompleteSessionReport`: r
+			Pallet::<T>::weigh_relay_session_report(&SessionReport::new_terminal(
+				report.end_index,
+				Vec::new(),
+				report.activation_timestamp,
+			))
+			.saturating_add(T::DbWeight::get().reads(1))
+		)]
+		pub fn relay_legacy_session_report(
+			origin: OriginFor<T>,
+			report: LegacySessionReport<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::RelayChainOrigin::ensure_origin_or_root(origin.clone())?;
+			// follow the buffered chunk of the same session, if any, or start a new report.
+			let chunk = match IncompleteSessionReport::<T>::get() {
+				Some(old) if old.end_index == report.end_index =>
+					(old.chunk.0.saturating_add(1), old.chunk.1),
+				_ => (0, 1),
+			};
+			Self::relay_session_report(origin, report.with_chunk(chunk))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Process a [`SessionReport`], or a chunk of it, once its origin is checked.
+		///
+		/// If `baseline` is given, the report only holds the deviations of a
+		/// [`CompressedSessionReport`], and the validators at the baseline are reconstructed once
+		/// it is complete.
+		fn do_relay_session_report(
+			report: SessionReport<T::AccountId>,
+			baseline: Option<(u32, [u8; 32])>,
+		) -> DispatchResultWithPostInfo {
 			let local_weight = T::DbWeight::get().reads_writes(2, 2);
 
+			// sessions before this report that the relay chain skipped as empty.
+			let mut skipped = 0;
 			match LastSessionReportEndingIndex::<T>::get() {
 				None => {
 					// first session report post genesis, okay.
@@ -635,6 +1039,13 @@ pub mod pallet {
 				Some(last) if report.end_index == last + 1 => {
 					// incremental -- good
 				},
+				Some(last)
+					if report.end_index > last + 1 &&
+						report.end_index - last - 1 <= T::MaxSkippedSessionReports::get() =>
+				{
+					// the relay chain skipped some empty reports -- replay them once final.
+					skipped = report.end_index - last - 1;
+				},
 				Some(last) if report.end_index > last + 1 => {
 					// deposit a warning event, but proceed
 					Self::deposit_event(Event::Unexpected(UnexpectedKind::SessionSkipped));
@@ -667,7 +1078,16 @@ pub mod pallet {
 
 			// If we have anything previously buffered, then merge it.
 			let maybe_new_session_report = match IncompleteSessionReport::<T>::take() {
+				Some(old) if old.end_index == report.end_index && report.chunk.0 <= old.chunk.0 => {
+					// a chunk that we have already merged -- keep what we have.
+					IncompleteSessionReport::<T>::put(old);
+					Self::deposit_event(Event::Unexpected(
+						UnexpectedKind::SessionReportChunkDuplicate,
+					));
+					return Ok(Some(local_weight).into());
+				},
 				Some(old) => old.merge(report.clone()),
+				None if report.chunk.0 != 0 => Err(UnexpectedKind::SessionReportIntegrityFailed),
 				None => Ok(report),
 			};
 
@@ -679,7 +1099,7 @@ pub mod pallet {
 				);
 				return Ok(().into());
 			}
-			let new_session_report = maybe_new_session_report.expect("checked above; qed");
+			let mut new_session_report = maybe_new_session_report.expect("checked above; qed");
 
 			if new_session_report.leftover {
 				// this is still not final -- buffer it.
@@ -687,34 +1107,118 @@ pub mod pallet {
 				Ok(().into())
 			} else {
 				// this is final, report it.
+				let mut weight = local_weight;
+				if let Some(baseline) = baseline {
+					// reconstruct the validators at the baseline of a compressed report.
+					weight.saturating_accrue(
+						T::DbWeight::get().reads(
+							<T::AHStakingInterface as AHStakingInterface>::MaxValidatorSet::get()
+								.into(),
+						),
+					);
+					if let Err(e) = new_session_report
+						.add_baseline(baseline, T::AHStakingInterface::active_validators())
+					{
+						Self::deposit_event(Event::Unexpected(e));
+					}
+				}
 				LastSessionReportEndingIndex::<T>::put(new_session_report.end_index);
-				let weight = T::AHStakingInterface::on_relay_session_report(new_session_report);
-				Ok((Some(local_weight + weight)).into())
+				for end_index in
+					(new_session_report.end_index - skipped)..new_session_report.end_index
+				{
+					let empty = SessionReport::new_terminal(end_index, vec![], None);
+					weight.saturating_accrue(T::AHStakingInterface::on_relay_session_report(empty));
+				}
+				weight.saturating_accrue(T::AHStakingInterface::on_relay_session_report(
+					new_session_report,
+				));
+				Ok(Some(weight).into())
 			}
 		}
 
-		/// Called to report one or more new offenses on the relay chain.
-		#[pallet::call_index(1)]
-		#[pallet::weight(
-			// events are free
-			// origin check is negligible.
-			T::AHStakingInterface::weigh_on_new_offences(*slash_session, offences)
-		)]
-		pub fn relay_new_offence(
-			origin: OriginFor<T>,
-			slash_session: SessionIndex,
-			offences: Vec<Offence<T::AccountId>>,
-		) -> DispatchResultWithPostInfo {
-			log!(info, "Received new offence at slash_session: {:?}", slash_session);
-			T::RelayChainOrigin::ensure_origin_or_root(origin)?;
+		/// The worst case weight of [`Call::relay_compressed_session_report`].
+		///
+		/// This is weighed on the encoded length of `report`, rather than decompressing it: on top
+		/// of [`Self::weigh_relay_session_report`], which does not depend on the validator points,
+		/// the chunk is buffered in `IncompleteSessionReport`, and the validators at the baseline
+		/// are read once the report is complete.
+		fn weigh_relay_compressed_session_report(
+			report: &CompressedSessionReport<T::AccountId>,
+		) -> Weight {
+			let header = SessionReport::new_terminal(
+				report.end_index,
+				Vec::new(),
+				report.activation_timestamp,
+			);
+			Self::weigh_relay_session_report(&header)
+				.saturating_add(Weight::from_parts(0, report.encoded_size() as u64))
+				.saturating_add(T::DbWeight::get().reads(
+					<T::AHStakingInterface as AHStakingInterface>::MaxValidatorSet::get().into(),
+				))
+		}
 
-			Self::deposit_event(Event::OffenceReceived {
-				slash_session,
-				offences_count: offences.len() as u32,
-			});
+		/// The worst case weight of [`Call::relay_session_report`], including the replay of up to
+		/// [`Config::MaxSkippedSessionReports`] empty session reports before `report`.
+		///
+		/// The weight of [`Config::AHStakingInterface`] is a worst case that does not depend on the
+		/// validator points of `report`.
+		fn weigh_relay_session_report(report: &SessionReport<T::AccountId>) -> Weight {
+			let empty = SessionReport::new_terminal(report.end_index, vec![], None);
+			// `LastSessionReportEndingIndex`: rw
+			// `IncompleteSessionReport`: rw
+			T::DbWeight::get()
+				.reads_writes(2, 2)
+				.saturating_add(T::AHStakingInterface::weigh_on_relay_session_report(report))
+				.saturating_add(
+					T::AHStakingInterface::weigh_on_relay_session_report(&empty)
+						.saturating_mul(T::MaxSkippedSessionReports::get().into()),
+				)
+		}
+	}
+}
 
-			let weight = T::AHStakingInterface::on_new_offences(slash_session, offences);
-			Ok(Some(weight).into())
+/// Storage migrations of this pallet.
+pub mod migrations {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+	/// Migrate to storage version 2, in which [`IncompleteSessionReport`] holds a [`SessionReport`]
+	/// with a numbered chunk.
+	///
+	/// ## Upgrade Order
+	///
+	/// AssetHub must be upgraded before the relay chain starts sending session reports via
+	/// [`Call::relay_session_report`]. Until then, relay chains keep using
+	/// [`Call::relay_legacy_session_report`], which is still accepted after this migration.
+	pub mod v2 {
+		use super::*;
+
+		pub struct VersionUncheckedMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+		impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+			fn on_runtime_upgrade() -> Weight {
+				// a buffered report is all the chunks that were merged so far, which we number as
+				// the first one. Any subsequent legacy chunk follows it.
+				let translated = IncompleteSessionReport::<T>::translate::<
+					LegacySessionReport<T::AccountId>,
+					_,
+				>(|maybe_old| maybe_old.map(|old| old.with_chunk((0, 1))));
+				if translated.is_err() {
+					log::error!(
+						target: LOG_TARGET,
+						"failed to decode the buffered session report, it is dropped"
+					);
+					IncompleteSessionReport::<T>::kill();
+				}
+				T::DbWeight::get().reads_writes(1, 1)
+			}
 		}
+
+		pub type MigrateV1ToV2<T> = VersionedMigration<
+			1,
+			2,
+			VersionUncheckedMigrateV1ToV2<T>,
+			Pallet<T>,
+			<T as frame_system::Config>::DbWeight,
+		>;
 	}
 }
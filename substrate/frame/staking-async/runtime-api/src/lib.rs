@@ -19,12 +19,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, HasCompact, MaxEncodedLen};
 
 sp_api::decl_runtime_apis! {
 	pub trait StakingApi<Balance, AccountId>
 		where
-			Balance: Codec,
+			Balance: Codec + HasCompact + MaxEncodedLen,
 			AccountId: Codec,
 	{
 		/// Returns the nominations quota for a nominator with a given balance.
@@ -35,5 +38,350 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns the eras that are still within the bonding window, along with the session index
+		/// at which each of them started.
+		///
+		/// An offence that happened in a session before the first session of this list can no
+		/// longer be slashed. Contains at most `BondingDuration + 1` entries.
+		#[api_version(2)]
+		fn bonded_eras() -> Vec<(sp_staking::EraIndex, sp_staking::SessionIndex)>;
+
+		/// Returns the ideal number of active validators, as set by governance.
+		///
+		/// Note that the actual number of elected validators can be lower than this.
+		#[api_version(3)]
+		fn intended_validator_count() -> u32;
+
+		/// Returns the minimum number of validators an election must yield for its result to be
+		/// used as-is.
+		#[api_version(3)]
+		fn minimum_validator_count() -> u32;
+
+		/// Returns true if `stash` would currently be eligible for fast-unstake.
+		///
+		/// That is, `stash` is bonded and chilled, has no funds unlocking, and has not been exposed
+		/// in any era within the bonding duration.
+		#[api_version(4)]
+		fn fast_unstake_eligible(stash: AccountId) -> bool;
+
+		/// Returns the share of the era reward earned by `validator` in `era`, based on its era
+		/// points.
+		///
+		/// This is the amount split between the validator and its nominators, before commission.
+		/// Returns `None` if the validator has no points, or the era reward is not yet known.
+		#[api_version(5)]
+		fn estimate_validator_era_reward(era: sp_staking::EraIndex, validator: AccountId) -> Option<Balance>;
+
+		/// Returns whether `stash` is a validator, a nominator, or idle.
+		///
+		/// Returns `None` if `stash` is not bonded.
+		#[api_version(6)]
+		fn staker_status(stash: AccountId) -> Option<sp_staking::StakerStatus<AccountId>>;
+
+		/// Returns the commission and the `blocked` flag of all validators elected in the active
+		/// era, as they were at the time of election.
+		#[api_version(7)]
+		fn validator_commissions() -> Vec<(AccountId, sp_runtime::Perbill, bool)>;
+
+		/// Returns all stashes exposed in `era`, i.e. the elected validators and their nominators,
+		/// which are slashable for offences committed in that era.
+		///
+		/// This iterates over all exposure pages of the era, so the result is potentially large.
+		#[api_version(8)]
+		fn exposed_stashes(era: sp_staking::EraIndex) -> Vec<AccountId>;
+
+		/// Returns how much of the stake of `nominator` backed each validator it was exposed to in
+		/// `era`, as assigned by the election, sorted by validator.
+		///
+		/// This is read from the exposures of `era`, regardless of what `nominator` nominates now,
+		/// and iterates over all exposure pages of the era.
+		#[api_version(9)]
+		fn nominator_exposure(era: sp_staking::EraIndex, nominator: AccountId) -> Vec<(AccountId, Balance)>;
+
+		/// Returns the unlocking chunks of `stash` as `(era, value)` pairs, sorted by era.
+		///
+		/// `value` becomes withdrawable at the start of `era`. Returns an empty vec if nothing is
+		/// unbonding.
+		#[api_version(10)]
+		fn unbonding_schedule(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns the progress of the election snapshot as `(pages_done, total_pages)`.
+		///
+		/// Returns `None` if no election is ongoing.
+		#[api_version(11)]
+		fn election_snapshot_progress() -> Option<(u32, u32)>;
+
+		/// Checks whether `stash` could currently nominate `targets`, running the same validation
+		/// as the `nominate` extrinsic, without mutating any state.
+		#[api_version(12)]
+		fn can_nominate(stash: AccountId, targets: Vec<AccountId>) -> Result<(), sp_staking::NominateError>;
+
+		/// Returns the validators elected for `era`, i.e. those with an exposure in that era.
+		///
+		/// Returns `None` if `era` is not planned yet, or already pruned.
+		#[api_version(13)]
+		fn historical_validator_set(era: sp_staking::EraIndex) -> Option<Vec<AccountId>>;
+
+		/// Returns every reward page of `validator` in `era`, and whether it has been claimed.
+		///
+		/// Returns an empty vec if `validator` was not exposed in `era`.
+		#[api_version(14)]
+		fn page_claim_status(era: sp_staking::EraIndex, validator: AccountId) -> Vec<(sp_staking::Page, bool)>;
+
+		/// Returns the total amount currently bonded across all staking ledgers, including funds
+		/// that are unbonding but not yet withdrawn.
+		#[api_version(15)]
+		fn total_bonded() -> Balance;
+
+		/// Returns the phase of the ongoing election, including the number of blocks left in the
+		/// signed, signed validation and unsigned phases.
+		#[api_version(16)]
+		fn election_phase() -> sp_staking::ElectionPhase<sp_runtime::traits::NumberFor<Block>>;
+
+		/// Returns the fraction that `offenders_count` validators equivocating in the same time
+		/// slot, out of `validator_count`, are each slashed by.
+		///
+		/// Useful to preview the slash fraction of an offence before it is submitted.
+		#[api_version(17)]
+		fn slash_fraction_for(offenders_count: u32, validator_count: u32) -> sp_runtime::Perbill;
+
+		/// Returns the number of eras since `stash` last submitted its nominations.
+		///
+		/// Returns `None` if `stash` is not a nominator.
+		#[api_version(18)]
+		fn nomination_age(stash: AccountId) -> Option<sp_staking::EraIndex>;
+
+		/// Returns whether `who` is a validator that blocks new nominations.
+		#[api_version(19)]
+		fn is_validator_blocked(who: AccountId) -> bool;
+
+		/// Returns how the rewards of `era` were split between stakers, the reward remainder and
+		/// burned rewards.
+		///
+		/// Returns `None` if `era` has not ended yet, or is already pruned.
+		#[api_version(20)]
+		fn era_reward_breakdown(era: sp_staking::EraIndex) -> Option<sp_staking::RewardBreakdown<Balance>>;
+
+		/// Returns the deferred slashes `stash` is exposed to, either as the offending validator or
+		/// as one of its nominators, as `(era the slash is applied in, amount)` sorted by era.
+		///
+		/// Amounts are projections; the slash can still be cancelled by governance.
+		#[api_version(21)]
+		fn pending_slashes(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns the exposure summary of `validator` in `era`: its total and own stake, and the
+		/// number of its nominators and exposure pages.
+		///
+		/// Returns `None` if `validator` was not elected in `era`, or `era` is already pruned.
+		#[api_version(22)]
+		fn exposure_overview(era: sp_staking::EraIndex, validator: AccountId) -> Option<sp_staking::PagedExposureMetadata<Balance>>;
+
+		/// Returns the targets `nominator` currently nominates, or an empty list if it is not a
+		/// nominator.
+		#[api_version(23)]
+		fn targets_of(nominator: AccountId) -> Vec<AccountId>;
+
+		/// Returns the nominators that currently have `validator` among their targets.
+		///
+		/// This is the reverse of [`Self::targets_of`], served from an index kept in storage.
+		#[api_version(23)]
+		fn backers_of(validator: AccountId) -> Vec<AccountId>;
+
+		/// Returns the annualized return of `nominator`, net of validator commission.
+		///
+		/// This is an estimate, projected from the rewards and duration of the last ended era
+		/// only, for the validators `nominator` was exposed to in that era. It can exceed one.
+		/// Returns `None` if `nominator` was not exposed in that era.
+		#[api_version(24)]
+		fn estimate_nominator_apr(nominator: AccountId) -> Option<sp_runtime::FixedU128>;
+
+		/// Returns the era that `session` belongs to.
+		///
+		/// Returns `None` if the era is no longer bonded, or `session` is past the planned end of
+		/// the active era.
+		#[api_version(25)]
+		fn era_for_session(session: sp_staking::SessionIndex) -> Option<sp_staking::EraIndex>;
+
+		/// Returns the sessions of `era`, or an empty list if `era` is not bonded.
+		///
+		/// For the active era, these are the sessions it is planned to last.
+		#[api_version(25)]
+		fn sessions_in_era(era: sp_staking::EraIndex) -> Vec<sp_staking::SessionIndex>;
+
+		/// Returns the controller of each of `stashes`, or `None` for those that are not bonded.
+		///
+		/// At most `MAX_BATCH_LOOKUP` stashes are looked up; the rest are ignored and missing from
+		/// the result.
+		#[api_version(26)]
+		fn bonded_many(stashes: Vec<AccountId>) -> Vec<Option<AccountId>>;
+
+		/// Returns whether a ledger exists for each of `controllers`.
+		///
+		/// At most `MAX_BATCH_LOOKUP` controllers are looked up; the rest are ignored and missing
+		/// from the result.
+		#[api_version(26)]
+		fn ledger_exists_many(controllers: Vec<AccountId>) -> Vec<bool>;
+
+		/// Returns the era in which `who` became a validator, if it is one.
+		///
+		/// Returns `None` for validators that became one before this was recorded.
+		#[api_version(27)]
+		fn validator_since(who: AccountId) -> Option<sp_staking::EraIndex>;
+
+		/// Returns up to `n` of the most recent rewards paid out to `stash`, as `(era, amount)`,
+		/// newest first.
+		///
+		/// Only the last `MaxRecentRewards` eras within the history depth are kept.
+		#[api_version(28)]
+		fn recent_rewards(stash: AccountId, n: u32) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns whether `validator` currently has more backers than were exposed for it in
+		/// `era`, i.e. whether some of its backers were not exposed nor rewarded.
+		///
+		/// Backers that started nominating `validator` after the election of `era` are counted
+		/// too. Returns `false` if `validator` was not exposed in `era`.
+		#[api_version(29)]
+		fn validator_oversubscribed(era: sp_staking::EraIndex, validator: AccountId) -> bool;
+
+		/// Returns what paying out `page` of `validator`'s rewards for `era` would do, without
+		/// doing it.
+		///
+		/// Useful to estimate the weight of a batch of payouts.
+		#[api_version(30)]
+		fn dry_run_payout(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			page: sp_staking::Page,
+		) -> sp_staking::PayoutDryRun;
+
+		/// Returns the total stake backing the lowest-backed validator of the last election.
+		///
+		/// This is the stake a validator needed to be elected.
+		#[api_version(31)]
+		fn minimum_elected_stake() -> Balance;
+
+		/// Returns the amount of `stash`'s bond that is unbonding, i.e. not active anymore but not
+		/// yet withdrawn.
+		///
+		/// Returns zero if `stash` is not bonded.
+		#[api_version(32)]
+		fn total_unlocking(stash: AccountId) -> Balance;
+
+		/// Returns whether `stash` was chilled and has not validated or nominated since.
+		#[api_version(33)]
+		fn is_chilled(stash: AccountId) -> bool;
+
+		/// Returns the era in which `stash` was chilled, if it has not validated or nominated
+		/// since.
+		///
+		/// Returns `None` for stashes that never validated nor nominated.
+		#[api_version(33)]
+		fn chilled_since(stash: AccountId) -> Option<sp_staking::EraIndex>;
+
+		/// Returns the total commission paid out to `validator`, across all eras and pages.
+		#[api_version(34)]
+		fn validator_total_commission(validator: AccountId) -> Balance;
+
+		/// Returns the status of the fast-unstake queue, as `(queued, processing)`.
+		///
+		/// `queued` is the number of stashes waiting in the queue, and `processing` is whether a
+		/// request is currently being checked, i.e. whether the queue has a head.
+		#[api_version(35)]
+		fn fast_unstake_queue_status() -> (u32, bool);
+
+		/// Returns the reward points of `era`, as the total and the points of each validator.
+		///
+		/// The per-validator list is bounded by the size of the elected validator set. Returns
+		/// `None` if `era` is not yet active or has been pruned.
+		#[api_version(36)]
+		fn era_points(era: sp_staking::EraIndex) -> Option<(u32, Vec<(AccountId, u32)>)>;
+
+		/// Returns the hash of the full exposure of `validator` in `era`, with its nominators
+		/// sorted by account.
+		///
+		/// Clients can use it to verify a claimed exposure without fetching all of its pages.
+		/// Returns `None` if `validator` was not exposed in `era`, or `era` has been pruned.
+		#[api_version(37)]
+		fn validator_exposure_hash(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_core::H256>;
+
+		/// Returns the upper score threshold of the voter-list bag `stash` is currently in.
+		///
+		/// Returns `None` if `stash` is not in the voter list.
+		#[api_version(38)]
+		fn voter_bag(stash: AccountId) -> Option<u64>;
+
+		/// Returns where the rewards of `stash` are paid.
+		///
+		/// Returns `None` if `stash` is not bonded.
+		#[api_version(39)]
+		fn reward_destination(stash: AccountId) -> Option<sp_staking::RewardDestination<AccountId>>;
+
+		/// Returns the staking rate, i.e. the share of the total issuance staked in the active era.
+		///
+		/// This is a snapshot: the stake is as of the election of the active era, while the
+		/// issuance is current.
+		#[api_version(40)]
+		fn staking_rate() -> sp_runtime::Perquintill;
+
+		/// Returns the minimum active bond required to nominate.
+		///
+		/// This is `MinNominatorBond`, but never less than the existential deposit.
+		#[api_version(41)]
+		fn min_nominator_bond() -> Balance;
+
+		/// Returns the minimum active bond required to validate.
+		///
+		/// This is `MinValidatorBond`, but never less than the existential deposit.
+		#[api_version(41)]
+		fn min_validator_bond() -> Balance;
+
+		/// Returns the exposure of `validator` in `era` in the legacy clipped format, for tools
+		/// that predate paged exposures.
+		///
+		/// This is synthesized from the first exposure page: `others` are the nominators of that
+		/// page only, which are not necessarily the highest staked ones, while `total` and `own`
+		/// cover the whole exposure. Returns `None` if `validator` was not exposed in `era`.
+		#[api_version(42)]
+		fn eras_stakers_clipped(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::Exposure<AccountId, Balance>>;
+
+		/// Returns the exposure summary of `validator` in `era`, along with its exposure `page`.
+		///
+		/// The validator's own stake is only part of the summary for page zero, as it is only paid
+		/// out along with that page. Returns `None` if `validator` was not exposed in `era`, or
+		/// `page` is past its last page.
+		#[api_version(43)]
+		fn exposure_page(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			page: sp_staking::Page,
+		) -> Option<(sp_staking::PagedExposureMetadata<Balance>, sp_staking::ExposurePage<AccountId, Balance>)>;
+
+		/// Returns the number of deferred slashes that were applied in `era`, i.e. scheduled for
+		/// it and not cancelled.
+		///
+		/// Only known for the last `HistoryDepth` eras; zero otherwise.
+		#[api_version(44)]
+		fn slashes_applied_in(era: sp_staking::EraIndex) -> u32;
+
+		/// Returns the delegated staking agent that manages the stake of `stash`.
+		///
+		/// This is `stash` itself if it is an agent, e.g. the bonded account of a nomination pool,
+		/// or the agent it delegates to. `None` for stashes that bonded directly.
+		#[api_version(45)]
+		fn delegating_agent(stash: AccountId) -> Option<AccountId>;
+
+		/// Returns the slashes that are queued to be applied in `era`, e.g. for governance to review
+		/// deferred slashes before they are applied.
+		///
+		/// Empty if there are none.
+		#[api_version(46)]
+		fn unapplied_slashes(era: sp_staking::EraIndex) -> Vec<sp_staking::UnappliedSlash<AccountId, Balance>>;
 	}
 }
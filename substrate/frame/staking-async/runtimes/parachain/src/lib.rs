@@ -49,15 +49,18 @@ use cumulus_primitives_core::{AggregateMessageOrigin, ClaimQueueOffset, CoreSele
 use frame_support::{
 	construct_runtime, derive_impl,
 	dispatch::DispatchClass,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	ord_parameter_types, parameter_types,
 	traits::{
+		dynamic_params::AggregatedKeyValue,
 		fungible,
 		fungible::HoldConsideration,
 		fungibles,
 		tokens::{imbalance::ResolveAssetTo, nonfungibles_v2::Inspect},
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
-		ConstantStoragePrice, Equals, InstanceFilter, TransformOrigin, WithdrawReasons,
+		ConstantStoragePrice, EnsureOriginWithArg, Equals, Get, InstanceFilter, TransformOrigin,
+		WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, Weight, WeightToFee as _},
 	BoundedVec, PalletId,
@@ -652,6 +655,11 @@ pub enum ProxyType {
 	AssetManager,
 	/// Collator selection proxy. Can execute calls related to collator selection mechanism.
 	Collator,
+	/// Nomination pools proxy. Can execute calls from `nomination-pools`, but no transfers.
+	///
+	/// Encoding note: new variants must only ever be appended, as the SCALE index of each variant
+	/// is what is stored on-chain in `pallet_proxy::Proxies`. Appending requires no migration.
+	NominationPools,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -758,6 +766,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
+			ProxyType::NominationPools => {
+				matches!(c, RuntimeCall::NominationPools(..) | RuntimeCall::Utility(..))
+			},
 		}
 	}
 
@@ -769,6 +780,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Assets, ProxyType::AssetOwner) => true,
 			(ProxyType::Assets, ProxyType::AssetManager) => true,
 			(ProxyType::NonTransfer, ProxyType::Collator) => true,
+			(ProxyType::NonTransfer, ProxyType::NominationPools) => true,
 			_ => false,
 		}
 	}
@@ -890,9 +902,138 @@ parameter_types! {
 	pub const RelayOrigin: AggregateMessageOrigin = AggregateMessageOrigin::Parent;
 }
 
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// The length of collator sessions, see [`SessionPeriodManager`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod session {
+		/// The length of a collator session, in blocks.
+		///
+		/// Only set by the runtime when enacting [`NextPeriod`], so that the current session is
+		/// not cut short.
+		#[codec(index = 0)]
+		pub static Period: u32 = 6 * HOURS;
+
+		/// The block from which [`Period`] is counted. Only set by the runtime, to the last
+		/// session boundary whenever the period changes.
+		#[codec(index = 1)]
+		pub static Offset: u32 = 0;
+
+		/// A session period scheduled by governance, applied at the next session boundary.
+		#[codec(index = 2)]
+		pub static NextPeriod: Option<u32> = None;
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Session(dynamic_params::session::Parameters::NextPeriod(
+			dynamic_params::session::NextPeriod,
+			Some(Some(6 * HOURS)),
+		))
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+/// Defines what origin can modify which dynamic parameters.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use dynamic_params::session::ParametersKey::*;
+		use RuntimeParametersKey::*;
+
+		match key {
+			Session(NextPeriod(_)) => frame_system::ensure_root(origin.clone()),
+			// changing these directly would cut the current session short.
+			Session(Period(_)) | Session(Offset(_)) => return Err(origin),
+		}
+		.map_err(|_| origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
 parameter_types! {
-	pub const Period: u32 = 6 * HOURS;
-	pub const Offset: u32 = 0;
+	/// The shortest session period that can be configured.
+	pub const MinSessionPeriod: u32 = 10 * MINUTES;
+}
+
+/// [`dynamic_params::session::Period`], never below [`MinSessionPeriod`].
+pub struct SessionPeriod;
+impl Get<u32> for SessionPeriod {
+	fn get() -> u32 {
+		dynamic_params::session::Period::get().max(MinSessionPeriod::get())
+	}
+}
+
+/// Sets a session parameter that [`DynamicParameterOrigin`] does not let anyone set.
+fn set_session_parameter(parameter: dynamic_params::session::Parameters) {
+	let (key, value) = RuntimeParameters::Session(parameter).into_parts();
+	pallet_parameters::Parameters::<Runtime>::set(key, value);
+}
+
+/// Apply any pending [`dynamic_params::session::NextPeriod`], counting the new period from the
+/// current block.
+///
+/// Must only be called at a session boundary.
+fn enact_next_session_period() {
+	use dynamic_params::session::{NextPeriod, Offset, Parameters, Period};
+
+	if let Some(period) = NextPeriod::get() {
+		set_session_parameter(Parameters::NextPeriod(NextPeriod, None));
+		set_session_parameter(Parameters::Period(
+			Period,
+			Some(period.max(MinSessionPeriod::get())),
+		));
+		set_session_parameter(Parameters::Offset(
+			Offset,
+			Some(frame_system::Pallet::<Runtime>::block_number()),
+		));
+	}
+}
+
+/// Wraps [`CollatorSelection`] to enact a pending [`dynamic_params::session::NextPeriod`] at
+/// session boundaries.
+///
+/// The collator selection runs first, so that its `KickThreshold` still matches the length of
+/// the session that just ended.
+pub struct SessionPeriodManager;
+impl pallet_session::SessionManager<AccountId> for SessionPeriodManager {
+	fn new_session(index: sp_staking::SessionIndex) -> Option<Vec<AccountId>> {
+		let validators =
+			<CollatorSelection as pallet_session::SessionManager<_>>::new_session(index);
+		enact_next_session_period();
+		validators
+	}
+	fn new_session_genesis(index: sp_staking::SessionIndex) -> Option<Vec<AccountId>> {
+		<CollatorSelection as pallet_session::SessionManager<_>>::new_session_genesis(index)
+	}
+	fn end_session(index: sp_staking::SessionIndex) {
+		<CollatorSelection as pallet_session::SessionManager<_>>::end_session(index)
+	}
+	fn start_session(index: sp_staking::SessionIndex) {
+		<CollatorSelection as pallet_session::SessionManager<_>>::start_session(index)
+	}
 }
 
 impl pallet_session::Config for Runtime {
@@ -900,9 +1041,11 @@ impl pallet_session::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	// we don't have stash and controller, thus we don't need the convert as well.
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
-	type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
-	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
-	type SessionManager = CollatorSelection;
+	type ShouldEndSession =
+		pallet_session::PeriodicSessions<SessionPeriod, dynamic_params::session::Offset>;
+	type NextSessionRotation =
+		pallet_session::PeriodicSessions<SessionPeriod, dynamic_params::session::Offset>;
+	type SessionManager = SessionPeriodManager;
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
@@ -936,7 +1079,7 @@ impl pallet_collator_selection::Config for Runtime {
 	type MinEligibleCollators = ConstU32<4>;
 	type MaxInvulnerables = ConstU32<20>;
 	// should be a multiple of session or things will get inconsistent
-	type KickThreshold = Period;
+	type KickThreshold = SessionPeriod;
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
@@ -1089,7 +1232,7 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations = pallet_staking_async::migrations::v20::LazyMigrationV19ToV20<Runtime>;
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1188,6 +1331,7 @@ construct_runtime!(
 		Whitelist: pallet_whitelist = 95,
 		Treasury: pallet_treasury = 96,
 		AssetRate: pallet_asset_rate = 97,
+		Parameters: pallet_parameters = 98,
 
 		// Balances.
 		Vesting: pallet_vesting = 100,
@@ -1231,6 +1375,14 @@ pub type UncheckedExtrinsic =
 
 /// Migrations to apply on runtime upgrade.
 pub type Migrations = (
+	// unreleased
+	pallet_staking_async::migrations::v18::MigrateV17ToV18<Runtime>,
+	pallet_staking_async::migrations::v19::MigrateV18ToV19<Runtime>,
+	pallet_staking_async::migrations::v20::MigrateV19ToV20<Runtime>,
+	pallet_staking_async::migrations::v21::MigrateV20ToV21<Runtime>,
+	pallet_staking_async::migrations::v22::MigrateV21ToV22<Runtime>,
+	pallet_staking_async::migrations::v23::MigrateV22ToV23<Runtime>,
+	pallet_staking_async_rc_client::migrations::v2::MigrateV1ToV2<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -1342,6 +1494,7 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_nfts, Nfts]
+		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_sudo, Sudo]
@@ -1780,6 +1933,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(46)]
 	impl pallet_staking_async_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -1789,9 +1943,248 @@ impl_runtime_apis! {
 			Staking::api_eras_stakers_page_count(era, account)
 		}
 
+		fn eras_stakers_clipped(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_staking::Exposure<AccountId, Balance>> {
+			Staking::api_eras_stakers_clipped(era, validator)
+		}
+
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn bonded_eras() -> Vec<(sp_staking::EraIndex, sp_staking::SessionIndex)> {
+			Staking::api_bonded_eras()
+		}
+
+		fn intended_validator_count() -> u32 {
+			Staking::api_intended_validator_count()
+		}
+
+		fn minimum_validator_count() -> u32 {
+			Staking::api_minimum_validator_count()
+		}
+
+		fn fast_unstake_eligible(stash: AccountId) -> bool {
+			Staking::api_fast_unstake_eligible(stash)
+		}
+
+		fn estimate_validator_era_reward(era: sp_staking::EraIndex, validator: AccountId) -> Option<Balance> {
+			Staking::api_estimate_validator_era_reward(era, validator)
+		}
+
+		fn staker_status(stash: AccountId) -> Option<sp_staking::StakerStatus<AccountId>> {
+			Staking::api_staker_status(stash)
+		}
+
+		fn validator_commissions() -> Vec<(AccountId, Perbill, bool)> {
+			Staking::api_validator_commissions()
+		}
+
+		fn exposed_stashes(era: sp_staking::EraIndex) -> Vec<AccountId> {
+			Staking::api_exposed_stashes(era)
+		}
+
+		fn nominator_exposure(era: sp_staking::EraIndex, nominator: AccountId) -> Vec<(AccountId, Balance)> {
+			Staking::api_nominator_exposure(era, nominator)
+		}
+
+		fn unbonding_schedule(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_unbonding_schedule(stash)
+		}
+
+		fn election_snapshot_progress() -> Option<(u32, u32)> {
+			MultiBlockElection::snapshot_progress()
+		}
+
+		fn can_nominate(stash: AccountId, targets: Vec<AccountId>) -> Result<(), sp_staking::NominateError> {
+			Staking::api_can_nominate(stash, targets)
+		}
+
+		fn historical_validator_set(era: sp_staking::EraIndex) -> Option<Vec<AccountId>> {
+			Staking::api_historical_validator_set(era)
+		}
+
+		fn page_claim_status(era: sp_staking::EraIndex, validator: AccountId) -> Vec<(sp_staking::Page, bool)> {
+			Staking::api_page_claim_status(era, validator)
+		}
+
+		fn total_bonded() -> Balance {
+			Staking::api_total_bonded()
+		}
+
+		fn election_phase() -> sp_staking::ElectionPhase<BlockNumber> {
+			use pallet_election_provider_multi_block::Phase;
+			use sp_staking::ElectionPhase;
+			match MultiBlockElection::current_phase() {
+				Phase::Off => ElectionPhase::Off,
+				Phase::Snapshot(remaining_pages) => ElectionPhase::Snapshot { remaining_pages },
+				Phase::Signed(remaining_blocks) => ElectionPhase::Signed { remaining_blocks },
+				Phase::SignedValidation(remaining_blocks) =>
+					ElectionPhase::SignedValidation { remaining_blocks },
+				Phase::Unsigned(remaining_blocks) => ElectionPhase::Unsigned { remaining_blocks },
+				Phase::Done => ElectionPhase::Done,
+				Phase::Export(_) => ElectionPhase::Export,
+				Phase::Emergency => ElectionPhase::Emergency,
+			}
+		}
+
+		fn slash_fraction_for(offenders_count: u32, validator_count: u32) -> Perbill {
+			sp_staking::offence::equivocation_slash_fraction(offenders_count, validator_count)
+		}
+
+		fn nomination_age(stash: AccountId) -> Option<sp_staking::EraIndex> {
+			Staking::api_nomination_age(stash)
+		}
+
+		fn is_validator_blocked(who: AccountId) -> bool {
+			Staking::api_is_validator_blocked(who)
+		}
+
+		fn era_reward_breakdown(era: sp_staking::EraIndex) -> Option<sp_staking::RewardBreakdown<Balance>> {
+			Staking::api_era_reward_breakdown(era)
+		}
+
+		fn pending_slashes(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_pending_slashes(stash)
+		}
+
+		fn slashes_applied_in(era: sp_staking::EraIndex) -> u32 {
+			Staking::api_slashes_applied_in(era)
+		}
+
+		fn unapplied_slashes(era: sp_staking::EraIndex) -> Vec<sp_staking::UnappliedSlash<AccountId, Balance>> {
+			Staking::api_unapplied_slashes(era)
+		}
+
+		fn exposure_overview(era: sp_staking::EraIndex, validator: AccountId) -> Option<sp_staking::PagedExposureMetadata<Balance>> {
+			Staking::api_exposure_overview(era, validator)
+		}
+
+		fn exposure_page(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			page: sp_staking::Page,
+		) -> Option<(sp_staking::PagedExposureMetadata<Balance>, sp_staking::ExposurePage<AccountId, Balance>)> {
+			Staking::api_exposure_page(era, validator, page)
+		}
+
+		fn targets_of(nominator: AccountId) -> Vec<AccountId> {
+			Staking::api_targets_of(nominator)
+		}
+
+		fn backers_of(validator: AccountId) -> Vec<AccountId> {
+			Staking::api_backers_of(validator)
+		}
+
+		fn estimate_nominator_apr(nominator: AccountId) -> Option<sp_runtime::FixedU128> {
+			Staking::api_estimate_nominator_apr(nominator)
+		}
+
+		fn era_for_session(session: sp_staking::SessionIndex) -> Option<sp_staking::EraIndex> {
+			Staking::api_era_for_session(session)
+		}
+
+		fn sessions_in_era(era: sp_staking::EraIndex) -> Vec<sp_staking::SessionIndex> {
+			Staking::api_sessions_in_era(era)
+		}
+
+		fn bonded_many(stashes: Vec<AccountId>) -> Vec<Option<AccountId>> {
+			Staking::api_bonded_many(stashes)
+		}
+
+		fn ledger_exists_many(controllers: Vec<AccountId>) -> Vec<bool> {
+			Staking::api_ledger_exists_many(controllers)
+		}
+
+		fn validator_since(who: AccountId) -> Option<sp_staking::EraIndex> {
+			Staking::api_validator_since(who)
+		}
+
+		fn recent_rewards(stash: AccountId, n: u32) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_recent_rewards(stash, n)
+		}
+
+		fn validator_oversubscribed(era: sp_staking::EraIndex, validator: AccountId) -> bool {
+			Staking::api_validator_oversubscribed(era, validator)
+		}
+
+		fn dry_run_payout(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+			page: sp_staking::Page,
+		) -> sp_staking::PayoutDryRun {
+			Staking::api_dry_run_payout(era, validator, page)
+		}
+
+		fn minimum_elected_stake() -> Balance {
+			Staking::api_minimum_elected_stake()
+		}
+
+		fn total_unlocking(stash: AccountId) -> Balance {
+			Staking::api_total_unlocking(stash)
+		}
+
+		fn is_chilled(stash: AccountId) -> bool {
+			Staking::api_is_chilled(stash)
+		}
+
+		fn chilled_since(stash: AccountId) -> Option<sp_staking::EraIndex> {
+			Staking::api_chilled_since(stash)
+		}
+
+		fn validator_total_commission(validator: AccountId) -> Balance {
+			Staking::api_validator_total_commission(validator)
+		}
+
+		fn era_points(era: sp_staking::EraIndex) -> Option<(u32, Vec<(AccountId, u32)>)> {
+			Staking::api_era_points(era)
+		}
+
+		fn validator_exposure_hash(
+			era: sp_staking::EraIndex,
+			validator: AccountId,
+		) -> Option<sp_core::H256> {
+			Staking::api_validator_exposure_hash(era, validator)
+		}
+
+		fn fast_unstake_queue_status() -> (u32, bool) {
+			(
+				pallet_fast_unstake::Queue::<Runtime>::count(),
+				pallet_fast_unstake::Head::<Runtime>::exists(),
+			)
+		}
+
+		fn voter_bag(stash: AccountId) -> Option<u64> {
+			pallet_bags_list::ListNodes::<Runtime, pallet_bags_list::Instance1>::get(&stash)
+				.map(|node| node.bag_upper)
+		}
+
+		fn reward_destination(stash: AccountId) -> Option<sp_staking::RewardDestination<AccountId>> {
+			Staking::api_reward_destination(stash)
+		}
+
+		fn staking_rate() -> sp_runtime::Perquintill {
+			Staking::api_staking_rate()
+		}
+
+		fn min_nominator_bond() -> Balance {
+			Staking::api_min_nominator_bond()
+		}
+
+		fn min_validator_bond() -> Balance {
+			Staking::api_min_validator_bond()
+		}
+
+		fn delegating_agent(stash: AccountId) -> Option<AccountId> {
+			if pallet_delegated_staking::Agents::<Runtime>::contains_key(&stash) {
+				Some(stash)
+			} else {
+				pallet_delegated_staking::Delegators::<Runtime>::get(&stash)
+					.map(|delegation| delegation.agent)
+			}
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -2263,3 +2656,57 @@ fn ensure_key_ss58() {
 		AccountId::from_ss58check("5F4EbSkZz18X36xhbsjvDNs6NuZ82HyYtq5UiJ1h9SBHJXZD").unwrap();
 	assert_eq!(acc, RootMigController::sorted_members()[0]);
 }
+
+#[test]
+fn session_period_change_applies_at_next_boundary() {
+	use frame_support::{assert_noop, assert_ok};
+	use pallet_session::ShouldEndSession;
+	type Sessions = <Runtime as pallet_session::Config>::ShouldEndSession;
+
+	sp_io::TestExternalities::new_empty().execute_with(|| {
+		let old = 6 * HOURS;
+		let new = HOURS;
+		let schedule = |period| {
+			pallet_parameters::Pallet::<Runtime>::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Session(dynamic_params::session::Parameters::NextPeriod(
+					dynamic_params::session::NextPeriod,
+					Some(Some(period)),
+				)),
+			)
+		};
+		assert_ok!(schedule(new));
+
+		// the current period can only be changed by the runtime.
+		assert_noop!(
+			pallet_parameters::Pallet::<Runtime>::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Session(dynamic_params::session::Parameters::Period(
+					dynamic_params::session::Period,
+					Some(new),
+				)),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// mimic `pallet_session`: check once per block, enact at each boundary.
+		let mut ends = vec![];
+		for now in 1..=(old + 3 * new) {
+			frame_system::Pallet::<Runtime>::set_block_number(now);
+			if Sessions::should_end_session(now) {
+				ends.push(now);
+				enact_next_session_period();
+			}
+		}
+
+		// the current session runs its full old length, then the new one kicks in.
+		assert_eq!(ends, vec![old, old + new, old + 2 * new, old + 3 * new]);
+		assert_eq!(dynamic_params::session::NextPeriod::get(), None);
+		assert_eq!(<Runtime as pallet_collator_selection::Config>::KickThreshold::get(), new);
+
+		// anything below the floor is clamped.
+		assert_ok!(schedule(1));
+		enact_next_session_period();
+		assert_eq!(SessionPeriod::get(), MinSessionPeriod::get());
+	});
+}
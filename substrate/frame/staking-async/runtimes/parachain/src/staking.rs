@@ -153,6 +153,12 @@ parameter_types! {
 	/// `ElectionProvider::DesiredTargets`. 1000 is the end-game for both Kusama and Polkadot for
 	/// the foreseeable future.
 	pub const MaxValidatorSet: u32 = 1000;
+	/// What to do if an election yields too few validators. With `MinimumValidatorCount` of 0,
+	/// this never applies.
+	pub const UnderSubscribedBehavior: pallet_staking_async::UnderSubscribedBehavior =
+		pallet_staking_async::UnderSubscribedBehavior::UseWhatWeGot;
+	pub const DeadPayeeBehavior: pallet_staking_async::DeadPayeeBehavior =
+		pallet_staking_async::DeadPayeeBehavior::Burn;
 
 	/// Number of nominators per page of the snapshot, and consequently number of backers in the
 	/// solution.
@@ -405,8 +411,9 @@ impl pallet_staking_async::EraPayout<Balance> for EraPayout {
 }
 
 parameter_types! {
-	// Six sessions in an era (6 hours).
-	pub const SessionsPerEra: SessionIndex = prod_or_fast!(6, 1);
+	// Six sessions in an era (6 hours). Settable via governance; a change applies from the next
+	// era onwards.
+	pub storage SessionsPerEra: SessionIndex = prod_or_fast!(6, 1);
 	/// Duration of a relay session in our blocks. Needs to be hardcoded per-runtime.
 	pub const RelaySessionDuration: BlockNumber = 10;
 	// 2 eras for unbonding (12 hours).
@@ -421,7 +428,12 @@ parameter_types! {
 	// Note: In WAH, this should be set closer to the ideal era duration to trigger capping more
 	// frequently. On Kusama and Polkadot, a higher value like 7 × ideal_era_duration is more
 	// appropriate.
-	pub const MaxEraDuration: u64 = RelaySessionDuration::get() as u64 * RELAY_CHAIN_SLOT_DURATION_MILLIS as u64 * SessionsPerEra::get() as u64;
+	//
+	// Based on the length of the active era, so that a pending `SessionsPerEra` change does not
+	// cap the era that is currently ending.
+	pub MaxEraDuration: u64 = RelaySessionDuration::get() as u64
+		* RELAY_CHAIN_SLOT_DURATION_MILLIS as u64
+		* pallet_staking_async::session_rotation::Rotator::<Runtime>::active_era_sessions() as u64;
 }
 
 impl pallet_staking_async::Config for Runtime {
@@ -437,20 +449,41 @@ impl pallet_staking_async::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type MaxSlashesPerBlock = ConstU32<1>;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
 	type EraPayout = EraPayout;
 	type MaxExposurePageSize = MaxExposurePageSize;
+	type MaxBackersExposed = ConstU32<{ u32::MAX }>;
 	type ElectionProvider = MultiBlockElection;
 	type VoterList = VoterList;
 	type TargetList = UseValidatorsMap<Self>;
 	type MaxValidatorSet = MaxValidatorSet;
+	type MinimumValidatorCount = frame_support::traits::ConstU32<0>;
+	type UnderSubscribedBehavior = UnderSubscribedBehavior;
+	type DeadPayeeBehavior = DeadPayeeBehavior;
 	type NominationsQuota = pallet_staking_async::FixedNominationsQuota<{ MaxNominations::get() }>;
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
+	type UnlockingChunkMergeWindow = frame_support::traits::ConstU32<0>;
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type MaxKicks = frame_support::traits::ConstU32<128>;
+	type MaxBlockedUpdates = frame_support::traits::ConstU32<64>;
 	type EventListeners = (NominationPools, DelegatedStaking);
 	type WeightInfo = weights::pallet_staking_async::WeightInfo<Runtime>;
 	type MaxInvulnerables = frame_support::traits::ConstU32<20>;
+	type AutoPayoutPerBlock = frame_support::traits::ConstU32<0>;
+	type PostSlashDustThreshold = ();
+	type MaxSlashReward = ();
+	type MaxReporters = frame_support::traits::ConstU32<16>;
+	type AllowAutoCompoundUnlocking = frame_support::traits::ConstBool<true>;
+	type SuppressSlashedNominations = frame_support::traits::ConstBool<false>;
+	type MaxRecentRewards = ConstU32<16>;
+	type RewardPointsCarryOver = ();
+	type SkipZeroRewardEras = frame_support::traits::ConstBool<false>;
+	type MaxNominatorsPerValidator = frame_support::traits::ConstU32<0>;
+	type AutoPayoutExpiring = frame_support::traits::ConstU32<0>;
+	type RoleChangeCooldown = frame_support::traits::ConstU32<0>;
+	type MinEraPointsForReward = frame_support::traits::ConstU32<0>;
 	type MaxEraDuration = MaxEraDuration;
 	type PlanningEraOffset =
 		pallet_staking_async::PlanningEraOffsetOf<Self, RelaySessionDuration, ConstU32<10>>;
@@ -461,6 +494,9 @@ impl pallet_staking_async_rc_client::Config for Runtime {
 	type RelayChainOrigin = EnsureRoot<AccountId>;
 	type AHStakingInterface = Staking;
 	type SendToRelayChain = StakingXcmToRelayChain;
+	type CompressSessionReports = ConstBool<false>;
+	// must match the relay chain's `MaxSkippedSessionReports`.
+	type MaxSkippedSessionReports = ConstU32<0>;
 }
 
 parameter_types! {
@@ -509,7 +545,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			StakingXcmDestination,
 			rc_client::ValidatorSetReport<Self::AccountId>,
 			ValidatorSetToXcm,
-		>::split_then_send(report, Some(8));
+		>::split_then_send(report, Some(8), None);
 	}
 }
 
@@ -31,10 +31,15 @@ pub mod pallet {
 	#[pallet::unbounded]
 	pub type Preset<T: Config> = StorageValue<_, alloc::string::String, OptionQuery>;
 
+	/// The number of validators the preset is expected to start with, if it declared one.
+	#[pallet::storage]
+	pub type ExpectedValidators<T: Config> = StorageValue<_, u32, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound, DebugNoBound, CloneNoBound, PartialEqNoBound, EqNoBound)]
 	pub struct GenesisConfig<T: Config> {
 		pub preset: alloc::string::String,
+		pub expected_validators: Option<u32>,
 		pub _marker: core::marker::PhantomData<T>,
 	}
 
@@ -42,6 +47,9 @@ pub mod pallet {
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			Preset::<T>::put(self.preset.clone());
+			if let Some(count) = self.expected_validators {
+				ExpectedValidators::<T>::put(count);
+			}
 		}
 	}
 
@@ -50,4 +58,72 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
+
+	impl<T: Config> Pallet<T> {
+		/// Assert that `count` validators is what the preset declared, if it declared any.
+		pub fn assert_validator_count(count: u32) {
+			if let Some(expected) = ExpectedValidators::<T>::get() {
+				assert_eq!(
+					count,
+					expected,
+					"incorrect validator count for {:?} preset",
+					Preset::<T>::get()
+				);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{self as preset_store, ExpectedValidators, Preset};
+	use frame::testing_prelude::*;
+
+	construct_runtime!(
+		pub enum Runtime {
+			System: frame_system,
+			PresetStore: preset_store,
+		}
+	);
+
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+	impl frame_system::Config for Runtime {
+		type Block = MockBlock<Self>;
+	}
+
+	impl preset_store::Config for Runtime {}
+
+	fn new_test_ext(preset: &str, expected_validators: Option<u32>) -> TestState {
+		RuntimeGenesisConfig {
+			preset_store: preset_store::GenesisConfig {
+				preset: preset.into(),
+				expected_validators,
+				..Default::default()
+			},
+			..Default::default()
+		}
+		.build_storage()
+		.unwrap()
+		.into()
+	}
+
+	#[test]
+	fn custom_preset_declares_its_validator_count() {
+		new_test_ext("custom", Some(3)).execute_with(|| {
+			assert_eq!(Preset::<Runtime>::get().as_deref(), Some("custom"));
+			assert_eq!(ExpectedValidators::<Runtime>::get(), Some(3));
+			PresetStore::assert_validator_count(3);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "incorrect validator count for Some(\"custom\") preset")]
+	fn wrong_validator_count_panics() {
+		new_test_ext("custom", Some(3)).execute_with(|| PresetStore::assert_validator_count(2));
+	}
+
+	#[test]
+	fn presets_without_a_count_are_not_checked() {
+		new_test_ext("custom", None).execute_with(|| PresetStore::assert_validator_count(42));
+	}
 }
@@ -162,6 +162,7 @@ fn westend_testnet_genesis(
 	)>,
 	root_key: AccountId,
 	preset: alloc::string::String,
+	expected_validators: u32,
 ) -> serde_json::Value {
 	let endowed_accounts =
 		Sr25519Keyring::well_known().map(|k| k.to_account_id()).collect::<Vec<_>>();
@@ -195,7 +196,11 @@ fn westend_testnet_genesis(
 		sudo: SudoConfig { key: Some(root_key) },
 		configuration: ConfigurationConfig { config: default_parachains_host_configuration() },
 		registrar: RegistrarConfig { next_free_para_id: polkadot_primitives::LOWEST_PUBLIC_ID },
-		preset_store: crate::PresetStoreConfig { preset, ..Default::default() },
+		preset_store: crate::PresetStoreConfig {
+			preset,
+			expected_validators: Some(expected_validators),
+			..Default::default()
+		},
 		staking_ah_client: StakingAhClientConfig {
 			operating_mode: pallet_staking_async_ah_client::OperatingMode::Active,
 			..Default::default()
@@ -215,16 +220,19 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 			],
 			Sr25519Keyring::Alice.to_account_id(),
 			id.to_string(),
+			4,
 		),
 		"real-s" => westend_testnet_genesis(
 			vec![get_authority_keys_from_seed("Alice"), get_authority_keys_from_seed("Bob")],
 			Sr25519Keyring::Alice.to_account_id(),
 			id.to_string(),
+			2,
 		),
 		"fake-s" => westend_testnet_genesis(
 			vec![get_authority_keys_from_seed("Alice"), get_authority_keys_from_seed("Bob")],
 			Sr25519Keyring::Alice.to_account_id(),
 			id.to_string(),
+			2,
 		),
 		_ => panic!("Unknown preset ID: {}", id),
 	};
@@ -239,3 +247,13 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 pub fn preset_names() -> Vec<PresetId> {
 	vec![PresetId::from("real-m"), PresetId::from("real-s"), PresetId::from("fake-s")]
 }
+
+#[test]
+fn presets_declare_their_validator_count() {
+	for (preset, expected) in [("real-m", 4), ("real-s", 2), ("fake-s", 2)] {
+		let patch: serde_json::Value =
+			serde_json::from_slice(&get_preset(&PresetId::from(preset)).unwrap()).unwrap();
+		assert_eq!(patch["presetStore"]["expectedValidators"], expected, "{preset}");
+		assert_eq!(patch["session"]["keys"].as_array().unwrap().len(), expected, "{preset}");
+	}
+}
@@ -38,9 +38,9 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration, tokens::UnityOrOuterConversion, ConstBool, ConstU32, Contains,
-		EitherOf, EitherOfDiverse, EnsureOriginWithArg, EverythingBut, FromContains,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, Nothing, ProcessMessage,
-		ProcessMessageError, VariantCountOf, WithdrawReasons,
+		EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, EverythingBut, FromContains,
+		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, Nothing, OnUnbalanced,
+		ProcessMessage, ProcessMessageError, VariantCountOf, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, WeightMeter, WeightToFee as _},
 	PalletId,
@@ -195,10 +195,22 @@ pub fn native_version() -> NativeVersion {
 /// locking the state of the pallet and preventing further updates to identities and sub-identities.
 /// The locked state will be the genesis state of a new system chain and then removed from the Relay
 /// Chain.
-pub struct IsIdentityCall;
-impl Contains<RuntimeCall> for IsIdentityCall {
+///
+/// Identity calls in `Allowed` are not identified, and therefore let through. See
+/// [`AllowedIdentityCalls`].
+pub struct IsIdentityCall<Allowed>(core::marker::PhantomData<Allowed>);
+impl<Allowed: Contains<RuntimeCall>> Contains<RuntimeCall> for IsIdentityCall<Allowed> {
+	fn contains(c: &RuntimeCall) -> bool {
+		matches!(c, RuntimeCall::Identity(_)) && !Allowed::contains(c)
+	}
+}
+
+/// Identity calls that remain callable while the rest of the pallet is locked, so that governance
+/// can clean up identities during the migration. These are all privileged calls.
+pub struct AllowedIdentityCalls;
+impl Contains<RuntimeCall> for AllowedIdentityCalls {
 	fn contains(c: &RuntimeCall) -> bool {
-		matches!(c, RuntimeCall::Identity(_))
+		matches!(c, RuntimeCall::Identity(pallet_identity::Call::kill_identity { .. }))
 	}
 }
 
@@ -209,7 +221,7 @@ parameter_types! {
 
 #[derive_impl(frame_system::config_preludes::RelayChainDefaultConfig)]
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = EverythingBut<IsIdentityCall>;
+	type BaseCallFilter = EverythingBut<IsIdentityCall<AllowedIdentityCalls>>;
 	type BlockWeights = BlockWeights;
 	type BlockLength = BlockLength;
 	type Nonce = Nonce;
@@ -263,6 +275,9 @@ pub mod dynamic_params {
 
 	/// Parameters used to calculate era payouts, see
 	/// [`polkadot_runtime_common::impls::EraPayoutParams`].
+	///
+	/// Note: no `EraPayout` reads these at the moment. This runtime uses `()`, and era payouts of
+	/// the staking-async parachain are computed from a fixed inflation rate.
 	#[dynamic_pallet_params]
 	#[codec(index = 0)]
 	pub mod inflation {
@@ -287,6 +302,64 @@ pub mod dynamic_params {
 		#[codec(index = 4)]
 		pub static UseAuctionSlots: bool = false;
 	}
+
+	/// Share of the block weight the `MessageQueue` pallet may spend servicing (UMP) messages.
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod message_queue {
+		/// Portion of `max_block` weight used to service messages in `on_initialize`.
+		#[codec(index = 0)]
+		pub static ServiceWeight: Perbill = Perbill::from_percent(20);
+
+		/// Portion of `max_block` weight that may be used to service messages in `on_idle`.
+		#[codec(index = 1)]
+		pub static IdleMaxServiceWeight: Perbill = Perbill::from_percent(20);
+	}
+
+	/// Where the periodic treasury burn goes, see [`ToOptionalAccount`].
+	#[dynamic_pallet_params]
+	#[codec(index = 2)]
+	pub mod treasury {
+		/// Account that receives the burned treasury funds. If `None`, they are burned.
+		#[codec(index = 0)]
+		pub static BurnDestination: Option<sp_runtime::AccountId32> = None;
+	}
+
+	/// Bounds of the election snapshot, see [`ElectionBounds`].
+	#[dynamic_pallet_params]
+	#[codec(index = 3)]
+	pub mod election {
+		/// Number of nominators taken as electing voters. Capped at [`MaxElectingVoters`].
+		#[codec(index = 0)]
+		pub static ElectingVoters: u32 = MaxElectingVoters::get();
+	}
+
+	/// Parameters of the validator disabling, see [`AlwaysDisableForSlashGreaterThan`].
+	#[dynamic_pallet_params]
+	#[codec(index = 4)]
+	pub mod disabling {
+		/// Minimum slash severity for which an offender is disabled.
+		#[codec(index = 0)]
+		pub static DisablingLimit: Perbill = Perbill::from_percent(25);
+	}
+
+	/// Where slashed funds go, see [`ToOptionalAccount`].
+	#[dynamic_pallet_params]
+	#[codec(index = 5)]
+	pub mod slashing {
+		/// Account that receives slashed funds. If `None`, they are burned.
+		#[codec(index = 0)]
+		pub static SlashDestination: Option<sp_runtime::AccountId32> = None;
+	}
+
+	/// Parameters of the era rotation, see [`pallet_staking::Config::SessionsPerEra`].
+	#[dynamic_pallet_params]
+	#[codec(index = 6)]
+	pub mod staking {
+		/// Number of sessions per era. A change also applies to the era in progress.
+		#[codec(index = 0)]
+		pub static SessionsPerEra: SessionIndex = 3;
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -319,6 +392,15 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 
 		match key {
 			Inflation(_) => frame_system::ensure_root(origin.clone()),
+			MessageQueue(_) => frame_system::ensure_root(origin.clone()),
+			Treasury(_) => frame_system::ensure_root(origin.clone()),
+			Election(_) => frame_system::ensure_root(origin.clone()),
+			Disabling(_) =>
+				EitherOf::<EnsureRoot<AccountId>, StakingAdmin>::ensure_origin(origin.clone()),
+			Slashing(_) =>
+				EitherOf::<EnsureRoot<AccountId>, StakingAdmin>::ensure_origin(origin.clone()),
+			Staking(_) =>
+				EitherOf::<EnsureRoot<AccountId>, StakingAdmin>::ensure_origin(origin.clone()),
 		}
 		.map_err(|_| origin)
 	}
@@ -560,8 +642,101 @@ impl<I: pallet_session::SessionManager<AccountId>> pallet_session::SessionManage
 	}
 }
 
+/// The index of each of the [`pallet_session::Validators`] in the set, so that an offender can be
+/// looked up directly rather than with a scan.
+///
+/// Rebuilt by [`IndexSessionValidators`] whenever a session starts.
+#[frame_support::storage_alias]
+pub type SessionValidatorIndex = StorageMap<Disabling, frame_support::Twox64Concat, AccountId, u32>;
+
+/// A `SessionManager` wrapper that rebuilds [`SessionValidatorIndex`] from the new
+/// [`pallet_session::Validators`] whenever a session starts, and otherwise defers to `I`.
+pub struct IndexSessionValidators<I>(core::marker::PhantomData<I>);
+
+impl<I> IndexSessionValidators<I> {
+	/// Rebuild [`SessionValidatorIndex`] from the current session validators.
+	///
+	/// Returns the weight consumed.
+	pub fn rebuild_index() -> Weight {
+		let removed = SessionValidatorIndex::clear(u32::MAX, None).unique;
+		let validators = pallet_session::Validators::<Runtime>::get();
+		for (index, validator) in validators.iter().enumerate() {
+			SessionValidatorIndex::insert(validator, index as u32);
+		}
+
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(1 + removed as u64, removed as u64 + validators.len() as u64)
+	}
+
+	/// The index of `who` in [`pallet_session::Validators`], if it is a session validator.
+	pub fn validator_index(who: &AccountId) -> Option<u32> {
+		SessionValidatorIndex::get(who)
+	}
+}
+
+impl<I: pallet_session::SessionManager<AccountId>> pallet_session::SessionManager<AccountId>
+	for IndexSessionValidators<I>
+{
+	fn end_session(end_index: SessionIndex) {
+		<I as pallet_session::SessionManager<_>>::end_session(end_index);
+	}
+	fn new_session(new_index: SessionIndex) -> Option<Vec<AccountId>> {
+		<I as pallet_session::SessionManager<_>>::new_session(new_index)
+	}
+	fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<AccountId>> {
+		<I as pallet_session::SessionManager<_>>::new_session_genesis(new_index)
+	}
+	fn start_session(start_index: SessionIndex) {
+		// `pallet_session::Validators` is already updated for `start_index` at this point.
+		frame_system::Pallet::<Runtime>::register_extra_weight_unchecked(
+			Self::rebuild_index(),
+			frame_support::dispatch::DispatchClass::Mandatory,
+		);
+		<I as pallet_session::SessionManager<_>>::start_session(start_index);
+	}
+}
+
+/// Benchmarks of [`IndexSessionValidators`].
+#[cfg(feature = "runtime-benchmarks")]
+pub mod session_validator_index_benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+
+	pub struct Pallet<T: Config>(core::marker::PhantomData<T>);
+	pub trait Config: pallet_session::Config {}
+	impl Config for Runtime {}
+
+	#[benchmarks]
+	mod benchmarks {
+		use super::*;
+
+		// `v`: session validators, e.g. 600 in Polkadot and 1000 in Kusama.
+		//
+		// The worst case replaces an index of as many other validators of the previous session.
+		#[benchmark]
+		fn rebuild_index(v: Linear<1, 1000>) {
+			let validators =
+				|name| (0..v).map(|i| account::<AccountId>(name, i, 0)).collect::<Vec<_>>();
+			pallet_session::Validators::<Runtime>::put(validators("previous"));
+			IndexSessionValidators::<()>::rebuild_index();
+			let current = validators("current");
+			pallet_session::Validators::<Runtime>::put(current.clone());
+
+			#[block]
+			{
+				IndexSessionValidators::<()>::rebuild_index();
+			}
+
+			assert_eq!(SessionValidatorIndex::iter().count(), v as usize);
+			assert_eq!(
+				IndexSessionValidators::<()>::validator_index(&current[v as usize - 1]),
+				Some(v - 1)
+			);
+		}
+	}
+}
+
 parameter_types! {
-	pub DisablingLimit: Perbill = Perbill::from_percent(25);
 	pub storage UsePreviousValidators: bool = false;
 }
 
@@ -571,6 +746,13 @@ parameter_types! {
 /// Only useful for testing.
 ///
 /// Never re-enables any validators.
+///
+/// The decision for each offender is made independently of any other offender, and of the
+/// `currently_disabled` set. Two offenders with equal severity are therefore never competing for
+/// a slot: both are disabled if they meet the threshold. The index that is disabled is the
+/// offender's position in [`pallet_session::Validators`], which holds no duplicates, so the
+/// decision is fully determined by the session validator set and is reproducible across nodes.
+/// The position is looked up in [`SessionValidatorIndex`] rather than by scanning the set.
 pub struct AlwaysDisableForSlashGreaterThan<S>(core::marker::PhantomData<S>);
 impl<S: Get<Perbill>> DisablingStrategy<Runtime> for AlwaysDisableForSlashGreaterThan<S> {
 	fn decision(
@@ -579,10 +761,7 @@ impl<S: Get<Perbill>> DisablingStrategy<Runtime> for AlwaysDisableForSlashGreate
 		_currently_disabled: &Vec<(u32, OffenceSeverity)>,
 	) -> DisablingDecision {
 		let meets_threshold = offender_slash_severity.0 >= S::get();
-		let offender_index = pallet_session::Validators::<Runtime>::get()
-			.iter()
-			.position(|v| v == offender_stash)
-			.map(|i| i as u32);
+		let offender_index = IndexSessionValidators::<()>::validator_index(offender_stash);
 		let disable = match offender_index {
 			Some(index) if meets_threshold => Some(index),
 			_ => {
@@ -605,12 +784,16 @@ impl pallet_session::Config for Runtime {
 	type ValidatorIdOf = IdentityValidatorIdeOf;
 	type ShouldEndSession = Babe;
 	type NextSessionRotation = Babe;
-	type SessionManager = MaybeUsePreviousValidatorsElse<
-		session_historical::NoteHistoricalRoot<Self, StakingAhClient>,
+	type SessionManager = IndexSessionValidators<
+		MaybeUsePreviousValidatorsElse<
+			session_historical::NoteHistoricalRoot<Self, StakingAhClient>,
+		>,
 	>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
-	type DisablingStrategy = AlwaysDisableForSlashGreaterThan<DisablingLimit>;
+	// Not `UpToStakeLimitDisablingStrategy`: stakes live on AssetHub, not here.
+	type DisablingStrategy =
+		AlwaysDisableForSlashGreaterThan<dynamic_params::disabling::DisablingLimit>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
@@ -625,6 +808,7 @@ impl session_historical::Config for Runtime {
 impl pallet_root_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OffenceHandler = StakingAhClient;
+	type WeightInfo = pallet_root_offences::weights::SubstrateWeight<Runtime>;
 }
 
 pub struct AssetHubLocation;
@@ -653,17 +837,61 @@ impl Convert<rc_client::SessionReport<AccountId>, Xcm<()>> for SessionReportToXc
 	}
 }
 
+pub struct CompressedSessionReportToXcm;
+impl Convert<rc_client::CompressedSessionReport<AccountId>, Xcm<()>>
+	for CompressedSessionReportToXcm
+{
+	fn convert(a: rc_client::CompressedSessionReport<AccountId>) -> Xcm<()> {
+		Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			Instruction::Transact {
+				origin_kind: OriginKind::Superuser,
+				fallback_max_weight: None,
+				call: AssetHubRuntimePallets::RcClient(
+					RcClientCalls::RelayCompressedSessionReport(a),
+				)
+				.encode()
+				.into(),
+			},
+		])
+	}
+}
+
 pub struct StakingXcmToAssetHub;
 impl ah_client::SendToAssetHub for StakingXcmToAssetHub {
 	type AccountId = AccountId;
 
-	fn relay_session_report(session_report: rc_client::SessionReport<Self::AccountId>) {
+	fn relay_session_report(
+		session_report: rc_client::SessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
 		rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			rc_client::SessionReport<AccountId>,
 			SessionReportToXcm,
-		>::split_then_send(session_report, Some(8));
+		>::split_then_send(
+			session_report,
+			Some(8),
+			Some(<Runtime as ah_client::Config>::MaxXcmMessageBytes::get()),
+		)
+	}
+
+	fn relay_compressed_session_report(
+		session_report: rc_client::CompressedSessionReport<Self::AccountId>,
+	) -> Option<(u32, u32)> {
+		rc_client::XCMSender::<
+			xcm_config::XcmRouter,
+			AssetHubLocation,
+			rc_client::CompressedSessionReport<AccountId>,
+			CompressedSessionReportToXcm,
+		>::split_then_send(
+			session_report,
+			Some(8),
+			Some(<Runtime as ah_client::Config>::MaxXcmMessageBytes::get()),
+		)
 	}
 
 	fn relay_new_offence(
@@ -702,10 +930,14 @@ enum AssetHubRuntimePallets<AccountId> {
 #[derive(Encode, Decode)]
 enum RcClientCalls<AccountId> {
 	/// A session with the given index has started.
-	#[codec(index = 0)]
+	///
+	/// Only accepted by AssetHub once it is upgraded, see `rc_client::migrations::v2`.
+	#[codec(index = 3)]
 	RelaySessionReport(rc_client::SessionReport<AccountId>),
 	#[codec(index = 1)]
 	RelayNewOffence(SessionIndex, Vec<rc_client::Offence<AccountId>>),
+	#[codec(index = 2)]
+	RelayCompressedSessionReport(rc_client::CompressedSessionReport<AccountId>),
 }
 
 pub struct EnsureAssetHub;
@@ -740,7 +972,16 @@ impl pallet_staking_async_ah_client::Config for Runtime {
 	type MinimumValidatorSetSize = ConstU32<1>;
 	type UnixTime = Timestamp;
 	type PointsPerBlock = ConstU32<20>;
+	type ExtraRewardPoints = ();
+	type MaxExtraPointsPerBlock = ConstU32<0>;
 	type MaxOffenceBatchSize = MaxOffenceBatchSize;
+	type MinOffenceDeliveryInterval = ConstU32<0>;
+	type AggregateOffences = ConstBool<false>;
+	type MaxXcmMessageBytes = ConstU32<{ 50 * 1024 }>;
+	type AlwaysReport = ConstBool<true>;
+	type MaxSkippedSessionReports = ConstU32<0>;
+	// AssetHub does not accept compressed session reports yet.
+	type CompressSessionReports = ConstBool<false>;
 	type Fallback = Staking;
 	type WeightInfo = ();
 }
@@ -768,12 +1009,18 @@ parameter_types! {
 	// 1 hour session, 15 minutes unsigned phase, 4 offchain executions.
 	pub OffchainRepeat: BlockNumber = UnsignedPhase::get() / 4;
 
+	/// The hard ceiling of electing voters, which [`dynamic_params::election::ElectingVoters`]
+	/// can only lower.
 	pub const MaxElectingVoters: u32 = 22_500;
-	/// We take the top 22500 nominators as electing voters and all of the validators as electable
-	/// targets. Whilst this is the case, we cannot and shall not increase the size of the
-	/// validator intentions.
+	/// We take the top [`dynamic_params::election::ElectingVoters`] nominators as electing voters
+	/// and all of the validators as electable targets. Whilst this is the case, we cannot and
+	/// shall not increase the size of the validator intentions.
 	pub ElectionBounds: frame_election_provider_support::bounds::ElectionBounds =
-		ElectionBoundsBuilder::default().voters_count(MaxElectingVoters::get().into()).build();
+		ElectionBoundsBuilder::default()
+			.voters_count(
+				dynamic_params::election::ElectingVoters::get().min(MaxElectingVoters::get()).into(),
+			)
+			.build();
 	// Maximum winners that can be chosen as active validators
 	pub const MaxActiveValidators: u32 = 1000;
 	// One page only, fill the whole page with the `MaxActiveValidators`.
@@ -881,7 +1128,6 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 }
 
 parameter_types! {
-	pub const SessionsPerEra: SessionIndex = 3;
 	pub const BondingDuration: sp_staking::EraIndex = 3;
 	pub const SlashDeferDuration: sp_staking::EraIndex = 1;
 	pub const MaxExposurePageSize: u32 = 64;
@@ -899,9 +1145,9 @@ impl pallet_staking::Config for Runtime {
 	type CurrencyToVote = sp_staking::currency_to_vote::U128CurrencyToVote;
 	type RewardRemainder = ();
 	type RuntimeEvent = RuntimeEvent;
-	type Slash = ();
+	type Slash = ToOptionalAccount<dynamic_params::slashing::SlashDestination>;
 	type Reward = ();
-	type SessionsPerEra = SessionsPerEra;
+	type SessionsPerEra = dynamic_params::staking::SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
@@ -959,6 +1205,30 @@ parameter_types! {
 	pub const MaxBalance: Balance = Balance::max_value();
 }
 
+/// Sends imbalances to the account returned by `Dest`, if any, and burns them otherwise.
+pub struct ToOptionalAccount<Dest>(core::marker::PhantomData<Dest>);
+impl<Dest: Get<Option<AccountId>>> OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>>
+	for ToOptionalAccount<Dest>
+{
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		if let Some(dest) = Dest::get() {
+			<Balances as frame_support::traits::Currency<_>>::resolve_creating(&dest, amount);
+		}
+	}
+}
+impl<Dest: Get<Option<AccountId>>>
+	OnUnbalanced<frame_support::traits::fungible::Credit<AccountId, Balances>>
+	for ToOptionalAccount<Dest>
+{
+	fn on_nonzero_unbalanced(amount: frame_support::traits::fungible::Credit<AccountId, Balances>) {
+		if let Some(dest) = Dest::get() {
+			// if the credit cannot be deposited, it is dropped, i.e. burned.
+			let _ =
+				<Balances as frame_support::traits::fungible::Balanced<_>>::resolve(&dest, amount);
+		}
+	}
+}
+
 impl pallet_treasury::Config for Runtime {
 	type PalletId = TreasuryPalletId;
 	type Currency = Balances;
@@ -966,7 +1236,7 @@ impl pallet_treasury::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
-	type BurnDestination = ();
+	type BurnDestination = ToOptionalAccount<dynamic_params::treasury::BurnDestination>;
 	type MaxApprovals = MaxApprovals;
 	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
 	type SpendFunds = ();
@@ -1421,13 +1691,33 @@ impl parachains_paras::Config for Runtime {
 	type AuthorizeCurrentCodeOrigin = EnsureRoot<AccountId>;
 }
 
+/// Amount of weight that can be spent per block to service messages, as set by
+/// [`dynamic_params::message_queue::ServiceWeight`].
+///
+/// # WARNING
+///
+/// This is not a good value for para-chains since the `Scheduler` already uses up to 80% block
+/// weight.
+pub struct MessageQueueServiceWeight;
+impl Get<Option<Weight>> for MessageQueueServiceWeight {
+	fn get() -> Option<Weight> {
+		Some(dynamic_params::message_queue::ServiceWeight::get() * BlockWeights::get().max_block)
+	}
+}
+
+/// Amount of weight that can be spent in `on_idle` to service messages, as set by
+/// [`dynamic_params::message_queue::IdleMaxServiceWeight`].
+pub struct MessageQueueIdleServiceWeight;
+impl Get<Option<Weight>> for MessageQueueIdleServiceWeight {
+	fn get() -> Option<Weight> {
+		Some(
+			dynamic_params::message_queue::IdleMaxServiceWeight::get() *
+				BlockWeights::get().max_block,
+		)
+	}
+}
+
 parameter_types! {
-	/// Amount of weight that can be spent per block to service messages.
-	///
-	/// # WARNING
-	///
-	/// This is not a good value for para-chains since the `Scheduler` already uses up to 80% block weight.
-	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
 	pub const MessageQueueHeapSize: u32 = 128 * 1024;
 	pub const MessageQueueMaxStale: u32 = 48;
 }
@@ -1460,7 +1750,7 @@ impl pallet_message_queue::Config for Runtime {
 	type HeapSize = MessageQueueHeapSize;
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
-	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type IdleMaxServiceWeight = MessageQueueIdleServiceWeight;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1970,10 +2260,20 @@ pub type Migrations = migrations::Unreleased;
 pub mod migrations {
 	use super::*;
 
+	/// Populates [`SessionValidatorIndex`] for the ongoing session, instead of waiting for the
+	/// next session to start.
+	pub struct BuildSessionValidatorIndex;
+	impl frame_support::traits::OnRuntimeUpgrade for BuildSessionValidatorIndex {
+		fn on_runtime_upgrade() -> Weight {
+			IndexSessionValidators::<()>::rebuild_index()
+		}
+	}
+
 	/// Unreleased migrations. Add new ones here:
 	pub type Unreleased = (
 		parachains_shared::migration::MigrateToV1<Runtime>,
 		parachains_scheduler::migration::MigrateV2ToV3<Runtime>,
+		BuildSessionValidatorIndex,
 		// permanent
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	);
@@ -2040,6 +2340,7 @@ mod benches {
 		[pallet_referenda, Referenda]
 		[pallet_scheduler, Scheduler]
 		[pallet_session, SessionBench::<Runtime>]
+		[session_validator_index, SessionValidatorIndexBench::<Runtime>]
 		[pallet_sudo, Sudo]
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
@@ -2673,6 +2974,7 @@ sp_api::impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 
 			use pallet_session_benchmarking::Pallet as SessionBench;
+			use session_validator_index_benchmarking::Pallet as SessionValidatorIndexBench;
 			use pallet_offences_benchmarking::Pallet as OffencesBench;
 			use pallet_election_provider_support_benchmarking::Pallet as ElectionProviderBench;
 			use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
@@ -2702,6 +3004,7 @@ sp_api::impl_runtime_apis! {
 			// Trying to add benchmarks directly to some pallets caused cyclic dependency issues.
 			// To get around that, we separated the benchmarks into its own crate.
 			use pallet_session_benchmarking::Pallet as SessionBench;
+			use session_validator_index_benchmarking::Pallet as SessionValidatorIndexBench;
 			use pallet_offences_benchmarking::Pallet as OffencesBench;
 			use pallet_election_provider_support_benchmarking::Pallet as ElectionProviderBench;
 			use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
@@ -2917,34 +3220,12 @@ sp_api::impl_runtime_apis! {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			let res = build_state::<RuntimeGenesisConfig>(config);
 
-			match PresetStore::preset().unwrap().as_str() {
-				"real-m" => {
-					log::info!(target: "runtime", "detected real-m preset");
-					assert_eq!(
-						pallet_session::Validators::<Runtime>::get().len(), 4,
-						"incorrect validator count for real-m preset"
-					);
-				},
-				"real-s" => {
-					log::info!(target: "runtime", "detected real-s preset");
-					assert_eq!(
-						pallet_session::Validators::<Runtime>::get().len(), 2,
-						"incorrect validator count for real-s preset"
-					);
-				},
-				"fake-s" => {
-					log::info!(target: "runtime", "detected fake-s preset");
-					assert_eq!(
-						pallet_session::Validators::<Runtime>::get().len(), 2,
-						"incorrect validator count for fake-s preset"
-					);
-					UsePreviousValidators::set(&true);
-				},
-				_ => {
-					panic!("unrecognized min nominator bond in genesis config: {}",
-						pallet_staking::MinNominatorBond::<Runtime>::get()
-					);
-				}
+			let preset = PresetStore::preset().unwrap();
+			log::info!(target: "runtime", "detected {} preset", preset);
+			let validators = pallet_session::Validators::<Runtime>::get().len() as u32;
+			PresetStore::assert_validator_count(validators);
+			if preset == "fake-s" {
+				UsePreviousValidators::set(&true);
 			}
 			res
 		}
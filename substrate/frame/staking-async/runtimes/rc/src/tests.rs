@@ -94,6 +94,32 @@ fn check_whitelist() {
 	assert!(whitelist.contains("1405f2411d0af5a7ff397e7c9dc68d196323ae84c43568be0d1394d5d0d522c4"));
 }
 
+#[test]
+fn only_allowed_identity_calls_pass_base_call_filter() {
+	use frame_support::traits::Contains;
+	use sp_runtime::MultiAddress;
+
+	type Filter = <Runtime as frame_system::Config>::BaseCallFilter;
+
+	// whitelisted identity calls pass.
+	let kill_identity = RuntimeCall::Identity(pallet_identity::Call::kill_identity {
+		target: MultiAddress::Id(Alice.into()),
+	});
+	assert!(Filter::contains(&kill_identity));
+
+	// the rest of the identity pallet is blocked.
+	let clear_identity = RuntimeCall::Identity(pallet_identity::Call::clear_identity {});
+	assert!(!Filter::contains(&clear_identity));
+	let add_registrar = RuntimeCall::Identity(pallet_identity::Call::add_registrar {
+		account: MultiAddress::Id(Alice.into()),
+	});
+	assert!(!Filter::contains(&add_registrar));
+
+	// other pallets are unaffected.
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	assert!(Filter::contains(&remark));
+}
+
 #[test]
 fn check_treasury_pallet_id() {
 	assert_eq!(
@@ -102,6 +128,301 @@ fn check_treasury_pallet_id() {
 	);
 }
 
+#[test]
+fn treasury_burn_goes_to_configured_destination() {
+	use frame_support::traits::Currency;
+	use sp_keyring::Sr25519Keyring::Bob;
+
+	let burn_with = |dest: Option<AccountId>| -> (Balance, Balance) {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Treasury(dynamic_params::treasury::Parameters::BurnDestination(
+					dynamic_params::treasury::BurnDestination,
+					Some(dest),
+				)),
+			)
+			.is_ok());
+
+			let _ = Balances::deposit_creating(&Treasury::account_id(), 1_000_000 * UNITS);
+			let issuance = Balances::total_issuance();
+			Treasury::spend_funds(1, 1);
+			(issuance - Balances::total_issuance(), Balances::free_balance(&Bob.into()))
+		})
+	};
+
+	let burn = Burn::get() * (1_000_000 * UNITS - ExistentialDeposit::get());
+	assert!(burn > 0);
+
+	// by default, the burn reduces issuance.
+	assert_eq!(burn_with(None), (burn, 0));
+	// with a destination, it lands there instead.
+	assert_eq!(burn_with(Some(Bob.into())), (0, burn));
+}
+
+#[test]
+fn equal_severity_offenders_are_disabled_deterministically() {
+	use sp_keyring::Sr25519Keyring::{Bob, Charlie};
+	sp_io::TestExternalities::default().execute_with(|| {
+		let validators: Vec<AccountId> = vec![Alice.into(), Bob.into(), Charlie.into()];
+		pallet_session::Validators::<Runtime>::put(validators);
+		IndexSessionValidators::<()>::rebuild_index();
+		let severity = OffenceSeverity(dynamic_params::disabling::DisablingLimit::get());
+
+		let decide = |who: AccountId, disabled: &Vec<(u32, OffenceSeverity)>| {
+			<Runtime as pallet_session::Config>::DisablingStrategy::decision(
+				&who, severity, disabled,
+			)
+		};
+
+		// both offenders are disabled at their own session index, regardless of the order in
+		// which they are reported.
+		let charlie = decide(Charlie.into(), &vec![]);
+		let bob = decide(Bob.into(), &vec![(2, severity)]);
+		assert_eq!((charlie.disable, charlie.reenable), (Some(2), None));
+		assert_eq!((bob.disable, bob.reenable), (Some(1), None));
+
+		let bob = decide(Bob.into(), &vec![]);
+		let charlie = decide(Charlie.into(), &vec![(1, severity)]);
+		assert_eq!((bob.disable, bob.reenable), (Some(1), None));
+		assert_eq!((charlie.disable, charlie.reenable), (Some(2), None));
+	});
+}
+
+#[test]
+fn lowering_disabling_limit_disables_more_offenders() {
+	use sp_keyring::Sr25519Keyring::Bob;
+	sp_io::TestExternalities::default().execute_with(|| {
+		let validators: Vec<AccountId> = vec![Alice.into(), Bob.into()];
+		pallet_session::Validators::<Runtime>::put(validators);
+		IndexSessionValidators::<()>::rebuild_index();
+
+		let severity = OffenceSeverity(Perbill::from_percent(10));
+		let decide = || {
+			<Runtime as pallet_session::Config>::DisablingStrategy::decision(
+				&Bob.into(),
+				severity,
+				&vec![],
+			)
+			.disable
+		};
+		let set_limit = |origin: RuntimeOrigin, limit: Perbill| {
+			Parameters::set_parameter(
+				origin,
+				RuntimeParameters::Disabling(
+					dynamic_params::disabling::Parameters::DisablingLimit(
+						dynamic_params::disabling::DisablingLimit,
+						Some(limit),
+					),
+				),
+			)
+		};
+
+		// below the default limit, the offender is not disabled.
+		assert_eq!(decide(), None);
+
+		// only root or the staking admin can change the limit.
+		assert!(set_limit(RuntimeOrigin::signed(Alice.into()), Perbill::from_percent(10)).is_err());
+		assert!(set_limit(
+			pallet_custom_origins::Origin::StakingAdmin.into(),
+			Perbill::from_percent(10)
+		)
+		.is_ok());
+
+		// now the same offence disables the offender.
+		assert_eq!(decide(), Some(1));
+	});
+}
+
+#[test]
+fn session_validator_index_matches_linear_scan() {
+	use pallet_session::SessionManager;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		// not sorted by account, so that the index differs from the position in the snapshot.
+		let validators = |range: core::ops::Range<u8>| -> Vec<AccountId> {
+			range.rev().map(|i| AccountId::from([i; 32])).collect()
+		};
+
+		let mut previous = 0u64;
+		for (session, set) in [validators(0..100), validators(50..120)].into_iter().enumerate() {
+			pallet_session::Validators::<Runtime>::put(set.clone());
+			let weight_before = frame_system::Pallet::<Runtime>::block_weight().total();
+			IndexSessionValidators::<()>::start_session(session as u32);
+
+			// the rebuild, removing the index of the previous session, is accounted for.
+			assert_eq!(
+				frame_system::Pallet::<Runtime>::block_weight().total(),
+				weight_before.saturating_add(
+					<Runtime as frame_system::Config>::DbWeight::get()
+						.reads_writes(1 + previous, previous + set.len() as u64)
+				)
+			);
+			previous = set.len() as u64;
+			for (position, validator) in set.iter().enumerate() {
+				assert_eq!(
+					IndexSessionValidators::<()>::validator_index(validator),
+					Some(position as u32)
+				);
+			}
+		}
+
+		// validators of the previous session only are no longer indexed.
+		assert_eq!(IndexSessionValidators::<()>::validator_index(&AccountId::from([0; 32])), None);
+		assert_eq!(SessionValidatorIndex::iter().count(), 70);
+	});
+}
+
+#[test]
+fn slashed_funds_go_to_configured_destination() {
+	use frame_support::traits::{Currency, OnUnbalanced};
+	use sp_keyring::Sr25519Keyring::Bob;
+
+	let slash_with = |dest: Option<AccountId>| -> (Balance, Balance) {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Slashing(
+					dynamic_params::slashing::Parameters::SlashDestination(
+						dynamic_params::slashing::SlashDestination,
+						Some(dest),
+					)
+				),
+			)
+			.is_ok());
+
+			let _ = Balances::deposit_creating(&Alice.into(), 1_000 * UNITS);
+			let issuance = Balances::total_issuance();
+			let (slashed, _) = Balances::slash(&Alice.into(), 100 * UNITS);
+			<Runtime as pallet_staking::Config>::Slash::on_unbalanced(slashed);
+			(issuance - Balances::total_issuance(), Balances::free_balance(&Bob.into()))
+		})
+	};
+
+	// by default, slashed funds are burned.
+	assert_eq!(slash_with(None), (100 * UNITS, 0));
+	// with a destination, they land there instead.
+	assert_eq!(slash_with(Some(Bob.into())), (0, 100 * UNITS));
+}
+
+#[test]
+fn lowering_electing_voters_shrinks_the_snapshot() {
+	use frame_support::{assert_ok, traits::Currency};
+
+	let snapshot_voters_with = |electing_voters: u32| -> u32 {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_ok!(Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Election(dynamic_params::election::Parameters::ElectingVoters(
+					dynamic_params::election::ElectingVoters,
+					Some(electing_voters),
+				)),
+			));
+
+			let validator = AccountId::from([0; 32]);
+			for i in 0..10u8 {
+				let who = AccountId::from([i; 32]);
+				let _ = Balances::make_free_balance_be(&who, 1_000 * UNITS);
+				assert_ok!(Staking::bond(
+					RuntimeOrigin::signed(who.clone()),
+					100 * UNITS,
+					pallet_staking::RewardDestination::Stash,
+				));
+				if i == 0 {
+					assert_ok!(Staking::validate(RuntimeOrigin::signed(who), Default::default()));
+				} else {
+					assert_ok!(Staking::nominate(
+						RuntimeOrigin::signed(who),
+						vec![validator.clone().into()]
+					));
+				}
+			}
+
+			assert_ok!(ElectionProviderMultiPhase::create_snapshot());
+			pallet_election_provider_multi_phase::SnapshotMetadata::<Runtime>::get()
+				.unwrap()
+				.voters
+		})
+	};
+
+	assert_eq!(snapshot_voters_with(MaxElectingVoters::get()), 10);
+	assert_eq!(snapshot_voters_with(4), 4);
+
+	// the compile-time ceiling cannot be raised.
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Election(dynamic_params::election::Parameters::ElectingVoters(
+				dynamic_params::election::ElectingVoters,
+				Some(u32::MAX),
+			)),
+		));
+		assert_eq!(ElectionBounds::get().voters.count, Some(MaxElectingVoters::get().into()));
+	});
+}
+
+#[test]
+fn sessions_per_era_is_configurable() {
+	use frame_support::traits::Get;
+	sp_io::TestExternalities::default().execute_with(|| {
+		let set_sessions = |origin: RuntimeOrigin, sessions: SessionIndex| {
+			Parameters::set_parameter(
+				origin,
+				RuntimeParameters::Staking(dynamic_params::staking::Parameters::SessionsPerEra(
+					dynamic_params::staking::SessionsPerEra,
+					Some(sessions),
+				)),
+			)
+		};
+		let sessions_per_era = <Runtime as pallet_staking::Config>::SessionsPerEra::get;
+
+		assert_eq!(sessions_per_era(), 3);
+
+		// only root or the staking admin can change the era length.
+		assert!(set_sessions(RuntimeOrigin::signed(Alice.into()), 6).is_err());
+		assert!(set_sessions(pallet_custom_origins::Origin::StakingAdmin.into(), 6).is_ok());
+		assert_eq!(sessions_per_era(), 6);
+	});
+}
+
+#[test]
+fn raising_idle_service_weight_drains_more_messages() {
+	use frame_support::{
+		traits::{EnqueueMessage, Hooks, QueueFootprintQuery},
+		BoundedSlice,
+	};
+	use polkadot_runtime_parachains::inclusion::{AggregateMessageOrigin, UmpQueueId};
+
+	let origin = AggregateMessageOrigin::Ump(UmpQueueId::Para(2000.into()));
+	let drained_with = |idle: Perbill| -> u64 {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::MessageQueue(
+					dynamic_params::message_queue::Parameters::IdleMaxServiceWeight(
+						dynamic_params::message_queue::IdleMaxServiceWeight,
+						Some(idle),
+					),
+				),
+			)
+			.is_ok());
+
+			let message = [0u8; 32];
+			MessageQueue::enqueue_messages(
+				(0..1000).map(|_| BoundedSlice::truncate_from(&message[..])),
+				origin.clone(),
+			);
+			let before = MessageQueue::footprint(origin.clone()).storage.count;
+			MessageQueue::on_idle(1, BlockWeights::get().max_block);
+			before - MessageQueue::footprint(origin.clone()).storage.count
+		})
+	};
+
+	let low = drained_with(Perbill::from_percent(1));
+	let high = drained_with(Perbill::from_percent(20));
+	assert!(high > low, "raising the idle weight should drain more: {low} vs {high}");
+}
+
 #[cfg(all(test, feature = "try-runtime"))]
 mod remote_tests {
 	use super::*;
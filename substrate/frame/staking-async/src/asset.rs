@@ -22,7 +22,7 @@ use crate::{BalanceOf, Config, HoldReason, NegativeImbalanceOf, PositiveImbalanc
 use frame_support::traits::{
 	fungible::{
 		hold::{Balanced as FunHoldBalanced, Inspect as FunHoldInspect, Mutate as FunHoldMutate},
-		Balanced, Inspect as FunInspect,
+		Balanced, Inspect as FunInspect, Mutate as FunMutate,
 	},
 	tokens::{Fortitude, Precision, Preservation},
 };
@@ -80,6 +80,17 @@ pub fn kill_stake<T: Config>(who: &T::AccountId) -> DispatchResult {
 	T::Currency::release_all(&HoldReason::Staking.into(), who, Precision::BestEffort).map(|_| ())
 }
 
+/// Transfer `amount` of unstaked balance from `who` to `dest`.
+///
+/// `who` may be reaped if it is left with nothing else.
+pub fn transfer<T: Config>(
+	who: &T::AccountId,
+	dest: &T::AccountId,
+	amount: BalanceOf<T>,
+) -> DispatchResult {
+	T::Currency::transfer(who, dest, amount, Preservation::Expendable).map(|_| ())
+}
+
 /// Slash the value from `who`.
 ///
 /// A negative imbalance is returned which can be resolved to deposit the slashed value.
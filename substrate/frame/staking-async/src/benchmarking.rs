@@ -382,10 +382,10 @@ mod benchmarks {
 	#[benchmark]
 	fn kick(
 		// scenario: we want to kick `k` nominators from nominating us (we are a validator).
-		// we'll assume that `k` is under 128 for the purposes of determining the slope.
+		// `k` is bounded by `T::MaxKicks`.
 		// each nominator should have `T::MaxNominations::get()` validators nominated, and our
 		// validator should be somewhere in there.
-		k: Linear<1, 128>,
+		k: Linear<1, { T::MaxKicks::get() }>,
 	) -> Result<(), BenchmarkError> {
 		// these are the other validators; there are `T::MaxNominations::get() - 1` of them, so
 		// there are a total of `T::MaxNominations::get()` validators in the system.
@@ -679,7 +679,7 @@ mod benchmarks {
 					validator: validator.clone(),
 					own: Zero::zero(),
 					others: WeakBoundedVec::default(),
-					reporter: Default::default(),
+					reporters: Default::default(),
 					payout: Zero::zero(),
 				};
 				UnappliedSlashes::<T>::insert(era, slash_key, unapplied_slash);
@@ -719,6 +719,18 @@ mod benchmarks {
 			Validators::<T>::get(&validator),
 		);
 
+		// worst case: the recent rewards of everyone are one entry short of full, so noting the
+		// reward decodes and re-encodes the largest buffer.
+		let recent_rewards: BoundedVec<_, T::MaxRecentRewards> = (1..T::MaxRecentRewards::get())
+			.map(|i| (current_era + i, BalanceOf::<T>::from(1u32)))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		RecentRewards::<T>::insert(&validator, recent_rewards.clone());
+		for (stash, _) in &nominators {
+			RecentRewards::<T>::insert(stash, recent_rewards.clone());
+		}
+
 		let caller = whitelisted_caller();
 		let balance_before = asset::stakeable_balance::<T>(&validator);
 		let mut nominator_balances_before = Vec::new();
@@ -770,7 +782,12 @@ mod benchmarks {
 		// so the sum of unlocking chunks puts voter into the dest bag.
 		assert!(value * l.into() + origin_weight > origin_weight);
 		assert!(value * l.into() + origin_weight <= dest_weight);
-		let unlock_chunk = UnlockChunk::<BalanceOf<T>> { value, era: EraIndex::zero() };
+		let unlock_chunk = UnlockChunk::<BalanceOf<T>, T::AccountId> {
+			value,
+			era: EraIndex::zero(),
+			dest: None,
+			withdrawable_era: None,
+		};
 
 		let controller = scenario.origin_controller1;
 		let mut staking_ledger = Ledger::<T>::get(controller.clone()).unwrap();
@@ -1001,7 +1018,7 @@ mod benchmarks {
 			validator: validator.clone(),
 			own: slashed_balance,
 			others: WeakBoundedVec::force_from(slashed_nominators, None),
-			reporter: Default::default(),
+			reporters: Default::default(),
 			payout: Zero::zero(),
 		};
 
@@ -1176,6 +1193,7 @@ mod benchmarks {
 		let report = rc_client::SessionReport {
 			end_index: 42,
 			leftover: false,
+			chunk: (0, 1),
 			validator_points,
 			activation_timestamp,
 		};
@@ -1268,6 +1286,280 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_auto_renominate() -> Result<(), BenchmarkError> {
+		let (stash, controller) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller), true);
+
+		assert!(AutoRenominate::<T>::contains_key(&stash));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_auto_compound_unlocking() -> Result<(), BenchmarkError> {
+		let (stash, controller) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
+		// opting out costs the same, in case opting in is not allowed.
+		let allowed = T::AllowAutoCompoundUnlocking::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller), allowed.then_some(Perbill::from_percent(50)));
+
+		assert_eq!(AutoCompoundUnlocking::<T>::contains_key(&stash), allowed);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn process_auto_renominate(
+		// number of targets of the nominator, half of which are no longer validators.
+		n: Linear<2, { MaxNominationsOf::<T>::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let validators = create_validators_with_seed::<T>(n, 100, 415)?;
+		let (stash, controller) = create_stash_controller::<T>(n, 100, RewardDestination::Staked)?;
+		Staking::<T>::nominate(RawOrigin::Signed(controller.clone()).into(), validators.clone())?;
+		Staking::<T>::set_auto_renominate(RawOrigin::Signed(controller).into(), true)?;
+
+		for validator in validators.into_iter().take(n as usize / 2) {
+			let validator = T::Lookup::lookup(validator).map_err(|_| "lookup failed")?;
+			Staking::<T>::do_remove_validator(&validator);
+		}
+
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), stash.clone());
+
+		assert_eq!(
+			Nominators::<T>::get(&stash).map(|n| n.targets.len()),
+			Some((n - n / 2) as usize)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_set_blocked(
+		// number of validators to block.
+		n: Linear<1, { T::MaxBlockedUpdates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let validators = create_validators_with_seed::<T>(n, 100, 415)?
+			.into_iter()
+			.map(|v| T::Lookup::lookup(v).map_err(|_| "lookup failed"))
+			.collect::<Result<Vec<_>, _>>()?;
+		let updates = validators.iter().cloned().map(|v| (v, true)).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, updates);
+
+		assert!(validators.iter().all(|v| Validators::<T>::get(v).blocked));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_forced_era() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 42);
+
+		assert_eq!(ForceEra::<T>::get(), Forcing::ForceScheduled(42));
+	}
+
+	#[benchmark]
+	fn unbond_to() -> Result<(), BenchmarkError> {
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = BalanceOf::<T>::try_from(952_994_955_240_703u128)
+			.map_err(|_| "balance expected to be a u128")
+			.unwrap();
+		let scenario = ListScenario::<T>::new(origin_weight, false)?;
+
+		let controller = scenario.origin_controller1.clone();
+		let amount = origin_weight - scenario.dest_weight;
+		let dest: T::AccountId = account("dest", 0, SEED);
+
+		whitelist_account!(controller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller.clone()), amount, T::Lookup::unlookup(dest.clone()));
+
+		let ledger = Ledger::<T>::get(&controller).ok_or("ledger not created after")?;
+		assert_eq!(ledger.unlocking.last().and_then(|chunk| chunk.dest.clone()), Some(dest));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn trim_nominations(
+		// number of targets of the nominator, above the maximum.
+		n: Linear<{ MaxNominationsOf::<T>::get() + 1 }, { MaxNominationsOf::<T>::get() * 2 }>,
+	) -> Result<(), BenchmarkError> {
+		let max = MaxNominationsOf::<T>::get() as usize;
+		let validators = create_validators_with_seed::<T>(n, 100, 415)?;
+		let (stash, controller) = create_stash_controller::<T>(n, 100, RewardDestination::Staked)?;
+		Staking::<T>::nominate(
+			RawOrigin::Signed(controller).into(),
+			validators.iter().take(max).cloned().collect(),
+		)?;
+
+		// more targets than allowed can only be stored if `MaxNominations` is lowered.
+		let mut targets = validators
+			.into_iter()
+			.map(|v| T::Lookup::lookup(v).map_err(|_| "lookup failed"))
+			.collect::<Result<Vec<_>, _>>()?;
+		targets.sort();
+		frame_support::storage::unhashed::put(
+			&Nominators::<T>::hashed_key_for(&stash),
+			&(targets, EraIndex::zero(), false),
+		);
+		assert!(Nominators::<T>::get(&stash).is_none());
+
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), stash.clone(), n);
+
+		assert_eq!(Nominators::<T>::get(&stash).map(|n| n.targets.len()), Some(max));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn chill_keep_targets() -> Result<(), BenchmarkError> {
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = Staking::<T>::min_nominator_bond();
+
+		// setup a worst case list scenario, as in `chill`.
+		let scenario = ListScenario::<T>::new(origin_weight, true)?;
+		let controller = scenario.origin_controller1.clone();
+		let stash = scenario.origin_stash1;
+		assert!(T::VoterList::contains(&stash));
+
+		whitelist_account!(controller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller));
+
+		assert!(!T::VoterList::contains(&stash));
+		assert!(PausedNominations::<T>::contains_key(&stash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume(n: Linear<1, { MaxNominationsOf::<T>::get() }>) -> Result<(), BenchmarkError> {
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = Staking::<T>::min_nominator_bond();
+
+		// setup a worst case list scenario, as in `nominate`.
+		ListScenario::<T>::new(origin_weight, true)?;
+		let (stash, controller) = create_stash_controller_with_balance::<T>(
+			SEED + MaxNominationsOf::<T>::get() + 1,
+			origin_weight,
+			RewardDestination::Staked,
+		)?;
+
+		let validators = create_validators::<T>(n, 100)?;
+		Staking::<T>::nominate(RawOrigin::Signed(controller.clone()).into(), validators)?;
+		Staking::<T>::chill_keep_targets(RawOrigin::Signed(controller.clone()).into())?;
+		assert!(!T::VoterList::contains(&stash));
+
+		whitelist_account!(controller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller));
+
+		assert_eq!(Nominators::<T>::get(&stash).map(|n| n.targets.len()), Some(n as usize));
+		assert!(T::VoterList::contains(&stash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_set_controller() -> Result<(), BenchmarkError> {
+		let (stash, ctlr) =
+			create_unique_stash_controller::<T>(9000, 100, RewardDestination::Staked, false)?;
+		let new_controller = account::<T::AccountId>("new_controller", 0, SEED);
+		assert_eq!(Bonded::<T>::get(&stash), Some(ctlr.clone()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, stash.clone(), new_controller.clone());
+
+		assert_eq!(Bonded::<T>::get(&stash), Some(new_controller.clone()));
+		assert!(!Ledger::<T>::contains_key(&ctlr));
+		assert!(Ledger::<T>::contains_key(&new_controller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_emergency_bonding_duration() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(1));
+
+		assert_eq!(EmergencyBondingDuration::<T>::get(), Some(1));
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn force_previous_validator_set(
+		v: Linear<1, { T::MaxValidatorSet::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// the active era, whose `v` validators with one exposure page each are kept, is not
+		// followed by a planned era.
+		let era = 7;
+		ActiveEra::<T>::put(ActiveEraInfo { index: era, start: None });
+		CurrentEra::<T>::put(era);
+		for i in 0..v {
+			let validator = account::<T::AccountId>("validator", i, SEED);
+			ErasValidatorPrefs::<T>::insert(era, validator.clone(), ValidatorPrefs::default());
+			let exposure = sp_staking::Exposure::<T::AccountId, BalanceOf<T>> {
+				own: 100u32.into(),
+				total: 200u32.into(),
+				others: vec![IndividualExposure {
+					who: account::<T::AccountId>("nominator", i, SEED),
+					value: 100u32.into(),
+				}],
+			};
+			Eras::<T>::upsert_exposure(era, &validator, exposure);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert_eq!(CurrentEra::<T>::get(), Some(era + 1));
+		assert_eq!(ErasStakersOverview::<T>::iter_prefix(era + 1).count(), v as usize);
+		assert_eq!(ForceEra::<T>::get(), Forcing::NotForcing);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn migration_v20_step() -> Result<(), BenchmarkError> {
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		// a nominator with the maximum number of targets, not yet in the backers index.
+		let (_, controller) = create_stash_controller::<T>(
+			SEED + MaxNominationsOf::<T>::get() + 1,
+			100,
+			RewardDestination::Staked,
+		)?;
+		let validators = create_validators::<T>(MaxNominationsOf::<T>::get(), 100)?;
+		Staking::<T>::nominate(RawOrigin::Signed(controller).into(), validators)?;
+		let _ = Backers::<T>::clear(u32::MAX, None);
+		let _ = BackersCount::<T>::clear(u32::MAX, None);
+
+		#[block]
+		{
+			crate::migrations::v20::LazyMigrationV19ToV20::<T>::index_step(None);
+		}
+
+		assert_eq!(Backers::<T>::iter_keys().count() as u32, MaxNominationsOf::<T>::get());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Staking,
 		crate::mock::ExtBuilder::default().has_stakers(true),
@@ -33,7 +33,7 @@
 
 use crate::{
 	asset, log, BalanceOf, Bonded, Config, DecodeWithMemTracking, Error, Ledger, Pallet, Payee,
-	RewardDestination, Vec, VirtualStakers,
+	RewardDestination, TotalBonded, Vec, VirtualStakers,
 };
 use alloc::{collections::BTreeMap, fmt::Debug};
 use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
@@ -46,17 +46,34 @@ use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, DispatchResult, Perquintill, Rounding, Saturating};
 use sp_staking::{EraIndex, OnStakingUpdate, StakingAccount, StakingInterface};
 
-/// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
+/// A chunk of funds that will be unlocked at a given era, and where they go once withdrawn.
 #[derive(
 	PartialEq, Eq, Clone, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo, MaxEncodedLen,
 )]
-pub struct UnlockChunk<Balance: HasCompact + MaxEncodedLen> {
+pub struct UnlockChunk<Balance: HasCompact + MaxEncodedLen, AccountId> {
 	/// Amount of funds to be unlocked.
 	#[codec(compact)]
 	pub value: Balance,
 	/// Era number at which point it'll be unlocked.
+	///
+	/// This is always the era of the unbonding request plus [`crate::Config::BondingDuration`],
+	/// so that the chunks of a ledger are sorted by it and slashes find all chunks that were
+	/// still bonded at the time of an offence.
 	#[codec(compact)]
 	pub era: EraIndex,
+	/// The account the funds are sent to once withdrawn, see [`crate::Call::unbond_to`]. If
+	/// `None`, they stay in the stash.
+	pub dest: Option<AccountId>,
+	/// An era before `era` at which the funds can already be withdrawn, if they were unbonded
+	/// while [`crate::EmergencyBondingDuration`] was set.
+	pub withdrawable_era: Option<EraIndex>,
+}
+
+impl<Balance: HasCompact + MaxEncodedLen, AccountId> UnlockChunk<Balance, AccountId> {
+	/// The era from which the funds of this chunk can be withdrawn.
+	pub fn unlock_era(&self) -> EraIndex {
+		self.withdrawable_era.map_or(self.era, |era| era.min(self.era))
+	}
 }
 
 /// The ledger of a (bonded) stash.
@@ -97,7 +114,7 @@ pub struct StakingLedger<T: Config> {
 	/// Any balance that is becoming free, which may eventually be transferred out of the stash
 	/// (assuming it doesn't get slashed first). It is assumed that this will be treated as a first
 	/// in, first out queue where the new (higher value) eras get pushed on the back.
-	pub unlocking: BoundedVec<UnlockChunk<BalanceOf<T>>, T::MaxUnlockingChunks>,
+	pub unlocking: BoundedVec<UnlockChunk<BalanceOf<T>, T::AccountId>, T::MaxUnlockingChunks>,
 
 	/// The controller associated with this ledger's stash.
 	///
@@ -254,13 +271,15 @@ impl<T: Config> StakingLedger<T> {
 				.map_err(|_| Error::<T>::NotEnoughFunds)?;
 		}
 
-		Ledger::<T>::insert(
-			&self.controller().ok_or_else(|| {
-				defensive!("update called on a ledger that is not bonded.");
-				Error::<T>::NotController
-			})?,
-			&self,
-		);
+		let controller = self.controller().ok_or_else(|| {
+			defensive!("update called on a ledger that is not bonded.");
+			Error::<T>::NotController
+		})?;
+		let previous_total = Ledger::<T>::get(&controller).map(|l| l.total).unwrap_or_default();
+		TotalBonded::<T>::mutate(|total| {
+			*total = total.saturating_sub(previous_total).saturating_add(self.total)
+		});
+		Ledger::<T>::insert(&controller, &self);
 
 		Ok(())
 	}
@@ -303,6 +322,8 @@ impl<T: Config> StakingLedger<T> {
 			// inconsistencies. See <https://github.com/paritytech/polkadot-sdk/pull/3639> for more
 			// details.
 			ensure!(bonded_ledger.stash == self.stash, Error::<T>::BadState);
+			// it is overwritten below.
+			TotalBonded::<T>::mutate(|total| *total = total.saturating_sub(bonded_ledger.total));
 		}
 
 		<Ledger<T>>::remove(&controller);
@@ -312,6 +333,32 @@ impl<T: Config> StakingLedger<T> {
 		Ok(())
 	}
 
+	/// Sets the ledger controller to `new_controller`, irrespective of the current controller.
+	///
+	/// Fails if `new_controller` is already used as a stash or controller by another ledger.
+	pub(crate) fn force_set_controller(
+		mut self,
+		new_controller: T::AccountId,
+	) -> Result<(), Error<T>> {
+		let controller = <Bonded<T>>::get(&self.stash).ok_or(Error::<T>::NotStash)?;
+		ensure!(controller != new_controller, Error::<T>::AlreadyPaired);
+
+		// refuse to create a controller collision. The stash itself is always a valid controller,
+		// unless it is already the controller of another ledger.
+		ensure!(
+			!<Ledger<T>>::contains_key(&new_controller) &&
+				(new_controller == self.stash || !<Bonded<T>>::contains_key(&new_controller)),
+			Error::<T>::AlreadyPaired
+		);
+
+		self.controller = Some(new_controller.clone());
+		<Ledger<T>>::remove(&controller);
+		<Ledger<T>>::insert(&new_controller, &self);
+		<Bonded<T>>::insert(&self.stash, &new_controller);
+
+		Ok(())
+	}
+
 	/// Clears all data related to a staking ledger and its bond in both [`Ledger`] and [`Bonded`]
 	/// storage items and updates the stash staking lock.
 	pub(crate) fn kill(stash: &T::AccountId) -> DispatchResult {
@@ -319,6 +366,7 @@ impl<T: Config> StakingLedger<T> {
 
 		<Ledger<T>>::get(&controller).ok_or(Error::<T>::NotController).map(|ledger| {
 			Ledger::<T>::remove(controller);
+			TotalBonded::<T>::mutate(|total| *total = total.saturating_sub(ledger.total));
 			<Bonded<T>>::remove(&stash);
 			<Payee<T>>::remove(&stash);
 
@@ -350,7 +398,7 @@ impl<T: Config> StakingLedger<T> {
 			.unlocking
 			.into_iter()
 			.filter(|chunk| {
-				if chunk.era > current_era {
+				if chunk.unlock_era() > current_era {
 					true
 				} else {
 					total = total.saturating_sub(chunk.value);
@@ -399,6 +447,37 @@ impl<T: Config> StakingLedger<T> {
 		(self, unlocking_balance)
 	}
 
+	/// Re-bond up to `value` of the chunks that are unlocked into the stash at the full bonding
+	/// duration, newest first.
+	///
+	/// Chunks sent elsewhere once withdrawn, see [`crate::Call::unbond_to`], and chunks unbonded
+	/// while [`crate::EmergencyBondingDuration`] was set are left as they are.
+	///
+	/// Returns the updated ledger, and the amount actually rebonded.
+	pub(crate) fn rebond_own(mut self, value: BalanceOf<T>) -> (Self, BalanceOf<T>) {
+		let mut unlocking_balance = BalanceOf::<T>::zero();
+
+		for index in (0..self.unlocking.len()).rev() {
+			if unlocking_balance >= value {
+				break
+			}
+			let chunk = &mut self.unlocking[index];
+			if chunk.dest.is_some() || chunk.withdrawable_era.is_some() {
+				continue
+			}
+
+			let diff = value.defensive_saturating_sub(unlocking_balance).min(chunk.value);
+			unlocking_balance += diff;
+			self.active += diff;
+			chunk.value -= diff;
+			if chunk.value.is_zero() {
+				self.unlocking.remove(index);
+			}
+		}
+
+		(self, unlocking_balance)
+	}
+
 	/// Slash the staker for a given amount of balance.
 	///
 	/// This implements a proportional slashing system, whereby we set our preference to slash as
@@ -572,8 +651,10 @@ pub struct StakingLedgerInspect<T: Config> {
 	pub total: BalanceOf<T>,
 	#[codec(compact)]
 	pub active: BalanceOf<T>,
-	pub unlocking:
-		frame_support::BoundedVec<crate::UnlockChunk<BalanceOf<T>>, T::MaxUnlockingChunks>,
+	pub unlocking: frame_support::BoundedVec<
+		crate::UnlockChunk<BalanceOf<T>, T::AccountId>,
+		T::MaxUnlockingChunks,
+	>,
 }
 
 #[cfg(test)]
@@ -86,6 +86,8 @@
 //! 2. **Era Tracking**: Era added to `OffenceQueueEras` (sorted vector of eras with offences)
 //! 3. **Duplicate Handling**: If an offence already exists for the same validator in the same era,
 //!    only the higher slash fraction is kept
+//! 4. **Reporters**: Duplicate reporters are dropped, and at most `MaxReporters` unique reporters
+//!    are kept
 //!
 //! ### Phase 3: Processing
 //!
@@ -104,7 +106,7 @@
 //! **Key Features**:
 //! - **Page-by-page processing**: Large validator sets don't overwhelm a single block
 //! - **Pro-rata slashing**: Nominators slashed proportionally to their stake
-//! - **Reward calculation**: A portion goes to reporters (if any)
+//! - **Reward calculation**: A portion goes to reporters (if any), split equally between them
 //!
 //! ### Phase 4: Application
 //!
@@ -190,6 +192,7 @@ mod tests;
 pub mod asset;
 pub mod election_size_tracker;
 pub mod ledger;
+pub mod migrations;
 mod pallet;
 pub mod session_rotation;
 pub mod slashing;
@@ -337,7 +340,7 @@ pub enum RewardDestination<AccountId> {
 	Default,
 	MaxEncodedLen,
 )]
-pub struct ValidatorPrefs {
+pub struct ValidatorPrefs<Balance> {
 	/// Reward that validator takes up-front; only the rest is split between themselves and
 	/// nominators.
 	#[codec(compact)]
@@ -346,6 +349,9 @@ pub struct ValidatorPrefs {
 	/// who is not already nominating this validator may nominate them. By default, validators
 	/// are accepting nominations.
 	pub blocked: bool,
+	/// The most this validator and their nominators may be paid for a single era, before
+	/// commission is taken. Any excess goes to [`Config::RewardRemainder`]. `None` means no cap.
+	pub max_reward_per_era: Option<Balance>,
 }
 
 /// Status of a paged snapshot progress.
@@ -373,10 +379,11 @@ pub struct Nominations<T: Config> {
 	///
 	/// Except for initial nominations which are considered submitted at era 0.
 	pub submitted_in: EraIndex,
-	/// Whether the nominations have been suppressed. This can happen due to slashing of the
-	/// validators, or other events that might invalidate the nomination.
+	/// Whether the nominations have been suppressed. This happens when the nominator is slashed,
+	/// if [`Config::SuppressSlashedNominations`] is set.
 	///
-	/// NOTE: this for future proofing and is thus far not used.
+	/// Suppressed nominations are not part of the election snapshot, until the nominator
+	/// nominates again.
 	pub suppressed: bool,
 }
 
@@ -438,8 +445,8 @@ pub struct UnappliedSlash<T: Config> {
 	pub own: BalanceOf<T>,
 	/// All other slashed stakers and amounts.
 	pub others: WeakBoundedVec<(T::AccountId, BalanceOf<T>), T::MaxExposurePageSize>,
-	/// Reporters of the offence; bounty payout recipients.
-	pub reporter: Option<T::AccountId>,
+	/// Unique reporters of the offence; bounty payout recipients.
+	pub reporters: BoundedVec<T::AccountId, T::MaxReporters>,
 	/// The amount of payout.
 	pub payout: BalanceOf<T>,
 }
@@ -497,6 +504,118 @@ impl<Balance: Default> EraPayout<Balance> for () {
 	}
 }
 
+/// The minimum active bond a staker must keep after being slashed, below which it is chilled.
+///
+/// The threshold is distinct per role, so that, for example, a validator need not be chilled at
+/// the nominator minimum.
+pub trait PostSlashDustThreshold<Balance> {
+	/// The threshold for a validator.
+	fn validator() -> Balance;
+	/// The threshold for a nominator.
+	fn nominator() -> Balance;
+}
+
+/// Never chill stakers after a slash.
+impl<Balance: Default> PostSlashDustThreshold<Balance> for () {
+	fn validator() -> Balance {
+		Default::default()
+	}
+	fn nominator() -> Balance {
+		Default::default()
+	}
+}
+
+/// The maximum number of eras over which [`SmoothedEraPayout`] can average the era duration.
+pub const MAX_SMOOTHED_ERAS: u32 = 32;
+
+/// The maximum number of accounts answered by a single batch lookup of the runtime API, such as
+/// [`Pallet::api_bonded_many`]. Accounts past this limit are ignored.
+pub const MAX_BATCH_LOOKUP: u32 = 512;
+
+/// An [`EraPayout`] adaptor that passes to `Inner` the average duration of the last `N` eras,
+/// including the ending one, rather than the duration of the ending era alone.
+///
+/// This dampens the payout spike of an abnormally long or short era, for example one forced by
+/// governance. The recent durations are kept in [`RecentEraDurations`], and `N` is capped at
+/// [`MAX_SMOOTHED_ERAS`].
+pub struct SmoothedEraPayout<T, Inner, N>(core::marker::PhantomData<(T, Inner, N)>);
+
+impl<T: Config, Balance, Inner: EraPayout<Balance>, N: Get<u32>> EraPayout<Balance>
+	for SmoothedEraPayout<T, Inner, N>
+{
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		let n = N::get().clamp(1, MAX_SMOOTHED_ERAS) as usize;
+
+		let smoothed_duration = RecentEraDurations::<T>::mutate(|durations| {
+			let mut recent = durations.to_vec();
+			recent.push(era_duration_millis);
+			let recent = recent.split_off(recent.len().saturating_sub(n));
+
+			let sum = recent.iter().map(|d| *d as u128).sum::<u128>();
+			let average = (sum / recent.len() as u128) as u64;
+
+			*durations = BoundedVec::truncate_from(recent);
+			average
+		});
+
+		Inner::era_payout(total_staked, total_issuance, smoothed_duration)
+	}
+}
+
+/// What to do when an election yields fewer than [`Config::MinimumValidatorCount`] validators.
+#[derive(
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	serde::Serialize,
+	serde::Deserialize,
+)]
+pub enum UnderSubscribedBehavior {
+	/// Keep the validators of the active era, along with their exposures, for the new era.
+	KeepPreviousSet,
+	/// Use the validators that were elected, however few.
+	UseWhatWeGot,
+	/// Do not send a new validator set at all. The planning of the era is rolled back and no
+	/// further era is planned, so the relay chain keeps its current validators until governance
+	/// either retries the election with [`Call::force_new_era`], or keeps the previous set with
+	/// [`Call::force_previous_validator_set`].
+	Halt,
+}
+
+/// What to do with a reward whose [`RewardDestination::Account`] does not exist, and would not be
+/// created by the reward alone, since it is below the existential deposit.
+#[derive(
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum DeadPayeeBehavior {
+	/// The reward is not paid out.
+	Burn,
+	/// The reward is paid into the stash account instead, not increasing the amount at stake.
+	ToStash,
+	/// The reward is sent to [`Config::RewardRemainder`] instead.
+	ToRemainder,
+}
+
 /// Mode of era-forcing.
 #[derive(
 	Copy,
@@ -523,6 +642,9 @@ pub enum Forcing {
 	ForceNone,
 	/// Force a new era at the end of all sessions indefinitely.
 	ForceAlways,
+	/// Force a new era once the given session starts, then reset to `NotForcing`. Until then,
+	/// behave as `NotForcing`.
+	ForceScheduled(SessionIndex),
 }
 
 impl Default for Forcing {
@@ -0,0 +1,525 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the Staking pallet.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The identifier of the multi-block migrations of this pallet.
+pub const PALLET_MIGRATIONS_ID: &[u8; 20] = b"pallet-staking-async";
+
+/// Adds [`ValidatorPrefs::max_reward_per_era`], set to `None` for all existing prefs.
+pub mod v18 {
+	use super::*;
+
+	/// [`ValidatorPrefs`] as of v17.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldValidatorPrefs {
+		#[codec(compact)]
+		pub commission: Perbill,
+		pub blocked: bool,
+	}
+
+	impl<Balance> From<OldValidatorPrefs> for ValidatorPrefs<Balance> {
+		fn from(old: OldValidatorPrefs) -> Self {
+			ValidatorPrefs {
+				commission: old.commission,
+				blocked: old.blocked,
+				max_reward_per_era: None,
+			}
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV17ToV18<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV17ToV18<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let validators = Validators::<T>::count();
+			let eras_prefs = ErasValidatorPrefs::<T>::iter_keys().count() as u32;
+			Ok((validators, eras_prefs).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			Validators::<T>::translate::<OldValidatorPrefs, _>(|_, old| {
+				translated.saturating_inc();
+				Some(old.into())
+			});
+			ErasValidatorPrefs::<T>::translate::<OldValidatorPrefs, _>(|_, _, old| {
+				translated.saturating_inc();
+				Some(old.into())
+			});
+
+			log!(info, "v18 applied successfully, translated {} prefs.", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (validators, eras_prefs) = <(u32, u32)>::decode(&mut state.as_slice())
+				.map_err(|_| "failed to decode pre-upgrade state")?;
+
+			ensure!(Validators::<T>::count() == validators, "Validators count mismatch");
+			ensure!(
+				Validators::<T>::iter_values().all(|p| p.max_reward_per_era.is_none()),
+				"Validators not all migrated"
+			);
+			ensure!(
+				ErasValidatorPrefs::<T>::iter_values().count() as u32 == eras_prefs,
+				"ErasValidatorPrefs not all migrated"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV17ToV18<T> = VersionedMigration<
+		17,
+		18,
+		VersionUncheckedMigrateV17ToV18<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Replaces the single `reporter` of [`slashing::OffenceRecord`] and [`UnappliedSlash`] with a
+/// bounded list of unique `reporters`.
+pub mod v19 {
+	use super::*;
+	use crate::slashing::OffenceRecord;
+	use frame_support::traits::Defensive;
+
+	/// [`OffenceRecord`] as of v18.
+	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldOffenceRecord<AccountId> {
+		pub reporter: Option<AccountId>,
+		pub reported_era: EraIndex,
+		pub exposure_page: u32,
+		pub slash_fraction: Perbill,
+		pub prior_slash_fraction: Perbill,
+	}
+
+	impl<T: Config> From<OldOffenceRecord<T::AccountId>> for OffenceRecord<T> {
+		fn from(old: OldOffenceRecord<T::AccountId>) -> Self {
+			OffenceRecord {
+				reporters: BoundedVec::truncate_from(old.reporter.into_iter().collect()),
+				reported_era: old.reported_era,
+				exposure_page: old.exposure_page,
+				slash_fraction: old.slash_fraction,
+				prior_slash_fraction: old.prior_slash_fraction,
+			}
+		}
+	}
+
+	/// [`UnappliedSlash`] as of v18.
+	#[derive(Encode, Decode, DebugNoBound, TypeInfo, MaxEncodedLen, PartialEqNoBound)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldUnappliedSlash<T: Config> {
+		pub validator: T::AccountId,
+		pub own: BalanceOf<T>,
+		pub others: WeakBoundedVec<(T::AccountId, BalanceOf<T>), T::MaxExposurePageSize>,
+		pub reporter: Option<T::AccountId>,
+		pub payout: BalanceOf<T>,
+	}
+
+	impl<T: Config> From<OldUnappliedSlash<T>> for UnappliedSlash<T> {
+		fn from(old: OldUnappliedSlash<T>) -> Self {
+			UnappliedSlash {
+				validator: old.validator,
+				own: old.own,
+				others: old.others,
+				reporters: BoundedVec::truncate_from(old.reporter.into_iter().collect()),
+				payout: old.payout,
+			}
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV18ToV19<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV18ToV19<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let offences = OffenceQueue::<T>::iter_keys().count() as u32;
+			let unapplied = UnappliedSlashes::<T>::iter_keys().count() as u32;
+			Ok((offences, unapplied).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			OffenceQueue::<T>::translate::<OldOffenceRecord<T::AccountId>, _>(|_, _, old| {
+				translated.saturating_inc();
+				Some(old.into())
+			});
+			let _ = ProcessingOffence::<T>::translate::<
+				(EraIndex, T::AccountId, OldOffenceRecord<T::AccountId>),
+				_,
+			>(|maybe_old| {
+				maybe_old.map(|(era, offender, old)| {
+					translated.saturating_inc();
+					(era, offender, old.into())
+				})
+			})
+			.defensive();
+			UnappliedSlashes::<T>::translate::<OldUnappliedSlash<T>, _>(|_, _, old| {
+				translated.saturating_inc();
+				Some(old.into())
+			});
+
+			log!(info, "v19 applied successfully, translated {} slashing records.", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (offences, unapplied) = <(u32, u32)>::decode(&mut state.as_slice())
+				.map_err(|_| "failed to decode pre-upgrade state")?;
+
+			ensure!(
+				OffenceQueue::<T>::iter_values().count() as u32 == offences,
+				"OffenceQueue not all migrated"
+			);
+			ensure!(
+				UnappliedSlashes::<T>::iter_values().count() as u32 == unapplied,
+				"UnappliedSlashes not all migrated"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV18ToV19<T> = VersionedMigration<
+		18,
+		19,
+		VersionUncheckedMigrateV18ToV19<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Builds the [`Backers`] reverse index and [`BackersCount`] from the existing [`Nominators`].
+///
+/// Since this writes an entry for every nomination, the index is built by the multi-block
+/// [`v20::LazyMigrationV19ToV20`], which must be run through `pallet-migrations`.
+/// [`v20::MigrateV19ToV20`] only bumps the storage version, so that the later single-block
+/// migrations can run in the same upgrade.
+pub mod v20 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+
+	/// Indexes the nominations of one nominator per step, into [`Backers`].
+	pub struct LazyMigrationV19ToV20<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> LazyMigrationV19ToV20<T> {
+		/// Index the nominations of the nominator after `cursor`, if any, and return it.
+		pub(crate) fn index_step(cursor: Option<T::AccountId>) -> Option<T::AccountId> {
+			let mut iter = match cursor {
+				Some(last) => Nominators::<T>::iter_from(Nominators::<T>::hashed_key_for(last)),
+				None => Nominators::<T>::iter(),
+			};
+			let (nominator, nominations) = iter.next()?;
+			for target in nominations.targets {
+				Pallet::<T>::add_backer(&target, &nominator);
+			}
+			Some(nominator)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for LazyMigrationV19ToV20<T> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<20>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 19, version_to: 20 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = T::WeightInfo::migration_v20_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			// index as many nominators as fit into this block.
+			while meter.try_consume(required).is_ok() {
+				match Self::index_step(cursor) {
+					Some(nominator) => cursor = Some(nominator),
+					None => {
+						log!(info, "v20 applied successfully, indexed all nominations.");
+						return Ok(None)
+					},
+				}
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			Pallet::<T>::check_backers()
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV19ToV20<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV19ToV20<T> {
+		fn on_runtime_upgrade() -> Weight {
+			log!(info, "v20 applied, the backers index is built by LazyMigrationV19ToV20.");
+			Weight::zero()
+		}
+	}
+
+	pub type MigrateV19ToV20<T> = VersionedMigration<
+		19,
+		20,
+		VersionUncheckedMigrateV19ToV20<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Adds [`UnlockChunk::dest`], set to `None` for all existing unlocking chunks.
+pub mod v21 {
+	use super::*;
+	use frame_support::storage::unhashed;
+
+	/// [`crate::UnlockChunk`] as of v20.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldUnlockChunk<Balance: HasCompact + MaxEncodedLen> {
+		#[codec(compact)]
+		pub value: Balance,
+		#[codec(compact)]
+		pub era: EraIndex,
+	}
+
+	/// [`StakingLedger`] as of v20.
+	#[derive(Encode, Decode, DebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldStakingLedger<T: Config> {
+		pub stash: T::AccountId,
+		#[codec(compact)]
+		pub total: BalanceOf<T>,
+		#[codec(compact)]
+		pub active: BalanceOf<T>,
+		pub unlocking: BoundedVec<OldUnlockChunk<BalanceOf<T>>, T::MaxUnlockingChunks>,
+	}
+
+	impl<T: Config> From<OldStakingLedger<T>> for v22::OldStakingLedger<T> {
+		fn from(old: OldStakingLedger<T>) -> Self {
+			let unlocking = old
+				.unlocking
+				.into_iter()
+				.map(|chunk| v22::OldUnlockChunk { value: chunk.value, era: chunk.era, dest: None })
+				.collect::<Vec<_>>();
+			v22::OldStakingLedger {
+				stash: old.stash,
+				total: old.total,
+				active: old.active,
+				unlocking: BoundedVec::truncate_from(unlocking),
+			}
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV20ToV21<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV20ToV21<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Ledger::<T>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			// the ledgers are written in the v21 encoding, not the current one, so we can not use
+			// `translate` here.
+			for controller in Ledger::<T>::iter_keys().collect::<Vec<_>>() {
+				let key = Ledger::<T>::hashed_key_for(&controller);
+				translated.saturating_inc();
+				if let Some(old) = unhashed::get::<OldStakingLedger<T>>(&key) {
+					unhashed::put(&key, &v22::OldStakingLedger::<T>::from(old));
+				}
+			}
+
+			log!(info, "v21 applied successfully, translated {} ledgers.", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let ledgers = u32::decode(&mut state.as_slice())
+				.map_err(|_| "failed to decode pre-upgrade state")?;
+
+			let migrated = Ledger::<T>::iter_keys()
+				.filter_map(|key| {
+					unhashed::get::<v22::OldStakingLedger<T>>(&Ledger::<T>::hashed_key_for(key))
+				})
+				.collect::<Vec<_>>();
+			ensure!(migrated.len() as u32 == ledgers, "Ledger not all migrated");
+			ensure!(
+				migrated.iter().all(|l| l.unlocking.iter().all(|c| c.dest.is_none())),
+				"Ledger unlocking chunks not all migrated"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV20ToV21<T> = VersionedMigration<
+		20,
+		21,
+		VersionUncheckedMigrateV20ToV21<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Adds [`UnlockChunk::withdrawable_era`], set to `None` for all existing unlocking chunks.
+pub mod v22 {
+	use super::*;
+	use crate::UnlockChunk;
+
+	/// [`UnlockChunk`] as of v21.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldUnlockChunk<Balance: HasCompact + MaxEncodedLen, AccountId> {
+		#[codec(compact)]
+		pub value: Balance,
+		#[codec(compact)]
+		pub era: EraIndex,
+		pub dest: Option<AccountId>,
+	}
+
+	/// [`StakingLedger`] as of v21.
+	#[derive(Encode, Decode, DebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldStakingLedger<T: Config> {
+		pub stash: T::AccountId,
+		#[codec(compact)]
+		pub total: BalanceOf<T>,
+		#[codec(compact)]
+		pub active: BalanceOf<T>,
+		pub unlocking:
+			BoundedVec<OldUnlockChunk<BalanceOf<T>, T::AccountId>, T::MaxUnlockingChunks>,
+	}
+
+	impl<T: Config> From<OldStakingLedger<T>> for StakingLedger<T> {
+		fn from(old: OldStakingLedger<T>) -> Self {
+			let unlocking = old
+				.unlocking
+				.into_iter()
+				.map(|chunk| UnlockChunk {
+					value: chunk.value,
+					era: chunk.era,
+					dest: chunk.dest,
+					withdrawable_era: None,
+				})
+				.collect::<Vec<_>>();
+			StakingLedger {
+				stash: old.stash,
+				total: old.total,
+				active: old.active,
+				unlocking: BoundedVec::truncate_from(unlocking),
+				controller: None,
+			}
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV21ToV22<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV21ToV22<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Ledger::<T>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			Ledger::<T>::translate::<OldStakingLedger<T>, _>(|_, old| {
+				translated.saturating_inc();
+				Some(old.into())
+			});
+
+			log!(info, "v22 applied successfully, translated {} ledgers.", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let ledgers = u32::decode(&mut state.as_slice())
+				.map_err(|_| "failed to decode pre-upgrade state")?;
+
+			ensure!(
+				Ledger::<T>::iter_values().count() as u32 == ledgers,
+				"Ledger not all migrated"
+			);
+			ensure!(
+				Ledger::<T>::iter_values()
+					.all(|l| l.unlocking.iter().all(|c| c.withdrawable_era.is_none())),
+				"Ledger unlocking chunks not all migrated"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV21ToV22<T> = VersionedMigration<
+		21,
+		22,
+		VersionUncheckedMigrateV21ToV22<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Initializes [`TotalBonded`] to the sum of the `total` of all existing [`Ledger`]s.
+pub mod v23 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateV22ToV23<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV22ToV23<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut ledgers = 0u64;
+
+			let total = Ledger::<T>::iter_values().fold(BalanceOf::<T>::zero(), |acc, ledger| {
+				ledgers.saturating_inc();
+				acc.saturating_add(ledger.total)
+			});
+			TotalBonded::<T>::put(total);
+
+			log!(info, "v23 applied successfully, {:?} bonded in {} ledgers.", total, ledgers);
+			T::DbWeight::get().reads_writes(ledgers + 1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			Pallet::<T>::check_total_bonded()
+		}
+	}
+
+	pub type MigrateV22ToV23<T> = VersionedMigration<
+		22,
+		23,
+		VersionUncheckedMigrateV22ToV23<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -80,13 +80,22 @@ impl Get<SessionIndex> for PlanningEraOffset {
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 1;
 	pub static SlashDeferDuration: EraIndex = 0;
+	pub static MaxSlashesPerBlock: u32 = 1;
 	pub static MaxControllersInDeprecationBatch: u32 = 5900;
+	pub static MaxKicks: u32 = 128;
+	pub static MaxBlockedUpdates: u32 = 8;
+	pub static AutoPayoutPerBlock: u32 = 0;
 	pub static BondingDuration: EraIndex = 3;
 	pub static HistoryDepth: u32 = 80;
 	pub static MaxExposurePageSize: u32 = 64;
+	pub static MaxBackersExposed: u32 = u32::MAX;
 	pub static MaxUnlockingChunks: u32 = 32;
+	pub static UnlockingChunkMergeWindow: EraIndex = 0;
 	pub static RewardOnUnbalanceWasCalled: bool = false;
 	pub static MaxValidatorSet: u32 = 100;
+	pub static MinimumValidatorCount: u32 = 0;
+	pub static UnderSubscribed: UnderSubscribedBehavior = UnderSubscribedBehavior::UseWhatWeGot;
+	pub static DeadPayee: DeadPayeeBehavior = DeadPayeeBehavior::Burn;
 	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
 	pub static AbsoluteMaxNominations: u32 = 16;
 	pub static PlanningEraModeVal: PlanningEraMode = PlanningEraMode::Fixed(2);
@@ -402,6 +411,31 @@ parameter_types! {
 	pub static RemainderRatio: Perbill = Perbill::from_percent(50);
 	pub static MaxEraDuration: u64 = time_per_era() * 7;
 }
+parameter_types! {
+	pub static ValidatorDustThreshold: Balance = 0;
+	pub static NominatorDustThreshold: Balance = 0;
+	pub static MaxSlashReward: Balance = 0;
+	pub static MaxReporters: u32 = 4;
+	pub static AllowAutoCompoundUnlocking: bool = true;
+	pub static SuppressSlashedNominations: bool = false;
+	pub static MaxRecentRewards: u32 = 3;
+	pub static RewardPointsCarryOver: Perbill = Perbill::zero();
+	pub static SkipZeroRewardEras: bool = false;
+	pub static MaxNominatorsPerValidator: u32 = 0;
+	pub static AutoPayoutExpiring: u32 = 0;
+	pub static RoleChangeCooldown: EraIndex = 0;
+	pub static MinEraPointsForReward: RewardPoint = 0;
+}
+pub struct MockPostSlashDustThreshold;
+impl PostSlashDustThreshold<Balance> for MockPostSlashDustThreshold {
+	fn validator() -> Balance {
+		ValidatorDustThreshold::get()
+	}
+	fn nominator() -> Balance {
+		NominatorDustThreshold::get()
+	}
+}
+
 pub struct OneTokenPerMillisecond;
 impl EraPayout<Balance> for OneTokenPerMillisecond {
 	fn era_payout(
@@ -424,20 +458,41 @@ impl crate::pallet::pallet::Config for Test {
 	type Reward = MockReward;
 	type SessionsPerEra = SessionsPerEra;
 	type SlashDeferDuration = SlashDeferDuration;
+	type MaxSlashesPerBlock = MaxSlashesPerBlock;
 	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<One, AccountId>>;
 	type EraPayout = OneTokenPerMillisecond;
 	type MaxExposurePageSize = MaxExposurePageSize;
+	type MaxBackersExposed = MaxBackersExposed;
 	type MaxValidatorSet = MaxValidatorSet;
+	type MinimumValidatorCount = MinimumValidatorCount;
+	type UnderSubscribedBehavior = UnderSubscribed;
+	type DeadPayeeBehavior = DeadPayee;
 	type ElectionProvider = TestElectionProvider;
 	type VoterList = VoterBagsList;
 	type TargetList = UseValidatorsMap<Self>;
 	type NominationsQuota = WeightedNominationsQuota<16>;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
+	type UnlockingChunkMergeWindow = UnlockingChunkMergeWindow;
 	type HistoryDepth = HistoryDepth;
 	type BondingDuration = BondingDuration;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+	type MaxKicks = MaxKicks;
+	type MaxBlockedUpdates = MaxBlockedUpdates;
 	type EventListeners = EventListenerMock;
 	type MaxInvulnerables = ConstU32<20>;
+	type AutoPayoutPerBlock = AutoPayoutPerBlock;
+	type PostSlashDustThreshold = MockPostSlashDustThreshold;
+	type MaxSlashReward = MaxSlashReward;
+	type MaxReporters = MaxReporters;
+	type AllowAutoCompoundUnlocking = AllowAutoCompoundUnlocking;
+	type SuppressSlashedNominations = SuppressSlashedNominations;
+	type MaxRecentRewards = MaxRecentRewards;
+	type RewardPointsCarryOver = RewardPointsCarryOver;
+	type SkipZeroRewardEras = SkipZeroRewardEras;
+	type MaxNominatorsPerValidator = MaxNominatorsPerValidator;
+	type AutoPayoutExpiring = AutoPayoutExpiring;
+	type RoleChangeCooldown = RoleChangeCooldown;
+	type MinEraPointsForReward = MinEraPointsForReward;
 	type MaxEraDuration = MaxEraDuration;
 	type PlanningEraOffset = PlanningEraOffset;
 	type Filter = MockedRestrictList;
@@ -870,6 +925,7 @@ pub(crate) fn set_controller_no_checks(stash: &AccountId) {
 	Ledger::<Test>::remove(&controller);
 	Ledger::<Test>::insert(stash, ledger);
 	Bonded::<Test>::insert(stash, stash);
+	sync_total_bonded();
 }
 
 // simulates `bond_extra` without corrupted ledger checks for testing purposes.
@@ -882,6 +938,12 @@ pub(crate) fn bond_extra_no_checks(stash: &AccountId, amount: Balance) {
 	ledger.total = new_total;
 	ledger.active = new_total;
 	Ledger::<Test>::insert(controller, ledger);
+	sync_total_bonded();
+}
+
+// recomputes `TotalBonded` after ledgers have been written to storage directly.
+pub(crate) fn sync_total_bonded() {
+	TotalBonded::<Test>::put(Ledger::<Test>::iter_values().map(|l| l.total).sum::<Balance>());
 }
 
 pub(crate) fn setup_double_bonded_ledgers() {
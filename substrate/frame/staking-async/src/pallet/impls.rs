@@ -22,11 +22,11 @@ use crate::{
 	election_size_tracker::StaticTracker,
 	log,
 	session_rotation::{self, Eras, Rotator},
-	slashing::OffenceRecord,
+	slashing::{unique_reporters, OffenceRecord},
 	weights::WeightInfo,
-	BalanceOf, Exposure, Forcing, LedgerIntegrityState, MaxNominationsOf, Nominations,
-	NominationsQuota, PositiveImbalanceOf, RewardDestination, SnapshotStatus, StakingLedger,
-	ValidatorPrefs, STAKING_ID,
+	BalanceOf, DeadPayeeBehavior, Exposure, Forcing, LedgerIntegrityState, MaxNominationsOf,
+	Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination, RewardPoint,
+	SnapshotStatus, StakingLedger, UnlockChunk, ValidatorPrefs, MAX_BATCH_LOOKUP, STAKING_ID,
 };
 use alloc::{boxed::Box, vec, vec::Vec};
 use frame_election_provider_support::{
@@ -41,18 +41,19 @@ use frame_support::{
 		Defensive, DefensiveSaturating, Get, Imbalance, InspectLockableCurrency, LockableCurrency,
 		OnUnbalanced,
 	},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 	StorageDoubleMap,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_staking_async_rc_client::{self as rc_client};
 use sp_runtime::{
-	traits::{CheckedAdd, Saturating, StaticLookup, Zero},
-	ArithmeticError, DispatchResult, Perbill,
+	traits::{CheckedAdd, Hash, SaturatedConversion, Saturating, StaticLookup, Zero},
+	ArithmeticError, DispatchResult, FixedPointNumber, FixedU128, Perbill, Perquintill,
 };
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, ExposurePage, NominateError, OnStakingUpdate, Page, PagedExposureMetadata,
+	PayoutDryRun, RewardBreakdown, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
 };
@@ -234,6 +235,108 @@ impl<T: Config> Pallet<T> {
 		active_era.min(earliest_unlock_era_by_offence_queue)
 	}
 
+	/// Schedule `value` of the stash of `controller` to be unlocked, see [`Call::unbond`].
+	///
+	/// Once withdrawn, the funds are sent to `dest`, if any.
+	pub(super) fn do_unbond(
+		controller: T::AccountId,
+		value: BalanceOf<T>,
+		dest: Option<T::AccountId>,
+	) -> DispatchResultWithPostInfo {
+		let unlocking = Self::ledger(Controller(controller.clone())).map(|l| l.unlocking.len())?;
+
+		// if there are no unlocking chunks available, try to remove any chunks by withdrawing
+		// funds that have fully unbonded.
+		let maybe_withdraw_weight = {
+			if unlocking == T::MaxUnlockingChunks::get() as usize {
+				Some(Self::do_withdraw_unbonded(&controller)?)
+			} else {
+				None
+			}
+		};
+
+		// we need to fetch the ledger again because it may have been mutated in the call
+		// to `Self::do_withdraw_unbonded` above.
+		let mut ledger = Self::ledger(Controller(controller))?;
+		let mut value = value.min(ledger.active);
+		let stash = ledger.stash.clone();
+
+		ensure!(
+			ledger.unlocking.len() < T::MaxUnlockingChunks::get() as usize,
+			Error::<T>::NoMoreChunks,
+		);
+
+		if !value.is_zero() {
+			ledger.active -= value;
+
+			// Avoid there being a dust balance left in the staking system.
+			if ledger.active < asset::existential_deposit::<T>() {
+				value += ledger.active;
+				ledger.active = Zero::zero();
+			}
+
+			let min_active_bond = if Nominators::<T>::contains_key(&stash) {
+				Self::min_nominator_bond()
+			} else if Validators::<T>::contains_key(&stash) {
+				Self::min_validator_bond()
+			} else {
+				// staker is chilled, no min bond.
+				Zero::zero()
+			};
+
+			// Make sure that the user maintains enough active bond for their role.
+			// If a user runs into this error, they should chill first.
+			ensure!(ledger.active >= min_active_bond, Error::<T>::InsufficientBond);
+
+			// Note: we used current era before, but that is meant to be used for only election.
+			// The right value to use here is the active era.
+
+			let active_era = session_rotation::Rotator::<T>::active_era();
+			// The chunk era always uses the full bonding duration, so that slashes keep covering
+			// the chunk. An `EmergencyBondingDuration` only lets it be withdrawn earlier.
+			let era = active_era.saturating_add(T::BondingDuration::get());
+			let unlock_era = active_era.saturating_add(Self::unbonding_duration());
+			let withdrawable_era = Some(unlock_era).filter(|unlock_era| *unlock_era < era);
+			let merge_window = T::UnlockingChunkMergeWindow::get();
+			if let Some(chunk) = ledger.unlocking.last_mut().filter(|chunk| {
+				chunk.era <= era &&
+					era - chunk.era <= merge_window &&
+					chunk.unlock_era() <= unlock_era &&
+					chunk.dest == dest
+			}) {
+				// To keep the chunk count down, we keep at most one chunk per
+				// `UnlockingChunkMergeWindow` eras and destination. Since `unlocking` is a FiFo
+				// queue, we only ever merge into the last chunk, and never if that would let its
+				// funds be withdrawn earlier.
+				chunk.value = chunk.value.defensive_saturating_add(value);
+				chunk.era = era;
+				chunk.withdrawable_era = withdrawable_era;
+			} else {
+				ledger
+					.unlocking
+					.try_push(UnlockChunk { value, era, dest, withdrawable_era })
+					.map_err(|_| Error::<T>::NoMoreChunks)?;
+			};
+			// NOTE: ledger must be updated prior to calling `Self::weight_of`.
+			ledger.update()?;
+
+			// update this staker in the sorted list, if they exist in it.
+			if T::VoterList::contains(&stash) {
+				let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash));
+			}
+
+			Self::deposit_event(Event::<T>::Unbonded { stash, amount: value });
+		}
+
+		let actual_weight = if let Some(withdraw_weight) = maybe_withdraw_weight {
+			Some(T::WeightInfo::unbond().saturating_add(withdraw_weight))
+		} else {
+			Some(T::WeightInfo::unbond())
+		};
+
+		Ok(actual_weight.into())
+	}
+
 	pub(super) fn do_withdraw_unbonded(controller: &T::AccountId) -> Result<Weight, DispatchError> {
 		let mut ledger = Self::ledger(Controller(controller.clone()))?;
 		let (stash, old_total) = (ledger.stash.clone(), ledger.total);
@@ -254,6 +357,14 @@ impl<T: Config> Pallet<T> {
 			earliest_era_to_withdraw
 		);
 
+		// chunks unbonded through `unbond_to` are sent to their destination once withdrawn.
+		let destined = ledger
+			.unlocking
+			.iter()
+			.filter(|chunk| chunk.unlock_era() <= earliest_era_to_withdraw)
+			.filter_map(|chunk| chunk.dest.clone().map(|dest| (dest, chunk.value)))
+			.collect::<Vec<_>>();
+
 		// withdraw unbonded balance from the ledger until earliest_era_to_withdraw.
 		ledger = ledger.consolidate_unlocked(earliest_era_to_withdraw);
 
@@ -263,9 +374,11 @@ impl<T: Config> Pallet<T> {
 			"consolidate_unlocked should never increase the total balance of the ledger"
 		);
 
-		let used_weight = if ledger.unlocking.is_empty() &&
-			(ledger.active < Self::min_chilled_bond() || ledger.active.is_zero())
-		{
+		let remaining_chunks = ledger.unlocking.len() as u32;
+		let fully_unbonded = ledger.unlocking.is_empty() &&
+			(ledger.active < Self::min_chilled_bond() || ledger.active.is_zero());
+
+		let used_weight = if fully_unbonded {
 			// This account must have called `unbond()` with some value that caused the active
 			// portion to fall below existential deposit + will have no more unlocking chunks
 			// left. We can now safely remove all staking-related information.
@@ -285,12 +398,39 @@ impl<T: Config> Pallet<T> {
 		if new_total < old_total {
 			// Already checked that this won't overflow by entry condition.
 			let value = old_total.defensive_saturating_sub(new_total);
-			Self::deposit_event(Event::<T>::Withdrawn { stash, amount: value });
+			Self::deposit_event(Event::<T>::Withdrawn {
+				stash: stash.clone(),
+				amount: value,
+				remaining_chunks,
+			});
 
 			// notify listeners.
 			T::EventListeners::on_withdraw(controller, value);
 		}
 
+		if fully_unbonded {
+			Self::deposit_event(Event::<T>::StashFullyUnbonded { stash: stash.clone() });
+		}
+
+		// best effort: if the transfer fails, the funds simply stay in the stash.
+		for (dest, amount) in destined {
+			match asset::transfer::<T>(&stash, &dest, amount) {
+				Ok(()) => Self::deposit_event(Event::<T>::WithdrawnTo {
+					stash: stash.clone(),
+					dest,
+					amount,
+				}),
+				Err(e) => log!(
+					warn,
+					"failed to send {:?} withdrawn from {:?} to {:?}: {:?}",
+					amount,
+					stash,
+					dest,
+					e
+				),
+			}
+		}
+
 		Ok(used_weight)
 	}
 
@@ -306,6 +446,11 @@ impl<T: Config> Pallet<T> {
 		validator_stash: T::AccountId,
 		era: EraIndex,
 	) -> DispatchResultWithPostInfo {
+		ensure!(
+			!Eras::<T>::is_reward_skipped(era),
+			Error::<T>::NothingToPayout.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
 		let page = Eras::<T>::get_next_claimable_page(era, &validator_stash).ok_or_else(|| {
 			Error::<T>::AlreadyClaimed.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
 		})?;
@@ -343,6 +488,11 @@ impl<T: Config> Pallet<T> {
 				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
 		})?;
 
+		ensure!(
+			!Eras::<T>::is_reward_skipped(era),
+			Error::<T>::NothingToPayout.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
 		let account = StakingAccount::Stash(validator_stash.clone());
 		let ledger = Self::ledger(account.clone()).or_else(|_| {
 			if StakingLedger::<T>::is_bonded(account) {
@@ -395,7 +545,19 @@ impl<T: Config> Pallet<T> {
 		// This is how much validator + nominators are entitled to.
 		let validator_total_payout = validator_total_reward_part * era_payout;
 
-		let validator_commission = Eras::<T>::get_validator_commission(era, &ledger.stash);
+		// Anything above the validator's per-era reward cap goes to `RewardRemainder` instead, and
+		// so does all of it if the validator earned too few points.
+		let validator_prefs = Eras::<T>::get_validator_prefs(era, &ledger.stash);
+		let (validator_total_payout, validator_excess_payout) =
+			match validator_prefs.max_reward_per_era {
+				_ if validator_reward_points < T::MinEraPointsForReward::get() =>
+					(Zero::zero(), validator_total_payout),
+				Some(cap) if validator_total_payout > cap =>
+					(cap, validator_total_payout.defensive_saturating_sub(cap)),
+				_ => (validator_total_payout, Zero::zero()),
+			};
+
+		let validator_commission = validator_prefs.commission;
 		// total commission validator takes across all nominator pages
 		let validator_total_commission_payout = validator_commission * validator_total_payout;
 
@@ -407,6 +569,11 @@ impl<T: Config> Pallet<T> {
 		let page_stake_part = Perbill::from_rational(exposure.page_total(), exposure.total());
 		// validator commission is paid out in fraction across pages proportional to the page stake.
 		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
+		// and so is the excess above the validator's reward cap.
+		let page_excess_payout = page_stake_part * validator_excess_payout;
+		if !page_excess_payout.is_zero() {
+			T::RewardRemainder::on_unbalanced(asset::issue::<T>(page_excess_payout));
+		}
 
 		Self::deposit_event(Event::<T>::PayoutStarted {
 			era_index: era,
@@ -416,12 +583,28 @@ impl<T: Config> Pallet<T> {
 		});
 
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
+		// Rewards that could not be paid out to anyone, and are thus never minted.
+		let mut burned_payout = BalanceOf::<T>::zero();
+		// Part of the above that was sent to the reward remainder instead, see
+		// `Config::DeadPayeeBehavior`.
+		let mut rerouted_payout = BalanceOf::<T>::zero();
 		// We can now make total validator payout:
+		let validator_payout = validator_staking_payout + validator_commission_payout;
+		let oldest_era = current_era.saturating_sub(history_depth);
 		if let Some((imbalance, dest)) =
-			Self::make_payout(&stash, validator_staking_payout + validator_commission_payout)
+			Self::make_payout(&stash, era, validator_payout, &mut rerouted_payout)
 		{
+			Self::note_recent_reward(&stash, era, imbalance.peek(), oldest_era);
+			// the page was marked as claimed above, so this is counted once per page.
+			if !validator_commission_payout.is_zero() {
+				ValidatorTotalCommission::<T>::mutate(&stash, |total| {
+					total.saturating_accrue(validator_commission_payout)
+				});
+			}
 			Self::deposit_event(Event::<T>::Rewarded { stash, dest, amount: imbalance.peek() });
 			total_imbalance.subsume(imbalance);
+		} else {
+			burned_payout.saturating_accrue(validator_payout);
 		}
 
 		// Track the number of payout ops to nominators. Note:
@@ -437,9 +620,12 @@ impl<T: Config> Pallet<T> {
 			let nominator_reward: BalanceOf<T> =
 				nominator_exposure_part * validator_leftover_payout;
 			// We can now make nominator payout:
-			if let Some((imbalance, dest)) = Self::make_payout(&nominator.who, nominator_reward) {
+			if let Some((imbalance, dest)) =
+				Self::make_payout(&nominator.who, era, nominator_reward, &mut rerouted_payout)
+			{
 				// Note: this logic does not count payouts for `RewardDestination::None`.
 				nominator_payout_count += 1;
+				Self::note_recent_reward(&nominator.who, era, imbalance.peek(), oldest_era);
 				let e = Event::<T>::Rewarded {
 					stash: nominator.who.clone(),
 					dest,
@@ -447,29 +633,139 @@ impl<T: Config> Pallet<T> {
 				};
 				Self::deposit_event(e);
 				total_imbalance.subsume(imbalance);
+			} else {
+				burned_payout.saturating_accrue(nominator_reward);
 			}
 		}
 
 		T::Reward::on_unbalanced(total_imbalance);
+		Eras::<T>::reroute_staker_rewards(
+			era,
+			page_excess_payout.saturating_add(rerouted_payout),
+			burned_payout.saturating_sub(rerouted_payout),
+		);
 		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
+	/// Automatically pay out pages of past eras, without exceeding `remaining_weight`.
+	///
+	/// First up to [`Config::AutoPayoutExpiring`] pages of the oldest era that can still be paid
+	/// out, then up to [`Config::AutoPayoutPerBlock`] pages of the last ended era. Returns the
+	/// consumed weight.
+	pub(crate) fn do_auto_payout(remaining_weight: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(remaining_weight);
+
+		let max_expiring = T::AutoPayoutExpiring::get();
+		// `CurrentEra`.
+		if !max_expiring.is_zero() && meter.try_consume(T::DbWeight::get().reads(1)).is_ok() {
+			// the oldest era within `HistoryDepth`, which can no longer be paid out once the next
+			// era is planned.
+			let expiring = CurrentEra::<T>::get()
+				.and_then(|current| current.checked_sub(T::HistoryDepth::get()));
+			if let Some(era) = expiring {
+				Self::auto_payout_era::<AutoPayoutExpiringCursor<T>>(era, max_expiring, &mut meter);
+			}
+		}
+
+		let max_pages = T::AutoPayoutPerBlock::get();
+		// `ActiveEra`.
+		if !max_pages.is_zero() && meter.try_consume(T::DbWeight::get().reads(1)).is_ok() {
+			if let Some(era) = ActiveEra::<T>::get().and_then(|a| a.index.checked_sub(1)) {
+				Self::auto_payout_era::<AutoPayoutCursor<T>>(era, max_pages, &mut meter);
+			}
+		}
+
+		meter.consumed()
+	}
+
+	/// Pay out up to `max_pages` pages of `era`, within the limits of `meter`.
+	///
+	/// Validators are visited in the iteration order of [`ErasStakersOverview`], and progress is
+	/// kept in the cursor `C` so that it resumes in the next block.
+	fn auto_payout_era<C>(era: EraIndex, max_pages: u32, meter: &mut WeightMeter)
+	where
+		C: frame_support::storage::StorageValue<
+			(EraIndex, Option<T::AccountId>),
+			Query = Option<(EraIndex, Option<T::AccountId>)>,
+		>,
+	{
+		// `C`.
+		if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+			return
+		}
+
+		let start_after = match C::get() {
+			// this era is already fully paid out.
+			Some((cursor_era, None)) if cursor_era >= era => return,
+			Some((cursor_era, Some(last))) if cursor_era == era => Some(last),
+			_ => None,
+		};
+		let validators = match start_after {
+			Some(ref last) => ErasStakersOverview::<T>::iter_key_prefix_from(
+				era,
+				ErasStakersOverview::<T>::hashed_key_for(era, last),
+			),
+			None => ErasStakersOverview::<T>::iter_key_prefix(era),
+		};
+
+		// worst case weight of paying out a single page, plus finding its next claimable page.
+		let page_weight = T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+			.saturating_add(T::DbWeight::get().reads(2));
+		let mut paid_pages = 0u32;
+		let mut last_done = start_after;
+
+		for validator in validators {
+			loop {
+				if paid_pages >= max_pages || !meter.can_consume(page_weight) {
+					// out of budget, resume from here in the next block.
+					if last_done.is_some() {
+						C::put((era, last_done));
+						meter.consume(T::DbWeight::get().writes(1));
+					}
+					return
+				}
+
+				let Some(page) = Eras::<T>::get_next_claimable_page(era, &validator) else { break };
+				meter.consume(page_weight);
+				paid_pages.saturating_inc();
+
+				if let Err(e) = Self::do_payout_stakers_by_page(validator.clone(), era, page) {
+					// the page cannot be paid out, skip the rest of this validator.
+					log!(debug, "auto payout of {:?} in era {:?} failed: {:?}", validator, era, e);
+					break
+				}
+			}
+			last_done = Some(validator);
+		}
+
+		// all validators of this era are paid out.
+		C::put((era, None::<T::AccountId>));
+		meter.consume(T::DbWeight::get().writes(1));
+	}
+
 	/// Chill a stash account.
 	pub(crate) fn chill_stash(stash: &T::AccountId) {
 		let chilled_as_validator = Self::do_remove_validator(stash);
 		let chilled_as_nominator = Self::do_remove_nominator(stash);
+		PausedNominations::<T>::remove(stash);
 		if chilled_as_validator || chilled_as_nominator {
+			ChilledSince::<T>::insert(stash, CurrentEra::<T>::get().unwrap_or(0));
 			Self::deposit_event(Event::<T>::Chilled { stash: stash.clone() });
 		}
 	}
 
 	/// Actually make a payment to a staker. This uses the currency's reward function
 	/// to pay the right payee for the given staker account.
+	///
+	/// Rewards sent to [`Config::RewardRemainder`] as per [`Config::DeadPayeeBehavior`] are added
+	/// to `rerouted`.
 	fn make_payout(
 		stash: &T::AccountId,
+		era: EraIndex,
 		amount: BalanceOf<T>,
+		rerouted: &mut BalanceOf<T>,
 	) -> Option<(PositiveImbalanceOf<T>, RewardDestination<T::AccountId>)> {
 		// noop if amount is zero
 		if amount.is_zero() {
@@ -484,6 +780,7 @@ impl<T: Config> Pallet<T> {
 					ledger.active += amount;
 					ledger.total += amount;
 					let r = asset::mint_into_existing::<T>(stash, amount);
+					let ledger = Self::auto_compound_unlocking(ledger, era);
 
 					let _ = ledger
 						.update()
@@ -492,6 +789,22 @@ impl<T: Config> Pallet<T> {
 					Ok(r)
 				})
 				.unwrap_or_default(),
+			RewardDestination::Account(ref dest_account)
+				if asset::total_balance::<T>(dest_account).is_zero() &&
+					amount < asset::existential_deposit::<T>() =>
+				match T::DeadPayeeBehavior::get() {
+					// the account could not be created, so nothing is minted. The caller accounts the
+					// reward as burned.
+					DeadPayeeBehavior::Burn => None,
+					DeadPayeeBehavior::ToStash =>
+						return asset::mint_into_existing::<T>(stash, amount)
+							.map(|imbalance| (imbalance, RewardDestination::Stash)),
+					DeadPayeeBehavior::ToRemainder => {
+						T::RewardRemainder::on_unbalanced(asset::issue::<T>(amount));
+						rerouted.saturating_accrue(amount);
+						None
+					},
+				},
 			RewardDestination::Account(ref dest_account) =>
 				Some(asset::mint_creating::<T>(&dest_account, amount)),
 			RewardDestination::None => None,
@@ -507,6 +820,57 @@ impl<T: Config> Pallet<T> {
 		maybe_imbalance.map(|imbalance| (imbalance, dest))
 	}
 
+	/// Add `amount` to the [`RecentRewards`] of `stash` in `era`, dropping the eras before
+	/// `oldest_era`, and the oldest era if there are too many.
+	fn note_recent_reward(
+		stash: &T::AccountId,
+		era: EraIndex,
+		amount: BalanceOf<T>,
+		oldest_era: EraIndex,
+	) {
+		if T::MaxRecentRewards::get().is_zero() {
+			return
+		}
+
+		RecentRewards::<T>::mutate(stash, |rewards| {
+			rewards.retain(|(e, _)| *e >= oldest_era);
+			match rewards.binary_search_by_key(&era, |(e, _)| *e) {
+				Ok(index) => rewards[index].1.saturating_accrue(amount),
+				// if full, this drops the oldest era, or `era` itself if it is the oldest.
+				Err(index) => {
+					let _ = rewards.force_insert_keep_right(index, (era, amount));
+				},
+			}
+		});
+	}
+
+	/// Rebond the opted in fraction of the unlocking chunks of `ledger` on its first payout of
+	/// `era`, see [`Call::set_auto_compound_unlocking`].
+	fn auto_compound_unlocking(ledger: StakingLedger<T>, era: EraIndex) -> StakingLedger<T> {
+		if !T::AllowAutoCompoundUnlocking::get() {
+			return ledger
+		}
+		let Some(fraction) = AutoCompoundUnlocking::<T>::get(&ledger.stash) else { return ledger };
+		if AutoCompoundedEra::<T>::get(&ledger.stash).is_some_and(|compounded| compounded >= era) {
+			return ledger
+		}
+		AutoCompoundedEra::<T>::insert(&ledger.stash, era);
+
+		let unlocking = ledger
+			.unlocking
+			.iter()
+			.filter(|chunk| chunk.dest.is_none() && chunk.withdrawable_era.is_none())
+			.fold(BalanceOf::<T>::zero(), |acc, chunk| acc.saturating_add(chunk.value));
+		let (ledger, rebonded) = ledger.rebond_own(fraction * unlocking);
+		if !rebonded.is_zero() {
+			Self::deposit_event(Event::<T>::Bonded {
+				stash: ledger.stash.clone(),
+				amount: rebonded,
+			});
+		}
+		ledger
+	}
+
 	/// Remove all associated data of a stash account from the staking system.
 	///
 	/// Assumes storage is upgraded before calling.
@@ -521,6 +885,13 @@ impl<T: Config> Pallet<T> {
 
 		Self::do_remove_validator(&stash);
 		Self::do_remove_nominator(&stash);
+		AutoRenominate::<T>::remove(&stash);
+		PausedNominations::<T>::remove(&stash);
+		AutoCompoundUnlocking::<T>::remove(&stash);
+		AutoCompoundedEra::<T>::remove(&stash);
+		RecentRewards::<T>::remove(&stash);
+		ChilledSince::<T>::remove(&stash);
+		ValidatorTotalCommission::<T>::remove(&stash);
 
 		Ok(())
 	}
@@ -609,13 +980,22 @@ impl<T: Config> Pallet<T> {
 				continue
 			}
 
-			if let Some(Nominations { targets, .. }) = <Nominators<T>>::get(&voter) {
+			if let Some((targets, Nominations { suppressed, .. })) =
+				Self::unbounded_nominations(&voter)
+			{
+				if suppressed {
+					log!(debug, "voter's nominations are suppressed. skip this voter.");
+					continue
+				}
+
 				if !targets.is_empty() {
 					// Note on lazy nomination quota: we do not check the nomination quota of the
 					// voter at this point and accept all the current nominations. The nomination
 					// quota is only enforced at `nominate` time.
-
-					let voter = (voter, voter_weight, targets);
+					//
+					// Targets can only exceed `MaxNominationsOf` if it was lowered, until the
+					// nominations are trimmed. Defensively cap them for the snapshot.
+					let voter = (voter, voter_weight, Self::cap_targets(targets));
 					if voters_size_tracker.try_register_voter(&voter, &bounds).is_err() {
 						// no more space left for the election result, stop iterating.
 						Self::deposit_event(Event::<T>::SnapshotVotersSizeExceeded {
@@ -652,15 +1032,9 @@ impl<T: Config> Pallet<T> {
 				all_voters.push(self_vote);
 				validators_taken.saturating_inc();
 			} else {
-				// this can only happen if: 1. there a bug in the bags-list (or whatever is the
-				// sorted list) logic and the state of the two pallets is no longer compatible, or
-				// because the nominators is not decodable since they have more nomination than
-				// `T::NominationsQuota::get_quota`. The latter can rarely happen, and is not
-				// really an emergency or bug if it does.
-				defensive!(
-				    "invalid item in `VoterList`: {:?}, this nominator probably has too many nominations now",
-                    voter,
-                );
+				// this can only happen if there a bug in the bags-list (or whatever is the sorted
+				// list) logic and the state of the two pallets is no longer compatible.
+				defensive!("invalid item in `VoterList`: {:?}", voter);
 			}
 		}
 
@@ -722,6 +1096,129 @@ impl<T: Config> Pallet<T> {
 		all_targets
 	}
 
+	/// The number of eras new unbonding requests are locked for.
+	///
+	/// This is [`Config::BondingDuration`], unless shortened by [`EmergencyBondingDuration`].
+	pub(crate) fn unbonding_duration() -> EraIndex {
+		let bonding_duration = T::BondingDuration::get();
+		EmergencyBondingDuration::<T>::get()
+			.map_or(bonding_duration, |duration| duration.min(bonding_duration))
+	}
+
+	/// Check that the owner of `ledger` can nominate `targets`, without mutating any state.
+	///
+	/// These are all the checks of [`Call::nominate`] past the origin and target lookup. Returns
+	/// the sorted and deduplicated targets.
+	pub(crate) fn check_nominate(
+		ledger: &StakingLedger<T>,
+		mut targets: Vec<T::AccountId>,
+	) -> Result<BoundedVec<T::AccountId, MaxNominationsOf<T>>, NominateError> {
+		ensure!(ledger.active >= Self::min_nominator_bond(), NominateError::InsufficientBond);
+		let stash = &ledger.stash;
+
+		// Only check limits if they are not already a nominator.
+		if !Nominators::<T>::contains_key(stash) {
+			ensure!(Self::role_change_allowed(stash), NominateError::RoleChangeTooSoon);
+			// If this error is reached, we need to adjust the `MinNominatorBond` and start
+			// calling `chill_other`. Until then, we explicitly block new nominators to protect
+			// the runtime.
+			if let Some(max_nominators) = MaxNominatorsCount::<T>::get() {
+				ensure!(
+					Nominators::<T>::count() < max_nominators,
+					NominateError::TooManyNominators
+				);
+			}
+		}
+
+		// dedup targets
+		targets.sort();
+		targets.dedup();
+
+		ensure!(!targets.is_empty(), NominateError::EmptyTargets);
+		ensure!(
+			targets.len() <= T::NominationsQuota::get_quota(ledger.active) as usize,
+			NominateError::TooManyTargets
+		);
+
+		let old = Nominators::<T>::get(stash).map_or_else(Vec::new, |x| x.targets.into_inner());
+		let max_nominators = T::MaxNominatorsPerValidator::get();
+
+		targets
+			.into_iter()
+			.map(|n| {
+				if old.contains(&n) {
+					Ok(n)
+				} else if !Validators::<T>::contains_key(&n) || Validators::<T>::get(&n).blocked {
+					Err(NominateError::BadTarget)
+				} else if max_nominators != 0 && BackersCount::<T>::get(&n) >= max_nominators {
+					Err(NominateError::ValidatorFull)
+				} else {
+					Ok(n)
+				}
+			})
+			.collect::<Result<Vec<_>, _>>()?
+			.try_into()
+			.map_err(|_| NominateError::TooManyNominators)
+	}
+
+	/// Whether `stash` changed its role at least [`Config::RoleChangeCooldown`] eras ago.
+	///
+	/// The last role change of `stash` is when it was chilled, became a validator, or, for
+	/// nominators, last nominated. A stash that never validated nor nominated can take any role.
+	pub(crate) fn role_change_allowed(stash: &T::AccountId) -> bool {
+		let cooldown = T::RoleChangeCooldown::get();
+		if cooldown.is_zero() {
+			return true
+		}
+
+		let last_change = ChilledSince::<T>::get(stash)
+			.or_else(|| ValidatorSince::<T>::get(stash))
+			.or_else(|| Self::unbounded_nominations(stash).map(|(_, n)| n.submitted_in));
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		last_change.is_none_or(|era| current_era >= era.saturating_add(cooldown))
+	}
+
+	/// Read the nominations of `who`, without the [`MaxNominationsOf`] bound on their targets.
+	///
+	/// Returns all the targets, and the rest of the nominations with no targets. Unlike
+	/// `Nominators::get`, this also works for nominations that became non-decodable since
+	/// `MaxNominations` was lowered.
+	pub(crate) fn unbounded_nominations(
+		who: &T::AccountId,
+	) -> Option<(Vec<T::AccountId>, Nominations<T>)> {
+		let raw = frame_support::storage::unhashed::get_raw(&Nominators::<T>::hashed_key_for(who))?;
+		let input = &mut &raw[..];
+		let targets = Vec::<T::AccountId>::decode(input).ok()?;
+		let nominations = Nominations {
+			targets: Default::default(),
+			submitted_in: EraIndex::decode(input).ok()?,
+			suppressed: bool::decode(input).ok()?,
+		};
+		Some((targets, nominations))
+	}
+
+	/// Cap `targets` at [`MaxNominationsOf`], keeping the targets with the highest stake.
+	///
+	/// Targets that are not validators anymore count as having no stake. The kept targets remain
+	/// sorted, as [`Call::nominate`] stores them.
+	pub(crate) fn cap_targets(
+		mut targets: Vec<T::AccountId>,
+	) -> BoundedVec<T::AccountId, MaxNominationsOf<T>> {
+		let max = MaxNominationsOf::<T>::get() as usize;
+		if targets.len() > max {
+			targets.sort_by_cached_key(|target| {
+				core::cmp::Reverse(if Validators::<T>::contains_key(target) {
+					Self::slashable_balance_of(target)
+				} else {
+					Zero::zero()
+				})
+			});
+			targets.truncate(max);
+			targets.sort();
+		}
+		BoundedVec::truncate_from(targets)
+	}
+
 	/// This function will add a nominator to the `Nominators` storage map,
 	/// and `VoterList`.
 	///
@@ -736,7 +1233,15 @@ impl<T: Config> Pallet<T> {
 			let _ = T::VoterList::on_insert(who.clone(), Self::weight_of(who))
 				.defensive_unwrap_or_default();
 		}
+		let old = Self::unbounded_nominations(who).map_or_else(Vec::new, |(targets, _)| targets);
+		for target in old.iter().filter(|t| !nominations.targets.contains(t)) {
+			Self::remove_backer(target, who);
+		}
+		for target in nominations.targets.iter().filter(|t| !old.contains(t)) {
+			Self::add_backer(target, who);
+		}
 		Nominators::<T>::insert(who, nominations);
+		ChilledSince::<T>::remove(who);
 
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
@@ -754,6 +1259,9 @@ impl<T: Config> Pallet<T> {
 	/// wrong.
 	pub fn do_remove_nominator(who: &T::AccountId) -> bool {
 		let outcome = if Nominators::<T>::contains_key(who) {
+			for target in Self::unbounded_nominations(who).map(|(t, _)| t).unwrap_or_default() {
+				Self::remove_backer(&target, who);
+			}
 			Nominators::<T>::remove(who);
 			let _ = T::VoterList::on_remove(who);
 			true
@@ -769,6 +1277,23 @@ impl<T: Config> Pallet<T> {
 		outcome
 	}
 
+	/// Add `who` to the [`Backers`] of `target`, if not already there.
+	pub(crate) fn add_backer(target: &T::AccountId, who: &T::AccountId) {
+		if !Backers::<T>::contains_key(target, who) {
+			Backers::<T>::insert(target, who, ());
+			BackersCount::<T>::mutate(target, |count| count.saturating_inc());
+		}
+	}
+
+	/// Remove `who` from the [`Backers`] of `target`, if there.
+	pub(crate) fn remove_backer(target: &T::AccountId, who: &T::AccountId) {
+		if Backers::<T>::take(target, who).is_some() {
+			BackersCount::<T>::mutate_exists(target, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| !count.is_zero())
+			});
+		}
+	}
+
 	/// This function will add a validator to the `Validators` storage map.
 	///
 	/// If the validator already exists, their preferences will be updated.
@@ -776,12 +1301,14 @@ impl<T: Config> Pallet<T> {
 	/// NOTE: you must ALWAYS use this function to add a validator to the system. Any access to
 	/// `Validators` or `VoterList` outside of this function is almost certainly
 	/// wrong.
-	pub fn do_add_validator(who: &T::AccountId, prefs: ValidatorPrefs) {
+	pub fn do_add_validator(who: &T::AccountId, prefs: ValidatorPrefs<BalanceOf<T>>) {
 		if !Validators::<T>::contains_key(who) {
 			// maybe update sorted list.
 			let _ = T::VoterList::on_insert(who.clone(), Self::weight_of(who));
+			ValidatorSince::<T>::insert(who, CurrentEra::<T>::get().unwrap_or(0));
 		}
 		Validators::<T>::insert(who, prefs);
+		ChilledSince::<T>::remove(who);
 
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
@@ -799,6 +1326,7 @@ impl<T: Config> Pallet<T> {
 	pub fn do_remove_validator(who: &T::AccountId) -> bool {
 		let outcome = if Validators::<T>::contains_key(who) {
 			Validators::<T>::remove(who);
+			ValidatorSince::<T>::remove(who);
 			let _ = T::VoterList::on_remove(who);
 			true
 		} else {
@@ -927,6 +1455,17 @@ impl<T: Config> Pallet<T> {
 		Self::eras_stakers(era, &account)
 	}
 
+	pub fn api_eras_stakers_clipped(
+		era: EraIndex,
+		validator: T::AccountId,
+	) -> Option<Exposure<T::AccountId, BalanceOf<T>>> {
+		Eras::<T>::get_paged_exposure(era, &validator, 0).map(|exposure| Exposure {
+			total: exposure.total(),
+			own: exposure.own(),
+			others: exposure.others().clone(),
+		})
+	}
+
 	pub fn api_eras_stakers_page_count(era: EraIndex, account: T::AccountId) -> Page {
 		Eras::<T>::exposure_page_count(era, &account)
 	}
@@ -934,6 +1473,429 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		Eras::<T>::pending_rewards(era, &account)
 	}
+
+	pub fn api_bonded_eras() -> Vec<(EraIndex, SessionIndex)> {
+		BondedEras::<T>::get().into_inner()
+	}
+
+	pub fn api_era_for_session(session: SessionIndex) -> Option<EraIndex> {
+		Rotator::<T>::session_era(session)
+	}
+
+	pub fn api_sessions_in_era(era: EraIndex) -> Vec<SessionIndex> {
+		Rotator::<T>::era_sessions(era)
+			.map(|sessions| sessions.collect())
+			.unwrap_or_default()
+	}
+
+	pub fn api_intended_validator_count() -> u32 {
+		ValidatorCount::<T>::get()
+	}
+
+	pub fn api_minimum_validator_count() -> u32 {
+		T::MinimumValidatorCount::get()
+	}
+
+	pub fn api_fast_unstake_eligible(stash: T::AccountId) -> bool {
+		let Ok(ledger) = Self::ledger(Stash(stash.clone())) else { return false };
+
+		// must be chilled, and have nothing unlocking.
+		if Validators::<T>::contains_key(&stash) ||
+			Nominators::<T>::contains_key(&stash) ||
+			ledger.active != ledger.total
+		{
+			return false
+		}
+
+		// must not be exposed in any era that can still be slashed. Exit on the first exposure.
+		let current_era = CurrentEra::<T>::get().unwrap_or_default();
+		let first_era = current_era.saturating_sub(T::BondingDuration::get());
+		!(first_era..=current_era).any(|era| {
+			ErasStakersOverview::<T>::contains_key(era, &stash) ||
+				<Self as StakingInterface>::is_exposed_in_era(&stash, &era)
+		})
+	}
+
+	pub fn api_estimate_validator_era_reward(
+		era: EraIndex,
+		validator: T::AccountId,
+	) -> Option<BalanceOf<T>> {
+		let era_payout = Eras::<T>::get_validators_reward(era)?;
+		let era_reward_points = Eras::<T>::get_reward_points(era);
+		let validator_reward_points = era_reward_points
+			.individual
+			.get(&validator)
+			.copied()
+			.filter(|p| !p.is_zero() && *p >= T::MinEraPointsForReward::get())?;
+
+		// same arithmetic as in `do_payout_stakers_by_page`.
+		Some(Perbill::from_rational(validator_reward_points, era_reward_points.total) * era_payout)
+	}
+
+	pub fn api_staker_status(
+		stash: T::AccountId,
+	) -> Option<sp_staking::StakerStatus<T::AccountId>> {
+		<Self as StakingInterface>::status(&stash).ok()
+	}
+
+	pub fn api_validator_commissions() -> Vec<(T::AccountId, Perbill, bool)> {
+		let active_era = Rotator::<T>::active_era();
+		ErasValidatorPrefs::<T>::iter_prefix(active_era)
+			.take(T::MaxValidatorSet::get() as usize)
+			.map(|(validator, prefs)| (validator, prefs.commission, prefs.blocked))
+			.collect()
+	}
+
+	pub fn api_exposed_stashes(era: EraIndex) -> Vec<T::AccountId> {
+		use alloc::collections::btree_set::BTreeSet;
+
+		// validators with only their own stake have no exposure pages, so start from the overview.
+		let mut exposed = ErasStakersOverview::<T>::iter_key_prefix(era).collect::<BTreeSet<_>>();
+		for page in ErasStakersPaged::<T>::iter_prefix_values((era,)) {
+			exposed.extend(page.others.iter().map(|individual| individual.who.clone()));
+		}
+
+		exposed.into_iter().collect()
+	}
+
+	pub fn api_nominator_exposure(
+		era: EraIndex,
+		nominator: T::AccountId,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		// the nominations may have changed since `era`, so scan the exposures of the era instead.
+		// a nominator is exposed at most once per validator.
+		let mut exposure = ErasStakersPaged::<T>::iter_prefix((era,))
+			.filter_map(|((validator, _page), exposure_page)| {
+				exposure_page
+					.others
+					.iter()
+					.find(|individual| individual.who == nominator)
+					.map(|individual| (validator, individual.value))
+			})
+			.collect::<Vec<_>>();
+		exposure.sort_by(|(a, _), (b, _)| a.cmp(b));
+		exposure
+	}
+
+	pub fn api_can_nominate(
+		stash: T::AccountId,
+		targets: Vec<T::AccountId>,
+	) -> Result<(), NominateError> {
+		let ledger = Self::ledger(Stash(stash)).map_err(|_| NominateError::NotStash)?;
+		Self::check_nominate(&ledger, targets).map(|_| ())
+	}
+
+	pub fn api_historical_validator_set(era: EraIndex) -> Option<Vec<T::AccountId>> {
+		let oldest_era = Rotator::<T>::active_era().saturating_sub(T::HistoryDepth::get());
+		if era > Rotator::<T>::planned_era() || era < oldest_era {
+			return None
+		}
+
+		Some(ErasStakersOverview::<T>::iter_key_prefix(era).collect())
+	}
+
+	pub fn api_page_claim_status(era: EraIndex, validator: T::AccountId) -> Vec<(Page, bool)> {
+		if !ErasStakersOverview::<T>::contains_key(era, &validator) {
+			return Vec::new()
+		}
+
+		let claimed = ClaimedRewards::<T>::get(era, &validator);
+		(0..Eras::<T>::exposure_page_count(era, &validator))
+			.map(|page| (page, claimed.contains(&page)))
+			.collect()
+	}
+
+	pub fn api_total_bonded() -> BalanceOf<T> {
+		TotalBonded::<T>::get()
+	}
+
+	pub fn api_is_validator_blocked(who: T::AccountId) -> bool {
+		Validators::<T>::get(who).blocked
+	}
+
+	pub fn api_era_reward_breakdown(era: EraIndex) -> Option<RewardBreakdown<BalanceOf<T>>> {
+		ErasRewardBreakdown::<T>::get(era)
+	}
+
+	pub fn api_pending_slashes(stash: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		use alloc::collections::btree_map::BTreeMap;
+
+		// a stash can be slashed more than once in the same era, e.g. as a validator and as a
+		// nominator of another offender, so sum up per era.
+		let mut pending = BTreeMap::<EraIndex, BalanceOf<T>>::new();
+		for (apply_era, _, slash) in UnappliedSlashes::<T>::iter() {
+			let own = (slash.validator == stash).then_some(slash.own);
+			let nominated = slash.others.iter().find(|(who, _)| *who == stash).map(|(_, v)| *v);
+			for amount in own.into_iter().chain(nominated) {
+				let total = pending.entry(apply_era).or_default();
+				*total = total.saturating_add(amount);
+			}
+		}
+
+		pending.into_iter().collect()
+	}
+
+	pub fn api_slashes_applied_in(era: EraIndex) -> u32 {
+		ErasSlashesApplied::<T>::get(era)
+	}
+
+	pub fn api_unapplied_slashes(
+		era: EraIndex,
+	) -> Vec<sp_staking::UnappliedSlash<T::AccountId, BalanceOf<T>>> {
+		UnappliedSlashes::<T>::iter_prefix_values(era)
+			.map(|slash| sp_staking::UnappliedSlash {
+				validator: slash.validator,
+				own: slash.own,
+				others: slash.others.into_inner(),
+				reporters: slash.reporters.into_inner(),
+				payout: slash.payout,
+			})
+			.collect()
+	}
+
+	pub fn api_exposure_overview(
+		era: EraIndex,
+		validator: T::AccountId,
+	) -> Option<PagedExposureMetadata<BalanceOf<T>>> {
+		ErasStakersOverview::<T>::get(era, validator)
+	}
+
+	pub fn api_exposure_page(
+		era: EraIndex,
+		validator: T::AccountId,
+		page: Page,
+	) -> Option<(PagedExposureMetadata<BalanceOf<T>>, ExposurePage<T::AccountId, BalanceOf<T>>)> {
+		if page >= Eras::<T>::exposure_page_count(era, &validator) {
+			return None
+		}
+		Eras::<T>::get_paged_exposure(era, &validator, page)
+			.map(|exposure| (exposure.exposure_metadata, exposure.exposure_page))
+	}
+
+	pub fn api_targets_of(nominator: T::AccountId) -> Vec<T::AccountId> {
+		Nominators::<T>::get(nominator)
+			.map(|n| n.targets.into_inner())
+			.unwrap_or_default()
+	}
+
+	pub fn api_backers_of(validator: T::AccountId) -> Vec<T::AccountId> {
+		Backers::<T>::iter_key_prefix(validator).collect()
+	}
+
+	pub fn api_bonded_many(stashes: Vec<T::AccountId>) -> Vec<Option<T::AccountId>> {
+		stashes
+			.into_iter()
+			.take(MAX_BATCH_LOOKUP as usize)
+			.map(Bonded::<T>::get)
+			.collect()
+	}
+
+	pub fn api_ledger_exists_many(controllers: Vec<T::AccountId>) -> Vec<bool> {
+		controllers
+			.into_iter()
+			.take(MAX_BATCH_LOOKUP as usize)
+			.map(Ledger::<T>::contains_key)
+			.collect()
+	}
+
+	pub fn api_estimate_nominator_apr(stash: T::AccountId) -> Option<FixedU128> {
+		const MILLISECONDS_PER_YEAR: u128 = 1000 * 3600 * 24 * 36525 / 100;
+
+		let era = Rotator::<T>::active_era().checked_sub(1)?;
+		let era_duration = ErasDuration::<T>::get(era).filter(|d| !d.is_zero())?;
+
+		let mut stake = BalanceOf::<T>::zero();
+		let mut reward = BalanceOf::<T>::zero();
+		for (validator, value) in Self::api_nominator_exposure(era, stash) {
+			stake.saturating_accrue(value);
+			let Some(overview) = ErasStakersOverview::<T>::get(era, &validator) else { continue };
+			let Some(validator_reward) =
+				Self::api_estimate_validator_era_reward(era, validator.clone())
+			else {
+				continue
+			};
+
+			// same arithmetic as in `do_payout_stakers_by_page`.
+			let prefs = Eras::<T>::get_validator_prefs(era, &validator);
+			let validator_reward = prefs
+				.max_reward_per_era
+				.map_or(validator_reward, |cap| validator_reward.min(cap));
+			let leftover = validator_reward.saturating_sub(prefs.commission * validator_reward);
+			reward.saturating_accrue(Perbill::from_rational(value, overview.total) * leftover);
+		}
+
+		if stake.is_zero() {
+			return None
+		}
+
+		let (reward, stake): (u128, u128) = (reward.saturated_into(), stake.saturated_into());
+		Some(FixedU128::saturating_from_rational(
+			reward.saturating_mul(MILLISECONDS_PER_YEAR),
+			stake.saturating_mul(era_duration.into()),
+		))
+	}
+
+	pub fn api_validator_since(who: T::AccountId) -> Option<EraIndex> {
+		ValidatorSince::<T>::get(who)
+	}
+
+	pub fn api_validator_total_commission(validator: T::AccountId) -> BalanceOf<T> {
+		ValidatorTotalCommission::<T>::get(validator)
+	}
+
+	pub fn api_era_points(
+		era: EraIndex,
+	) -> Option<(RewardPoint, Vec<(T::AccountId, RewardPoint)>)> {
+		let active_era = Rotator::<T>::active_era();
+		if era > active_era || era < active_era.saturating_sub(T::HistoryDepth::get()) {
+			return None
+		}
+
+		let points = Eras::<T>::get_reward_points(era);
+		Some((points.total, points.individual.into_iter().collect()))
+	}
+
+	pub fn api_validator_exposure_hash(era: EraIndex, validator: T::AccountId) -> Option<T::Hash> {
+		if !ErasStakersOverview::<T>::contains_key(era, &validator) {
+			return None
+		}
+
+		// pages are filled in election order, which is not something a client can reproduce.
+		let mut exposure = Eras::<T>::get_full_exposure(era, &validator);
+		exposure.others.sort_by(|a, b| a.who.cmp(&b.who));
+		Some(T::Hashing::hash_of(&exposure))
+	}
+
+	pub fn api_staking_rate() -> Perquintill {
+		let staked = ErasTotalStake::<T>::get(Rotator::<T>::active_era());
+		Perquintill::from_rational(staked, asset::total_issuance::<T>())
+	}
+
+	pub fn api_min_nominator_bond() -> BalanceOf<T> {
+		Self::min_nominator_bond()
+	}
+
+	pub fn api_min_validator_bond() -> BalanceOf<T> {
+		Self::min_validator_bond()
+	}
+
+	pub fn api_is_chilled(stash: T::AccountId) -> bool {
+		ChilledSince::<T>::contains_key(stash)
+	}
+
+	pub fn api_chilled_since(stash: T::AccountId) -> Option<EraIndex> {
+		ChilledSince::<T>::get(stash)
+	}
+
+	pub fn api_validator_oversubscribed(era: EraIndex, validator: T::AccountId) -> bool {
+		ErasStakersOverview::<T>::get(era, &validator)
+			.is_some_and(|o| BackersCount::<T>::get(&validator) > o.nominator_count)
+	}
+
+	pub fn api_dry_run_payout(era: EraIndex, validator: T::AccountId, page: Page) -> PayoutDryRun {
+		// same checks as in `do_payout_stakers_by_page`.
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		let claimable = era <= current_era &&
+			era >= current_era.saturating_sub(T::HistoryDepth::get()) &&
+			page < Eras::<T>::exposure_page_count(era, &validator) &&
+			Eras::<T>::get_validators_reward(era).is_some() &&
+			!Eras::<T>::is_reward_skipped(era) &&
+			Self::ledger(Stash(validator.clone())).is_ok() &&
+			!Eras::<T>::is_rewards_claimed(era, &validator, page);
+		let Some(exposure) =
+			Eras::<T>::get_paged_exposure(era, &validator, page).filter(|_| claimable)
+		else {
+			return PayoutDryRun::default()
+		};
+
+		// the page is claimed, but nothing is paid out if the validator has no points, or fewer
+		// than `MinEraPointsForReward`.
+		let validator_reward_points = Eras::<T>::get_reward_points(era)
+			.individual
+			.get(&validator)
+			.copied()
+			.unwrap_or_else(Zero::zero);
+		if validator_reward_points.is_zero() ||
+			validator_reward_points < T::MinEraPointsForReward::get()
+		{
+			return PayoutDryRun { nominator_payouts: 0, newly_claimed: true }
+		}
+		let Some(validator_reward) =
+			Self::api_estimate_validator_era_reward(era, validator.clone())
+		else {
+			return PayoutDryRun { nominator_payouts: 0, newly_claimed: true }
+		};
+
+		// same arithmetic as in `do_payout_stakers_by_page`.
+		let prefs = Eras::<T>::get_validator_prefs(era, &validator);
+		let validator_reward = prefs
+			.max_reward_per_era
+			.map_or(validator_reward, |cap| validator_reward.min(cap));
+		let leftover = validator_reward.saturating_sub(prefs.commission * validator_reward);
+		let nominator_payouts = exposure
+			.others()
+			.iter()
+			.filter(|n| !(Perbill::from_rational(n.value, exposure.total()) * leftover).is_zero())
+			.filter(|n| {
+				Self::payee(Stash(n.who.clone()))
+					.is_some_and(|dest| dest != RewardDestination::None)
+			})
+			.count() as u32;
+
+		PayoutDryRun { nominator_payouts, newly_claimed: true }
+	}
+
+	pub fn api_minimum_elected_stake() -> BalanceOf<T> {
+		MinimumElectedStake::<T>::get()
+	}
+
+	pub fn api_total_unlocking(stash: T::AccountId) -> BalanceOf<T> {
+		Self::ledger(Stash(stash))
+			.map(|ledger| ledger.total.saturating_sub(ledger.active))
+			.unwrap_or_default()
+	}
+
+	pub fn api_reward_destination(
+		stash: T::AccountId,
+	) -> Option<sp_staking::RewardDestination<T::AccountId>> {
+		use sp_staking::RewardDestination as Dest;
+		Payee::<T>::get(&stash).map(|dest| match dest {
+			RewardDestination::Staked => Dest::Staked,
+			RewardDestination::Stash => Dest::Stash,
+			RewardDestination::Account(who) => Dest::Account(who),
+			RewardDestination::None => Dest::None,
+			// paid to the controller, see `Self::make_payout`.
+			#[allow(deprecated)]
+			RewardDestination::Controller => Self::bonded(&stash).map_or(Dest::None, Dest::Account),
+		})
+	}
+
+	pub fn api_recent_rewards(stash: T::AccountId, n: u32) -> Vec<(EraIndex, BalanceOf<T>)> {
+		let oldest_era = CurrentEra::<T>::get().unwrap_or(0).saturating_sub(T::HistoryDepth::get());
+		RecentRewards::<T>::get(stash)
+			.into_iter()
+			.rev()
+			.take_while(|(era, _)| *era >= oldest_era)
+			.take(n as usize)
+			.collect()
+	}
+
+	pub fn api_nomination_age(stash: T::AccountId) -> Option<EraIndex> {
+		let nominations = Nominators::<T>::get(stash)?;
+		Some(CurrentEra::<T>::get().unwrap_or(0).saturating_sub(nominations.submitted_in))
+	}
+
+	pub fn api_unbonding_schedule(stash: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		let Ok(ledger) = Self::ledger(Stash(stash)) else { return Vec::new() };
+
+		let mut schedule = ledger
+			.unlocking
+			.into_iter()
+			.map(|chunk| (chunk.unlock_era(), chunk.value))
+			.collect::<Vec<_>>();
+		schedule.sort_by_key(|(era, _)| *era);
+		schedule
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1055,7 +2017,11 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		<Ledger<T>>::insert(target.clone(), StakingLedger::<T>::new(target.clone(), stake));
 		Self::do_add_validator(
 			&target,
-			ValidatorPrefs { commission: Perbill::zero(), blocked: false },
+			ValidatorPrefs {
+				commission: Perbill::zero(),
+				blocked: false,
+				max_reward_per_era: None,
+			},
 		);
 	}
 
@@ -1068,7 +2034,13 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		#[allow(deprecated)]
 		<Validators<T>>::remove_all();
 		#[allow(deprecated)]
+		<ValidatorSince<T>>::remove_all(None);
+		#[allow(deprecated)]
 		<Nominators<T>>::remove_all();
+		#[allow(deprecated)]
+		<Backers<T>>::remove_all(None);
+		#[allow(deprecated)]
+		<BackersCount<T>>::remove_all(None);
 
 		T::VoterList::unsafe_clear();
 	}
@@ -1087,7 +2059,11 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 			<Ledger<T>>::insert(v.clone(), StakingLedger::<T>::new(v.clone(), stake));
 			Self::do_add_validator(
 				&v,
-				ValidatorPrefs { commission: Perbill::zero(), blocked: false },
+				ValidatorPrefs {
+					commission: Perbill::zero(),
+					blocked: false,
+					max_reward_per_era: None,
+				},
 			);
 		});
 
@@ -1130,6 +2106,7 @@ impl<T: Config> rc_client::AHStakingInterface for Pallet<T> {
 			activation_timestamp,
 			validator_points,
 			leftover,
+			chunk: _,
 		} = report;
 		debug_assert!(!leftover);
 
@@ -1146,6 +2123,10 @@ impl<T: Config> rc_client::AHStakingInterface for Pallet<T> {
 			.saturating_add(T::WeightInfo::prune_era(ValidatorCount::<T>::get()))
 	}
 
+	fn active_validators() -> Vec<Self::AccountId> {
+		ErasStakersOverview::<T>::iter_key_prefix(Rotator::<T>::active_era()).collect()
+	}
+
 	/// Accepts offences only if they are from era `active_era - (SlashDeferDuration - 1)` or newer.
 	///
 	/// Slashes for offences are applied `SlashDeferDuration` eras after the offence occurred.
@@ -1252,7 +2233,7 @@ impl<T: Config> rc_client::AHStakingInterface for Pallet<T> {
 						offence_era,
 						&validator,
 						OffenceRecord {
-							reporter: o.reporters.first().cloned(),
+							reporters: unique_reporters::<T>(o.reporters),
 							reported_era: active_era.index,
 							slash_fraction,
 							..existing
@@ -1293,7 +2274,7 @@ impl<T: Config> rc_client::AHStakingInterface for Pallet<T> {
 					offence_era,
 					&validator,
 					OffenceRecord {
-						reporter: o.reporters.first().cloned(),
+						reporters: unique_reporters::<T>(o.reporters),
 						reported_era: active_era.index,
 						// there are cases of validator with no exposure, hence 0 page, so we
 						// saturate to avoid underflow.
@@ -1447,6 +2428,8 @@ impl<T: Config> SortedListProvider<T::AccountId> for UseValidatorsMap<T> {
 	fn unsafe_clear() {
 		#[allow(deprecated)]
 		Validators::<T>::remove_all();
+		#[allow(deprecated)]
+		ValidatorSince::<T>::remove_all(None);
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1529,6 +2512,10 @@ impl<T: Config> SortedListProvider<T::AccountId> for UseNominatorsAndValidatorsM
 		#[allow(deprecated)]
 		Nominators::<T>::remove_all();
 		#[allow(deprecated)]
+		Backers::<T>::remove_all(None);
+		#[allow(deprecated)]
+		BackersCount::<T>::remove_all(None);
+		#[allow(deprecated)]
 		Validators::<T>::remove_all();
 	}
 
@@ -1777,7 +2764,11 @@ impl<T: Config> Pallet<T> {
 		Self::check_payees()?;
 		Self::check_paged_exposures()?;
 		Self::check_count()?;
+		Self::check_backers()?;
+		Self::check_validator_since()?;
+		Self::check_chilled_since()?;
 		Self::check_slash_health()?;
+		Self::check_total_bonded()?;
 
 		Ok(())
 	}
@@ -1883,6 +2874,62 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Invariants:
+	/// * Every target of every nominator in [`Nominators`] has a matching [`Backers`] entry.
+	/// * Every [`Backers`] entry is backed by a nomination in [`Nominators`].
+	/// * [`BackersCount`] holds the number of [`Backers`] of every target, and nothing else.
+	pub(crate) fn check_backers() -> Result<(), TryRuntimeError> {
+		use alloc::collections::btree_map::BTreeMap;
+		let mut edges = 0usize;
+		let mut counts = BTreeMap::<T::AccountId, u32>::new();
+		for (nominator, nominations) in Nominators::<T>::iter() {
+			for target in nominations.targets {
+				ensure!(
+					Backers::<T>::contains_key(&target, &nominator),
+					"nomination missing from the backers index"
+				);
+				edges.saturating_inc();
+				counts.entry(target).or_default().saturating_inc();
+			}
+		}
+		ensure!(Backers::<T>::iter_keys().count() == edges, "stale entry in the backers index");
+		ensure!(
+			BackersCount::<T>::iter().collect::<BTreeMap<_, _>>() == counts,
+			"BackersCount does not match the backers index"
+		);
+		Ok(())
+	}
+
+	/// Invariants:
+	/// * Every stash in [`ValidatorSince`] is a validator.
+	fn check_validator_since() -> Result<(), TryRuntimeError> {
+		ensure!(
+			ValidatorSince::<T>::iter_keys().all(|v| Validators::<T>::contains_key(v)),
+			"ValidatorSince contains a stash that is not a validator"
+		);
+		Ok(())
+	}
+
+	/// Invariants:
+	/// * No stash in [`ChilledSince`] is a validator or nominator.
+	fn check_chilled_since() -> Result<(), TryRuntimeError> {
+		ensure!(
+			ChilledSince::<T>::iter_keys()
+				.all(|s| !Validators::<T>::contains_key(&s) && !Nominators::<T>::contains_key(&s)),
+			"ChilledSince contains a stash that is still a validator or nominator"
+		);
+		Ok(())
+	}
+
+	/// Invariants:
+	/// * [`TotalBonded`] is the sum of the `total` of all ledgers.
+	pub(crate) fn check_total_bonded() -> Result<(), TryRuntimeError> {
+		let sum = Ledger::<T>::iter_values()
+			.fold(BalanceOf::<T>::zero(), |acc, ledger| acc.saturating_add(ledger.total));
+		ensure!(TotalBonded::<T>::get() == sum, "TotalBonded does not match the sum of ledgers");
+		Ok(())
+	}
+
 	/// Invariants:
 	/// * Stake consistency: ledger.total == ledger.active + sum(ledger.unlocking).
 	/// * The ledger's controller and stash matches the associated `Bonded` tuple.
@@ -1949,7 +2996,6 @@ impl<T: Config> Pallet<T> {
 	/// * Paged exposures metadata (`ErasStakersOverview`) matches the paged exposures state.
 	fn check_paged_exposures() -> Result<(), TryRuntimeError> {
 		use alloc::collections::btree_map::BTreeMap;
-		use sp_staking::PagedExposureMetadata;
 
 		// Sanity check for the paged exposure of the active era.
 		let mut exposures: BTreeMap<T::AccountId, PagedExposureMetadata<BalanceOf<T>>> =
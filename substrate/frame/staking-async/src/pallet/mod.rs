@@ -18,10 +18,11 @@
 //! `pallet-staking-async`'s main `pallet` module.
 
 use crate::{
-	asset, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
-	EraRewardPoints, ExposurePage, Forcing, LedgerIntegrityState, MaxNominationsOf,
-	NegativeImbalanceOf, Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination,
-	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
+	asset, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf,
+	DeadPayeeBehavior, EraPayout, EraRewardPoints, ExposurePage, Forcing, LedgerIntegrityState,
+	MaxNominationsOf, NegativeImbalanceOf, Nominations, NominationsQuota, PositiveImbalanceOf,
+	PostSlashDustThreshold, RewardDestination, RewardPoint, StakingLedger, UnappliedSlash,
+	UnderSubscribedBehavior, UnlockChunk, ValidatorPrefs,
 };
 use alloc::{format, vec::Vec};
 use codec::Codec;
@@ -34,8 +35,7 @@ use frame_support::{
 			hold::{Balanced as FunHoldBalanced, Mutate as FunHoldMutate},
 			Mutate, Mutate as FunMutate,
 		},
-		Contains, Defensive, DefensiveSaturating, EnsureOrigin, Get, InspectLockableCurrency,
-		Nothing, OnUnbalanced,
+		Contains, Defensive, EnsureOrigin, Get, InspectLockableCurrency, Nothing, OnUnbalanced,
 	},
 	weights::Weight,
 	BoundedBTreeSet, BoundedVec,
@@ -53,7 +53,7 @@ use sp_runtime::{
 	ArithmeticError, Perbill, Percent,
 };
 use sp_staking::{
-	EraIndex, Page, SessionIndex,
+	EraIndex, NominateError, Page, RewardBreakdown, SessionIndex,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
 };
@@ -71,7 +71,7 @@ pub mod pallet {
 	use frame_support::DefaultNoBound;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(23);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -153,8 +153,8 @@ pub mod pallet {
 		///
 		/// Following information is kept for eras in `[current_era -
 		/// HistoryDepth, current_era]`: `ErasValidatorPrefs`, `ErasValidatorReward`,
-		/// `ErasRewardPoints`, `ErasTotalStake`, `ClaimedRewards`,
-		/// `ErasStakersPaged`, `ErasStakersOverview`.
+		/// `ErasRewardBreakdown`, `ErasDuration`, `ErasRewardPoints`, `ErasTotalStake`,
+		/// `ErasSlashesApplied`, `ClaimedRewards`, `ErasStakersPaged`, `ErasStakersOverview`.
 		///
 		/// Must be more than the number of eras delayed by session.
 		/// I.e. active era must always be in history. I.e. `active_era >
@@ -181,6 +181,9 @@ pub mod pallet {
 		type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
 
 		/// Number of sessions per era, as per the preferences of the **relay chain**.
+		///
+		/// This may change at runtime, e.g. by governance. A new value only applies from the next
+		/// era onwards, see [`ActiveEraSessions`].
 		#[pallet::constant]
 		type SessionsPerEra: Get<SessionIndex>;
 
@@ -212,6 +215,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type SlashDeferDuration: Get<EraIndex>;
 
+		/// Maximum number of deferred slashes applied in a single block, at the start of the era
+		/// they are due in.
+		///
+		/// The remaining slashes are applied in the following blocks. Slashes that are not applied
+		/// by the end of their era can still be applied via [`Call::apply_slash`], and block
+		/// withdrawals until then. Must be non-zero.
+		#[pallet::constant]
+		type MaxSlashesPerBlock: Get<u32>;
+
 		/// The origin which can manage less critical staking parameters that does not require root.
 		///
 		/// Supported actions: (1) cancel deferred slash, (2) set minimum commission.
@@ -237,6 +249,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxExposurePageSize: Get<u32>;
 
+		/// The maximum number of nominators exposed per validator in an era.
+		///
+		/// If more nominators back a validator, only the ones with the highest stake are exposed.
+		/// The stake of the rest behind this validator is left unexposed: they are neither
+		/// rewarded nor slashed for it in that era. See [`Event::NominatorNotExposed`].
+		#[pallet::constant]
+		type MaxBackersExposed: Get<u32>;
+
 		/// The absolute maximum of winner validators this pallet should return.
 		///
 		/// As this pallet supports multi-block election, the set of winner validators *per
@@ -244,6 +264,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxValidatorSet: Get<u32>;
 
+		/// The minimum number of validators an election must yield for its result to be used
+		/// as-is. Otherwise, [`Config::UnderSubscribedBehavior`] applies.
+		#[pallet::constant]
+		type MinimumValidatorCount: Get<u32>;
+
+		/// What to do when an election yields fewer than [`Config::MinimumValidatorCount`]
+		/// validators.
+		#[pallet::constant]
+		type UnderSubscribedBehavior: Get<UnderSubscribedBehavior>;
+
+		/// What to do with a reward whose [`RewardDestination::Account`] cannot receive it, since
+		/// the account does not exist and the reward is below the existential deposit.
+		#[pallet::constant]
+		type DeadPayeeBehavior: Get<DeadPayeeBehavior>;
+
 		/// Something that provides a best-effort sorted list of voters aka electing nominators,
 		/// used for NPoS election.
 		///
@@ -294,9 +329,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUnlockingChunks: Get<u32>;
 
+		/// The maximum number of eras between the last unlocking chunk of a ledger and a new
+		/// unbonding request for the two to be merged into a single chunk.
+		///
+		/// With `0`, only unbonding requests that unlock in the same era are merged. Otherwise,
+		/// the last chunk is postponed to the unlocking era of the new request. Its funds then
+		/// stay locked, and remain slashable for offences, for up to this many eras longer than
+		/// they would have otherwise.
+		#[pallet::constant]
+		type UnlockingChunkMergeWindow: Get<EraIndex>;
+
 		/// The maximum amount of controller accounts that can be deprecated in one call.
 		type MaxControllersInDeprecationBatch: Get<u32>;
 
+		/// The maximum number of nominators that a validator can kick in a single call to
+		/// [`Call::kick`].
+		#[pallet::constant]
+		type MaxKicks: Get<u32>;
+
+		/// The maximum number of validators that can be updated in a single call to
+		/// [`Call::force_set_blocked`].
+		#[pallet::constant]
+		type MaxBlockedUpdates: Get<u32>;
+
 		/// Something that listens to staking updates and performs actions based on the data it
 		/// receives.
 		///
@@ -308,6 +363,108 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxInvulnerables: Get<u32>;
 
+		/// Maximum number of payout pages that are automatically paid out in `on_idle`, per block.
+		///
+		/// If non-zero, the pages of the last ended era are paid out in the background, as long as
+		/// there is enough idle weight left in the block. Progress is kept in
+		/// [`AutoPayoutCursor`] and resumed in the next block.
+		///
+		/// Set to zero to disable automatic payouts.
+		#[pallet::constant]
+		type AutoPayoutPerBlock: Get<u32>;
+
+		/// The minimum active bond, per role, that a validator or nominator must keep after being
+		/// slashed. A staker whose active bond falls below it is chilled.
+		#[pallet::no_default_bounds]
+		type PostSlashDustThreshold: PostSlashDustThreshold<BalanceOf<Self>>;
+
+		/// The maximum reward paid to the reporters of the slashes of an offender in an era, across
+		/// all exposure pages of the offender, see [`ErasSlashReward`].
+		///
+		/// The reward is [`SlashRewardFraction`] of the slashed amount, clamped to this value. If
+		/// set to zero, the reward is not clamped.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type MaxSlashReward: Get<BalanceOf<Self>>;
+
+		/// The maximum number of reporters kept for an offence.
+		///
+		/// Duplicate reporters are dropped, and only the first `MaxReporters` unique ones share
+		/// the reward of the slash.
+		#[pallet::constant]
+		type MaxReporters: Get<u32>;
+
+		/// Whether stakers may opt in to having part of their unlocking chunks rebonded whenever
+		/// they are paid out, via [`Call::set_auto_compound_unlocking`].
+		///
+		/// Only stakers with [`RewardDestination::Staked`] are affected.
+		#[pallet::constant]
+		type AllowAutoCompoundUnlocking: Get<bool>;
+
+		/// Whether the nominations of a nominator are suppressed when it is slashed.
+		///
+		/// Suppressed nominations are left out of the election snapshot until the nominator
+		/// nominates again.
+		#[pallet::constant]
+		type SuppressSlashedNominations: Get<bool>;
+
+		/// The maximum number of eras for which the rewards of a stash are kept in
+		/// [`RecentRewards`].
+		///
+		/// Set to zero to not keep any.
+		#[pallet::constant]
+		type MaxRecentRewards: Get<u32>;
+
+		/// The fraction of a validator's reward points that is carried over into the next era.
+		///
+		/// At the start of an era, each validator exposed in it is seeded with this fraction of
+		/// the points it earned in the ending era. Set to zero to start every era afresh.
+		#[pallet::constant]
+		type RewardPointsCarryOver: Get<Perbill>;
+
+		/// Whether eras without any validator reward are skipped by payouts.
+		///
+		/// If set, all pages of an era whose [`ErasValidatorReward`] is zero are marked as claimed
+		/// when the era ends, and [`Call::payout_stakers`] rejects them with
+		/// [`Error::NothingToPayout`].
+		#[pallet::constant]
+		type SkipZeroRewardEras: Get<bool>;
+
+		/// The maximum number of nominators a validator can have.
+		///
+		/// Nominating a validator that already has this many nominators fails with
+		/// [`Error::ValidatorFull`], unless it was already nominated. Existing nominations are not
+		/// affected when this is lowered. Set to zero for no limit.
+		#[pallet::constant]
+		type MaxNominatorsPerValidator: Get<u32>;
+
+		/// Maximum number of payout pages of the oldest era within [`Config::HistoryDepth`] that
+		/// are automatically paid out in `on_idle`, per block.
+		///
+		/// That era can no longer be paid out once the next era is planned, so this pays out
+		/// rewards that no one claimed before they are lost. It takes precedence over
+		/// [`Config::AutoPayoutPerBlock`] and keeps its progress in [`AutoPayoutExpiringCursor`].
+		///
+		/// Set to zero to disable.
+		#[pallet::constant]
+		type AutoPayoutExpiring: Get<u32>;
+
+		/// The minimum number of eras between two role changes of a stash.
+		///
+		/// Becoming a validator or a nominator fails with [`Error::RoleChangeTooSoon`] within this
+		/// many eras of the last role change of the stash, i.e. of it being chilled, becoming a
+		/// validator, or last nominating. Chilling is always allowed. Set to zero for no limit.
+		#[pallet::constant]
+		type RoleChangeCooldown: Get<EraIndex>;
+
+		/// The minimum reward points a validator must earn in an era to be rewarded for it.
+		///
+		/// The share of the era reward of a validator with fewer points, and thus of its
+		/// nominators, goes to [`Config::RewardRemainder`] instead. Set to zero to reward any
+		/// validator with points.
+		#[pallet::constant]
+		type MinEraPointsForReward: Get<RewardPoint>;
+
 		/// Maximum allowed era duration in milliseconds.
 		///
 		/// This provides a defensive upper bound to cap the effective era duration, preventing
@@ -348,7 +505,10 @@ pub mod pallet {
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
-		use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+		use frame_support::{
+			derive_impl, parameter_types,
+			traits::{ConstBool, ConstU32},
+		};
 		pub struct TestDefaultConfig;
 
 		#[derive_impl(frame_system::config_preludes::TestDefaultConfig, no_aggregated_types)]
@@ -357,6 +517,9 @@ pub mod pallet {
 		parameter_types! {
 			pub const SessionsPerEra: SessionIndex = 3;
 			pub const BondingDuration: EraIndex = 3;
+			pub const UnderSubscribedBehavior: crate::UnderSubscribedBehavior =
+				crate::UnderSubscribedBehavior::UseWhatWeGot;
+			pub const DeadPayeeBehavior: crate::DeadPayeeBehavior = crate::DeadPayeeBehavior::Burn;
 		}
 
 		#[frame_support::register_default_impl(TestDefaultConfig)]
@@ -374,11 +537,32 @@ pub mod pallet {
 			type BondingDuration = BondingDuration;
 			type PlanningEraOffset = ConstU32<1>;
 			type SlashDeferDuration = ();
+			type MaxSlashesPerBlock = ConstU32<1>;
 			type MaxExposurePageSize = ConstU32<64>;
+			type MaxBackersExposed = ConstU32<{ u32::MAX }>;
 			type MaxUnlockingChunks = ConstU32<32>;
+			type UnlockingChunkMergeWindow = ConstU32<0>;
 			type MaxValidatorSet = ConstU32<100>;
+			type MinimumValidatorCount = ConstU32<0>;
+			type UnderSubscribedBehavior = UnderSubscribedBehavior;
+			type DeadPayeeBehavior = DeadPayeeBehavior;
 			type MaxControllersInDeprecationBatch = ConstU32<100>;
+			type MaxKicks = ConstU32<128>;
+			type MaxBlockedUpdates = ConstU32<64>;
 			type MaxInvulnerables = ConstU32<20>;
+			type AutoPayoutPerBlock = ConstU32<0>;
+			type PostSlashDustThreshold = ();
+			type MaxSlashReward = ();
+			type MaxReporters = ConstU32<16>;
+			type AllowAutoCompoundUnlocking = ConstBool<false>;
+			type SuppressSlashedNominations = ConstBool<false>;
+			type MaxRecentRewards = ConstU32<0>;
+			type RewardPointsCarryOver = ();
+			type SkipZeroRewardEras = ConstBool<false>;
+			type MaxNominatorsPerValidator = ConstU32<0>;
+			type AutoPayoutExpiring = ConstU32<0>;
+			type RoleChangeCooldown = ConstU32<0>;
+			type MinEraPointsForReward = ConstU32<0>;
 			type MaxEraDuration = ();
 			type EventListeners = ();
 			type Filter = Nothing;
@@ -415,12 +599,30 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinimumActiveStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// The total stake backing the lowest-backed validator of the last successful election.
+	///
+	/// This is the stake a validator needed to be elected, i.e. the election cutoff.
+	#[pallet::storage]
+	pub type MinimumElectedStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	/// The minimum amount of commission that validators can set.
 	///
 	/// If set to `0`, no limit exists.
 	#[pallet::storage]
 	pub type MinCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// If set, the bonding duration used for new unbonding requests instead of
+	/// [`Config::BondingDuration`], capped at the latter.
+	///
+	/// Existing unlocking chunks are not affected. New chunks can be withdrawn early, see
+	/// [`UnlockChunk::withdrawable_era`], but are slashed like any other chunk until then.
+	/// Offences and slashes still use the full [`Config::BondingDuration`], which means that funds
+	/// unbonded while this is set can become withdrawable before all offences committed while they
+	/// were bonded are reported. Only meant to be set temporarily, in emergencies. See
+	/// [`Call::set_emergency_bonding_duration`].
+	#[pallet::storage]
+	pub type EmergencyBondingDuration<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
 	/// Map from all (unlocked) "controller" accounts to the info regarding the staking.
 	///
 	/// Note: All the reads and mutations to this storage *MUST* be done through the methods exposed
@@ -428,6 +630,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Ledger<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, StakingLedger<T>>;
 
+	/// The sum of the `total` of all [`Ledger`]s, i.e. the live amount bonded in the system.
+	///
+	/// Unlike [`ErasTotalStake`], this reflects every bond, unbond, withdrawal and slash as soon
+	/// as it happens.
+	#[pallet::storage]
+	pub type TotalBonded<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	/// Where the reward payment should be made. Keyed by stash.
 	///
 	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
@@ -440,7 +649,28 @@ pub mod pallet {
 	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
 	#[pallet::storage]
 	pub type Validators<T: Config> =
-		CountedStorageMap<_, Twox64Concat, T::AccountId, ValidatorPrefs, ValueQuery>;
+		CountedStorageMap<_, Twox64Concat, T::AccountId, ValidatorPrefs<BalanceOf<T>>, ValueQuery>;
+
+	/// The era in which a stash in [`Validators`] declared its intent to validate, i.e. the current
+	/// era at the time it became a validator.
+	///
+	/// Calling [`Call::validate`] again does not change it, and it is removed once the stash is no
+	/// longer a validator. Validators that became one before this map was introduced have no
+	/// entry.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ValidatorSince<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
+
+	/// The era in which a stash was chilled, i.e. the current era at the time it stopped being a
+	/// validator or nominator.
+	///
+	/// It is removed once the stash validates or nominates again, or is killed. Stashes that were
+	/// never a validator or nominator have no entry.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ChilledSince<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
 
 	/// The maximum validator count before we stop allowing new validators to join.
 	///
@@ -455,9 +685,8 @@ pub mod pallet {
 	/// account's [`NominationsQuota::MaxNominations`] configuration is decreased.
 	/// In this rare case, these nominators
 	/// are still existent in storage, their key is correct and retrievable (i.e. `contains_key`
-	/// indicates that they exist), but their value cannot be decoded. Therefore, the non-decodable
-	/// nominators will effectively not-exist, until they re-submit their preferences such that it
-	/// is within the bounds of the newly set `Config::MaxNominations`.
+	/// indicates that they exist), but their value cannot be decoded, until they re-submit their
+	/// preferences such that it is within the bounds of the newly set `Config::MaxNominations`.
 	///
 	/// This implies that `::iter_keys().count()` and `::iter().count()` might return different
 	/// values for this map. Moreover, the main `::count()` is aligned with the former, namely the
@@ -471,6 +700,91 @@ pub mod pallet {
 	pub type Nominators<T: Config> =
 		CountedStorageMap<_, Twox64Concat, T::AccountId, Nominations<T>>;
 
+	/// Reverse index of [`Nominators`]: `(target, nominator)` exists iff `target` is in the
+	/// nominations of `nominator`.
+	///
+	/// This costs one extra storage entry per nomination, i.e. up to `MaxNominations` entries per
+	/// nominator, and as many extra writes whenever nominations change. It must only be updated
+	/// alongside [`Nominators`], via [`Pallet::do_add_nominator`] and
+	/// [`Pallet::do_remove_nominator`].
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type Backers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, ()>;
+
+	/// The number of [`Backers`] of each target.
+	///
+	/// Kept in sync with [`Backers`] through [`Pallet::add_backer`] and
+	/// [`Pallet::remove_backer`], so that the backers of a target can be counted with one read.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type BackersCount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Nominators that opted in to having targets that are no longer validators removed from
+	/// their nominations by anyone, via [`Call::process_auto_renominate`].
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AutoRenominate<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ()>;
+
+	/// The targets of nominators that paused nominating via [`Call::chill_keep_targets`], to be
+	/// restored by [`Call::resume`].
+	///
+	/// A paused stash is not in [`Nominators`], and thus not a voter in elections. The record is
+	/// dropped once the stash nominates, validates, is chilled or is unbonded.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type PausedNominations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<T::AccountId, MaxNominationsOf<T>>>;
+
+	/// Stakers that opted in to having the given fraction of their unlocking chunks rebonded
+	/// whenever they are paid out with [`RewardDestination::Staked`].
+	///
+	/// Only honoured while [`Config::AllowAutoCompoundUnlocking`] is set.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AutoCompoundUnlocking<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill>;
+
+	/// The latest era whose payout rebonded the unlocking chunks of a stash, as per
+	/// [`AutoCompoundUnlocking`].
+	///
+	/// Unlocking chunks are rebonded at most once per era, on the first payout of an era newer
+	/// than this one, no matter how many pages or validators pay the stash.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AutoCompoundedEra<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
+
+	/// The most recent rewards paid out to a stash, as `(era, amount)` sorted by era, keeping at
+	/// most [`Config::MaxRecentRewards`] eras.
+	///
+	/// Rewards of the same era, e.g. from several validators, are summed up. Eras older than
+	/// [`Config::HistoryDepth`] are pruned whenever the stash is paid out.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type RecentRewards<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(EraIndex, BalanceOf<T>), T::MaxRecentRewards>,
+		ValueQuery,
+	>;
+
+	/// The total commission paid out to a validator, across all eras and pages.
+	///
+	/// Commission that could not be paid out, e.g. due to [`RewardDestination::None`], is not
+	/// counted.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ValidatorTotalCommission<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	/// Stakers whose funds are managed by other pallets.
 	///
 	/// This pallet does not apply any locks on them, therefore they are only virtually bonded. They
@@ -502,6 +816,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ActiveEra<T> = StorageValue<_, ActiveEraInfo>;
 
+	/// The number of sessions of the active era.
+	///
+	/// Fixed from [`Config::SessionsPerEra`] when the era starts, so that changing it does not
+	/// affect an ongoing era. If not set, [`Config::SessionsPerEra`] is used.
+	#[pallet::storage]
+	pub type ActiveEraSessions<T> = StorageValue<_, SessionIndex>;
+
 	/// Custom bound for [`BondedEras`] which is equal to [`Config::BondingDuration`] + 1.
 	pub struct BondedErasBound<T>(core::marker::PhantomData<T>);
 	impl<T: Config> Get<u32> for BondedErasBound<T> {
@@ -658,7 +979,7 @@ pub mod pallet {
 		EraIndex,
 		Twox64Concat,
 		T::AccountId,
-		ValidatorPrefs,
+		ValidatorPrefs<BalanceOf<T>>,
 		ValueQuery,
 	>;
 
@@ -668,6 +989,20 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ErasValidatorReward<T: Config> = StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>>;
 
+	/// Where the rewards of each of the last [`Config::HistoryDepth`] eras went.
+	///
+	/// Set when the era ends, and updated as its rewards are paid out.
+	#[pallet::storage]
+	pub type ErasRewardBreakdown<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, RewardBreakdown<BalanceOf<T>>>;
+
+	/// The duration, in milliseconds, that the payout of each of the last
+	/// [`Config::HistoryDepth`] eras was computed with.
+	///
+	/// Set when the era ends.
+	#[pallet::storage]
+	pub type ErasDuration<T: Config> = StorageMap<_, Twox64Concat, EraIndex, u64>;
+
 	/// Rewards for the last [`Config::HistoryDepth`] eras.
 	/// If reward hasn't been set or has been removed then 0 reward is returned.
 	#[pallet::storage]
@@ -680,6 +1015,13 @@ pub mod pallet {
 	pub type ErasTotalStake<T: Config> =
 		StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The number of [`UnappliedSlashes`] applied in each of the last [`Config::HistoryDepth`]
+	/// eras, keyed by the era they were scheduled for.
+	///
+	/// Cancelled slashes are not counted.
+	#[pallet::storage]
+	pub type ErasSlashesApplied<T: Config> = StorageMap<_, Twox64Concat, EraIndex, u32, ValueQuery>;
+
 	/// Mode of era forcing.
 	#[pallet::storage]
 	pub type ForceEra<T> = StorageValue<_, Forcing, ValueQuery>;
@@ -718,7 +1060,7 @@ pub mod pallet {
 		EraIndex,
 		Twox64Concat,
 		T::AccountId,
-		slashing::OffenceRecord<T::AccountId>,
+		slashing::OffenceRecord<T>,
 	>;
 
 	/// Tracks the eras that contain offences in `OffenceQueue`, sorted from **earliest to latest**.
@@ -749,7 +1091,7 @@ pub mod pallet {
 	/// in a single block while maintaining correct slashing behavior.
 	#[pallet::storage]
 	pub type ProcessingOffence<T: Config> =
-		StorageValue<_, (EraIndex, T::AccountId, slashing::OffenceRecord<T::AccountId>)>;
+		StorageValue<_, (EraIndex, T::AccountId, slashing::OffenceRecord<T>)>;
 
 	/// All unapplied slashes that are queued for later.
 	#[pallet::storage]
@@ -790,6 +1132,23 @@ pub mod pallet {
 		(Perbill, BalanceOf<T>),
 	>;
 
+	/// The reward computed so far for the reporters of the slashes of an offender in an era,
+	/// summed up over all exposure pages, so that the total is clamped to
+	/// [`Config::MaxSlashReward`].
+	///
+	/// Only tracked while [`Config::MaxSlashReward`] is not zero. Slashes cancelled later still
+	/// count towards the total.
+	#[pallet::storage]
+	pub type ErasSlashReward<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
 	/// The threshold for when users can start calling `chill_other` for other validators /
 	/// nominators. The threshold is compared to the actual number of validators / nominators
 	/// (`CountFor*`) in the system compared to the configured max (`Max*Count`).
@@ -818,6 +1177,28 @@ pub mod pallet {
 	pub type ElectableStashes<T: Config> =
 		StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxValidatorSet>, ValueQuery>;
 
+	/// Progress of the automatic payouts, see [`Config::AutoPayoutPerBlock`].
+	///
+	/// Holds the era that is being paid out, and the last validator of that era whose pages have
+	/// all been paid out. If the validator is `None`, the era has been fully paid out.
+	#[pallet::storage]
+	pub type AutoPayoutCursor<T: Config> =
+		StorageValue<_, (EraIndex, Option<T::AccountId>), OptionQuery>;
+
+	/// Progress of the automatic payouts of expiring eras, see [`Config::AutoPayoutExpiring`].
+	///
+	/// Same as [`AutoPayoutCursor`].
+	#[pallet::storage]
+	pub type AutoPayoutExpiringCursor<T: Config> =
+		StorageValue<_, (EraIndex, Option<T::AccountId>), OptionQuery>;
+
+	/// The durations of the most recent eras, oldest first.
+	///
+	/// Only maintained if [`crate::SmoothedEraPayout`] is used as [`Config::EraPayout`].
+	#[pallet::storage]
+	pub type RecentEraDurations<T: Config> =
+		StorageValue<_, BoundedVec<u64, ConstU32<{ crate::MAX_SMOOTHED_ERAS }>>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound, frame_support::DebugNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -1074,10 +1455,11 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		},
 		/// An account has called `withdraw_unbonded` and removed unbonding chunks worth `Balance`
-		/// from the unlocking queue.
+		/// from the unlocking queue, leaving `remaining_chunks` chunks in it.
 		Withdrawn {
 			stash: T::AccountId,
 			amount: BalanceOf<T>,
+			remaining_chunks: u32,
 		},
 		/// A subsequent event of `Withdrawn`, indicating that `stash` was fully removed from the
 		/// system.
@@ -1103,7 +1485,7 @@ pub mod pallet {
 		/// A validator has set their preferences.
 		ValidatorPrefsSet {
 			stash: T::AccountId,
-			prefs: ValidatorPrefs,
+			prefs: ValidatorPrefs<BalanceOf<T>>,
 		},
 		/// Voters size limit reached.
 		SnapshotVotersSizeExceeded {
@@ -1180,6 +1562,65 @@ pub mod pallet {
 		EraPruned {
 			index: EraIndex,
 		},
+		/// The controller of `stash` was forcefully set to `controller` by governance.
+		ControllerForced {
+			stash: T::AccountId,
+			controller: T::AccountId,
+		},
+		/// An election yielded fewer than [`Config::MinimumValidatorCount`] validators, and was
+		/// handled as per `behavior`.
+		ElectionUnderSubscribed {
+			elected: u32,
+			minimum: u32,
+			behavior: UnderSubscribedBehavior,
+		},
+		/// `removed` targets that are no longer validators were removed from the nominations of
+		/// `stash`. If none are left, `stash` is chilled.
+		AutoRenominated {
+			stash: T::AccountId,
+			removed: u32,
+		},
+		/// `nominator` backed `validator` with `stake` in `era`, but was not exposed since the
+		/// validator already had [`Config::MaxBackersExposed`] nominators with a higher stake.
+		NominatorNotExposed {
+			era: EraIndex,
+			validator: T::AccountId,
+			nominator: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		/// The `blocked` flag of validator `stash` was forcefully set to `blocked`.
+		ValidatorBlockedForced {
+			stash: T::AccountId,
+			blocked: bool,
+		},
+		/// A subsequent event of `Withdrawn`, indicating that the withdrawal left nothing bonded
+		/// and the ledger of `stash` was closed.
+		StashFullyUnbonded {
+			stash: T::AccountId,
+		},
+		/// A subsequent event of `Withdrawn`, indicating that `amount` of the withdrawn funds of
+		/// `stash` were sent to `dest`, as requested by [`Call::unbond_to`].
+		WithdrawnTo {
+			stash: T::AccountId,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// `removed` targets were trimmed from the nominations of `stash`, which had more than
+		/// [`MaxNominationsOf`] targets.
+		NominationsTrimmed {
+			stash: T::AccountId,
+			removed: u32,
+		},
+		/// `stash` paused nominating, keeping its targets. See [`Call::chill_keep_targets`].
+		NominationsPaused {
+			stash: T::AccountId,
+		},
+		/// `stash` resumed nominating its paused targets, dropping `removed` targets that are no
+		/// longer validators.
+		NominationsResumed {
+			stash: T::AccountId,
+			removed: u32,
+		},
 	}
 
 	/// Represents unexpected or invariant-breaking conditions encountered during execution.
@@ -1272,13 +1713,69 @@ pub mod pallet {
 		/// Unapplied slashes in the recently concluded era is blocking this operation.
 		/// See `Call::apply_slash` to apply them.
 		UnappliedSlashesInPreviousEra,
+		/// Too many nominators were supplied to be kicked in a single call. See
+		/// `Config::MaxKicks`.
+		TooManyKicks,
+		/// The stash has not opted in to automatic re-nomination.
+		AutoRenominateDisabled,
+		/// The stash is not nominating, or all of its targets are still validators.
+		NothingToRenominate,
+		/// Too many validators were supplied to be updated in a single call. See
+		/// `Config::MaxBlockedUpdates`.
+		TooManyBlockedUpdates,
+		/// Automatic compounding of unlocking chunks is not allowed in this runtime. See
+		/// `Config::AllowAutoCompoundUnlocking`.
+		AutoCompoundUnlockingNotAllowed,
+		/// The era has no validator reward to pay out. See `Config::SkipZeroRewardEras`.
+		NothingToPayout,
+		/// A target already has the maximum number of nominators. See
+		/// `Config::MaxNominatorsPerValidator`.
+		ValidatorFull,
+		/// The stash is not nominating, or has no more targets than allowed.
+		NothingToTrim,
+		/// The stash changed its role less than [`Config::RoleChangeCooldown`] eras ago.
+		RoleChangeTooSoon,
+		/// The stash is not nominating.
+		NotNominator,
+		/// The stash has no paused nominations.
+		NotPaused,
+		/// The next era is already planned.
+		AlreadyPlanning,
+	}
+
+	impl<T: Config> From<NominateError> for Error<T> {
+		fn from(e: NominateError) -> Self {
+			match e {
+				NominateError::NotStash => Error::<T>::NotStash,
+				NominateError::InsufficientBond => Error::<T>::InsufficientBond,
+				NominateError::TooManyNominators => Error::<T>::TooManyNominators,
+				NominateError::EmptyTargets => Error::<T>::EmptyTargets,
+				NominateError::TooManyTargets => Error::<T>::TooManyTargets,
+				NominateError::BadTarget => Error::<T>::BadTarget,
+				NominateError::ValidatorFull => Error::<T>::ValidatorFull,
+				NominateError::RoleChangeTooSoon => Error::<T>::RoleChangeTooSoon,
+			}
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Apply previously-unapplied slashes on the beginning of a new era, after a delay.
+		///
+		/// At most [`Config::MaxSlashesPerBlock`] slashes are applied per call. Applied slashes are
+		/// removed from storage, so the next call continues with the remaining ones.
 		pub fn apply_unapplied_slashes(active_era: EraIndex) -> Weight {
-			let mut slashes = UnappliedSlashes::<T>::iter_prefix(&active_era).take(1);
-			if let Some((key, slash)) = slashes.next() {
+			let slashes = UnappliedSlashes::<T>::iter_prefix(&active_era)
+				.take(T::MaxSlashesPerBlock::get() as usize)
+				.collect::<Vec<_>>();
+			if slashes.is_empty() {
+				// No slashes found for this era
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Check if any of these slashes have been cancelled
+			let cancelled_slashes = CancelledSlashes::<T>::get(&active_era);
+			let applied = slashes.len() as u64;
+			for (key, slash) in slashes {
 				crate::log!(
 					debug,
 					"🦹 found slash {:?} scheduled to be executed in era {:?}",
@@ -1286,8 +1783,6 @@ pub mod pallet {
 					active_era,
 				);
 
-				// Check if this slash has been cancelled
-				let cancelled_slashes = CancelledSlashes::<T>::get(&active_era);
 				let is_cancelled = cancelled_slashes.iter().any(|(validator, cancel_fraction)| {
 					*validator == key.0 && *cancel_fraction >= key.1
 				});
@@ -1302,22 +1797,20 @@ pub mod pallet {
 				} else {
 					let offence_era = active_era.saturating_sub(T::SlashDeferDuration::get());
 					slashing::apply_slash::<T>(slash, offence_era);
+					session_rotation::Eras::<T>::note_slash_applied(active_era);
 				}
 
 				// Always remove the slash from UnappliedSlashes
 				UnappliedSlashes::<T>::remove(&active_era, &key);
+			}
 
-				// Check if there are more slashes for this era
-				if UnappliedSlashes::<T>::iter_prefix(&active_era).next().is_none() {
-					// No more slashes for this era, clear CancelledSlashes
-					CancelledSlashes::<T>::remove(&active_era);
-				}
-
-				T::WeightInfo::apply_slash()
-			} else {
-				// No slashes found for this era
-				T::DbWeight::get().reads(1)
+			// Check if there are more slashes for this era
+			if UnappliedSlashes::<T>::iter_prefix(&active_era).next().is_none() {
+				// No more slashes for this era, clear CancelledSlashes
+				CancelledSlashes::<T>::remove(&active_era);
 			}
+
+			T::WeightInfo::apply_slash().saturating_mul(applied)
 		}
 	}
 
@@ -1340,6 +1833,10 @@ pub mod pallet {
 			consumed_weight
 		}
 
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_payout(remaining_weight)
+		}
+
 		fn integrity_test() {
 			// ensure that we funnel the correct value to the `DataProvider::MaxVotesPerVoter`;
 			assert_eq!(
@@ -1356,6 +1853,8 @@ pub mod pallet {
 				T::SlashDeferDuration::get(),
 				T::BondingDuration::get(),
 			);
+
+			assert!(!T::MaxSlashesPerBlock::get().is_zero(), "MaxSlashesPerBlock must be non-zero");
 		}
 
 		#[cfg(feature = "try-runtime")]
@@ -1464,86 +1963,7 @@ pub mod pallet {
 			#[pallet::compact] value: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let controller = ensure_signed(origin)?;
-			let unlocking =
-				Self::ledger(Controller(controller.clone())).map(|l| l.unlocking.len())?;
-
-			// if there are no unlocking chunks available, try to remove any chunks by withdrawing
-			// funds that have fully unbonded.
-			let maybe_withdraw_weight = {
-				if unlocking == T::MaxUnlockingChunks::get() as usize {
-					Some(Self::do_withdraw_unbonded(&controller)?)
-				} else {
-					None
-				}
-			};
-
-			// we need to fetch the ledger again because it may have been mutated in the call
-			// to `Self::do_withdraw_unbonded` above.
-			let mut ledger = Self::ledger(Controller(controller))?;
-			let mut value = value.min(ledger.active);
-			let stash = ledger.stash.clone();
-
-			ensure!(
-				ledger.unlocking.len() < T::MaxUnlockingChunks::get() as usize,
-				Error::<T>::NoMoreChunks,
-			);
-
-			if !value.is_zero() {
-				ledger.active -= value;
-
-				// Avoid there being a dust balance left in the staking system.
-				if ledger.active < asset::existential_deposit::<T>() {
-					value += ledger.active;
-					ledger.active = Zero::zero();
-				}
-
-				let min_active_bond = if Nominators::<T>::contains_key(&stash) {
-					Self::min_nominator_bond()
-				} else if Validators::<T>::contains_key(&stash) {
-					Self::min_validator_bond()
-				} else {
-					// staker is chilled, no min bond.
-					Zero::zero()
-				};
-
-				// Make sure that the user maintains enough active bond for their role.
-				// If a user runs into this error, they should chill first.
-				ensure!(ledger.active >= min_active_bond, Error::<T>::InsufficientBond);
-
-				// Note: we used current era before, but that is meant to be used for only election.
-				// The right value to use here is the active era.
-
-				let era = session_rotation::Rotator::<T>::active_era()
-					.saturating_add(T::BondingDuration::get());
-				if let Some(chunk) = ledger.unlocking.last_mut().filter(|chunk| chunk.era == era) {
-					// To keep the chunk count down, we only keep one chunk per era. Since
-					// `unlocking` is a FiFo queue, if a chunk exists for `era` we know that it will
-					// be the last one.
-					chunk.value = chunk.value.defensive_saturating_add(value)
-				} else {
-					ledger
-						.unlocking
-						.try_push(UnlockChunk { value, era })
-						.map_err(|_| Error::<T>::NoMoreChunks)?;
-				};
-				// NOTE: ledger must be updated prior to calling `Self::weight_of`.
-				ledger.update()?;
-
-				// update this staker in the sorted list, if they exist in it.
-				if T::VoterList::contains(&stash) {
-					let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash));
-				}
-
-				Self::deposit_event(Event::<T>::Unbonded { stash, amount: value });
-			}
-
-			let actual_weight = if let Some(withdraw_weight) = maybe_withdraw_weight {
-				Some(T::WeightInfo::unbond().saturating_add(withdraw_weight))
-			} else {
-				Some(T::WeightInfo::unbond())
-			};
-
-			Ok(actual_weight.into())
+			Self::do_unbond(controller, value, None)
 		}
 
 		/// Remove any stake that has been fully unbonded and is ready for withdrawal.
@@ -1557,7 +1977,7 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_ by the controller.
 		///
-		/// Emits `Withdrawn`.
+		/// Emits `Withdrawn`, followed by `StashFullyUnbonded` if the ledger is closed.
 		///
 		/// See also [`Call::unbond`].
 		///
@@ -1584,7 +2004,10 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::validate())]
-		pub fn validate(origin: OriginFor<T>, prefs: ValidatorPrefs) -> DispatchResult {
+		pub fn validate(
+			origin: OriginFor<T>,
+			prefs: ValidatorPrefs<BalanceOf<T>>,
+		) -> DispatchResult {
 			let controller = ensure_signed(origin)?;
 
 			let ledger = Self::ledger(Controller(controller))?;
@@ -1597,6 +2020,7 @@ pub mod pallet {
 
 			// Only check limits if they are not already a validator.
 			if !Validators::<T>::contains_key(stash) {
+				ensure!(Self::role_change_allowed(stash), Error::<T>::RoleChangeTooSoon);
 				// If this error is reached, we need to adjust the `MinValidatorBond` and start
 				// calling `chill_other`. Until then, we explicitly block new validators to protect
 				// the runtime.
@@ -1609,6 +2033,7 @@ pub mod pallet {
 			}
 
 			Self::do_remove_nominator(stash);
+			PausedNominations::<T>::remove(stash);
 			Self::do_add_validator(stash, prefs.clone());
 			Self::deposit_event(Event::<T>::ValidatorPrefsSet { stash: ledger.stash, prefs });
 
@@ -1629,53 +2054,13 @@ pub mod pallet {
 			let controller = ensure_signed(origin)?;
 
 			let ledger = Self::ledger(StakingAccount::Controller(controller.clone()))?;
-
-			ensure!(ledger.active >= Self::min_nominator_bond(), Error::<T>::InsufficientBond);
 			let stash = &ledger.stash;
 
-			// Only check limits if they are not already a nominator.
-			if !Nominators::<T>::contains_key(stash) {
-				// If this error is reached, we need to adjust the `MinNominatorBond` and start
-				// calling `chill_other`. Until then, we explicitly block new nominators to protect
-				// the runtime.
-				if let Some(max_nominators) = MaxNominatorsCount::<T>::get() {
-					ensure!(
-						Nominators::<T>::count() < max_nominators,
-						Error::<T>::TooManyNominators
-					);
-				}
-			}
-
-			// dedup targets
-			let mut targets = targets
+			let targets = targets
 				.into_iter()
 				.map(|t| T::Lookup::lookup(t).map_err(DispatchError::from))
 				.collect::<Result<Vec<_>, _>>()?;
-			targets.sort();
-			targets.dedup();
-
-			ensure!(!targets.is_empty(), Error::<T>::EmptyTargets);
-			ensure!(
-				targets.len() <= T::NominationsQuota::get_quota(ledger.active) as usize,
-				Error::<T>::TooManyTargets
-			);
-
-			let old = Nominators::<T>::get(stash).map_or_else(Vec::new, |x| x.targets.into_inner());
-
-			let targets: BoundedVec<_, _> = targets
-				.into_iter()
-				.map(|n| {
-					if old.contains(&n) ||
-						(Validators::<T>::contains_key(&n) && !Validators::<T>::get(&n).blocked)
-					{
-						Ok(n)
-					} else {
-						Err(Error::<T>::BadTarget.into())
-					}
-				})
-				.collect::<Result<Vec<_>, DispatchError>>()?
-				.try_into()
-				.map_err(|_| Error::<T>::TooManyNominators)?;
+			let targets = Self::check_nominate(&ledger, targets).map_err(Error::<T>::from)?;
 
 			let nominations = Nominations {
 				targets,
@@ -1685,6 +2070,7 @@ pub mod pallet {
 			};
 
 			Self::do_remove_validator(stash);
+			PausedNominations::<T>::remove(stash);
 			Self::do_add_nominator(stash, nominations);
 			Ok(())
 		}
@@ -2078,12 +2464,15 @@ pub mod pallet {
 		/// - `who`: A list of nominator stash accounts who are nominating this validator which
 		///   should no longer be nominating this validator.
 		///
+		/// At most `T::MaxKicks` nominators can be kicked in a single call.
+		///
 		/// Note: Making this call only makes sense if you first set the validator preferences to
 		/// block any further nominations.
 		#[pallet::call_index(21)]
 		#[pallet::weight(T::WeightInfo::kick(who.len() as u32))]
 		pub fn kick(origin: OriginFor<T>, who: Vec<AccountIdLookupOf<T>>) -> DispatchResult {
 			let controller = ensure_signed(origin)?;
+			ensure!(who.len() <= T::MaxKicks::get() as usize, Error::<T>::TooManyKicks);
 			let ledger = Self::ledger(Controller(controller))?;
 			let stash = &ledger.stash;
 
@@ -2097,6 +2486,7 @@ pub mod pallet {
 					if let Some(ref mut nom) = maybe_nom {
 						if let Some(pos) = nom.targets.iter().position(|v| v == stash) {
 							nom.targets.swap_remove(pos);
+							Self::remove_backer(stash, &nom_stash);
 							Self::deposit_event(Event::<T>::Kicked {
 								nominator: nom_stash.clone(),
 								stash: stash.clone(),
@@ -2176,20 +2566,23 @@ pub mod pallet {
 		/// If the caller is different than the controller being targeted, the following conditions
 		/// must be met:
 		///
-		/// * `controller` must belong to a nominator who has become non-decodable,
+		/// * `controller` must belong to a nominator who has become non-decodable.
 		///
 		/// Or:
 		///
-		/// * A `ChillThreshold` must be set and checked which defines how close to the max
+		/// * A [`ChillThreshold`] must be set and checked which defines how close to the max
 		///   nominators or validators we must reach before users can start chilling one-another.
-		/// * A `MaxNominatorCount` and `MaxValidatorCount` must be set which is used to determine
-		///   how close we are to the threshold.
-		/// * A `MinNominatorBond` and `MinValidatorBond` must be set and checked, which determines
-		///   if this is a person that should be chilled because they have not met the threshold
-		///   bond required.
+		/// * A [`MaxNominatorsCount`] and [`MaxValidatorsCount`] must be set which is used to
+		///   determine how close we are to the threshold.
+		/// * A [`MinNominatorBond`] and [`MinValidatorBond`] must be set and checked, which
+		///   determines if this is a person that should be chilled because they have not met the
+		///   threshold bond required.
 		///
 		/// This can be helpful if bond requirements are updated, and we need to remove old users
-		/// who do not satisfy these requirements.
+		/// who do not satisfy these requirements. All three values are set via
+		/// [`Call::set_staking_configs`].
+		///
+		/// Emits `Chilled`.
 		#[pallet::call_index(23)]
 		#[pallet::weight(T::WeightInfo::chill_other())]
 		pub fn chill_other(origin: OriginFor<T>, stash: T::AccountId) -> DispatchResult {
@@ -2211,8 +2604,8 @@ pub mod pallet {
 			//
 			// * A `ChillThreshold` is set which defines how close to the max nominators or
 			//   validators we must reach before users can start chilling one-another.
-			// * A `MaxNominatorCount` and `MaxValidatorCount` which is used to determine how close
-			//   we are to the threshold.
+			// * A `MaxNominatorsCount` and `MaxValidatorsCount` which is used to determine how
+			//   close we are to the threshold.
 			// * A `MinNominatorBond` and `MinValidatorBond` which is the final condition checked to
 			//   determine this is a person that should be chilled because they have not met the
 			//   threshold bond required.
@@ -2414,7 +2807,9 @@ pub mod pallet {
 			stash: T::AccountId,
 			maybe_controller: Option<T::AccountId>,
 			maybe_total: Option<BalanceOf<T>>,
-			maybe_unlocking: Option<BoundedVec<UnlockChunk<BalanceOf<T>>, T::MaxUnlockingChunks>>,
+			maybe_unlocking: Option<
+				BoundedVec<UnlockChunk<BalanceOf<T>, T::AccountId>, T::MaxUnlockingChunks>,
+			>,
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
@@ -2557,8 +2952,337 @@ pub mod pallet {
 			let unapplied_slash = UnappliedSlashes::<T>::take(&slash_era, &slash_key)
 				.ok_or(Error::<T>::InvalidSlashRecord)?;
 			slashing::apply_slash::<T>(unapplied_slash, slash_era);
+			session_rotation::Eras::<T>::note_slash_applied(slash_era);
 
 			Ok(Pays::No.into())
 		}
+
+		/// Forcefully sets the controller of a `stash` to `new_controller`.
+		///
+		/// This is a recovery tool for stashes that lost access to their controller key, and thus
+		/// cannot call [`Call::set_controller`] themselves. The ledger of `stash` must be in a
+		/// consistent state, see [`Call::restore_ledger`] otherwise.
+		///
+		/// Fails with `AlreadyPaired` if `new_controller` is already the stash or controller of
+		/// another ledger.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::force_set_controller())]
+		pub fn force_set_controller(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			new_controller: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let ledger = Self::ledger(StakingAccount::Stash(stash.clone()))?;
+			ledger.force_set_controller(new_controller.clone())?;
+
+			Self::deposit_event(Event::<T>::ControllerForced { stash, controller: new_controller });
+			Ok(())
+		}
+
+		/// Sets or clears the [`EmergencyBondingDuration`], the bonding duration of new unbonding
+		/// requests.
+		///
+		/// Values above [`Config::BondingDuration`] have no effect. Note that this shortens the
+		/// time in which unbonding funds remain slashable, see [`EmergencyBondingDuration`].
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_emergency_bonding_duration())]
+		pub fn set_emergency_bonding_duration(
+			origin: OriginFor<T>,
+			maybe_duration: Option<EraIndex>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			EmergencyBondingDuration::<T>::set(maybe_duration);
+			Ok(())
+		}
+
+		/// Opt in or out of automatic re-nomination, see [`Call::process_auto_renominate`].
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_auto_renominate())]
+		pub fn set_auto_renominate(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+
+			if enabled {
+				AutoRenominate::<T>::insert(&ledger.stash, ());
+			} else {
+				AutoRenominate::<T>::remove(&ledger.stash);
+			}
+			Ok(())
+		}
+
+		/// Remove the targets of `stash` that are no longer validators from its nominations, so
+		/// that its stake is not split over targets that cannot be elected.
+		///
+		/// `stash` must have opted in via [`Call::set_auto_renominate`]. If none of its targets
+		/// are validators anymore, it is chilled. Fails if all of its targets are still
+		/// validators.
+		///
+		/// The dispatch origin for this call must be _Signed_, by any account.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::process_auto_renominate(MaxNominationsOf::<T>::get()))]
+		pub fn process_auto_renominate(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			ensure!(AutoRenominate::<T>::contains_key(&stash), Error::<T>::AutoRenominateDisabled);
+			let mut nominations =
+				Nominators::<T>::get(&stash).ok_or(Error::<T>::NothingToRenominate)?;
+			let initial = nominations.targets.len();
+
+			nominations.targets.retain(|target| Validators::<T>::contains_key(target));
+			let removed = initial.saturating_sub(nominations.targets.len()) as u32;
+			ensure!(removed > 0, Error::<T>::NothingToRenominate);
+
+			if nominations.targets.is_empty() {
+				Self::chill_stash(&stash);
+			} else {
+				Self::do_add_nominator(&stash, nominations);
+			}
+
+			Self::deposit_event(Event::<T>::AutoRenominated { stash, removed });
+			Ok(Some(T::WeightInfo::process_auto_renominate(initial as u32)).into())
+		}
+
+		/// Set the `blocked` flag of many validators at once, e.g. during an incident.
+		///
+		/// Accounts that are not validators, or whose flag is already as requested, are skipped.
+		/// At most `T::MaxBlockedUpdates` validators can be updated in a single call.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::force_set_blocked(updates.len() as u32))]
+		pub fn force_set_blocked(
+			origin: OriginFor<T>,
+			updates: Vec<(T::AccountId, bool)>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				updates.len() <= T::MaxBlockedUpdates::get() as usize,
+				Error::<T>::TooManyBlockedUpdates
+			);
+
+			for (stash, blocked) in updates {
+				let Ok(mut prefs) = Validators::<T>::try_get(&stash) else { continue };
+				if prefs.blocked == blocked {
+					continue
+				}
+
+				prefs.blocked = blocked;
+				Validators::<T>::insert(&stash, prefs);
+				Self::deposit_event(Event::<T>::ValidatorBlockedForced { stash, blocked });
+			}
+			Ok(())
+		}
+
+		/// Opt in to having `maybe_fraction` of the unlocking chunks rebonded whenever the stash
+		/// is paid out, or opt out if `None`.
+		///
+		/// Chunks are rebonded newest first, as in [`Call::rebond`], and only while the reward
+		/// destination is [`RewardDestination::Staked`]. This happens at most once per era, on the
+		/// first payout of the stash in that era. Chunks unbonded with [`Call::unbond_to`] or while
+		/// [`EmergencyBondingDuration`] was set are never rebonded. Opting in requires
+		/// `T::AllowAutoCompoundUnlocking`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound_unlocking())]
+		pub fn set_auto_compound_unlocking(
+			origin: OriginFor<T>,
+			maybe_fraction: Option<Perbill>,
+		) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+
+			match maybe_fraction {
+				Some(fraction) => {
+					ensure!(
+						T::AllowAutoCompoundUnlocking::get(),
+						Error::<T>::AutoCompoundUnlockingNotAllowed
+					);
+					AutoCompoundUnlocking::<T>::insert(&ledger.stash, fraction);
+				},
+				None => {
+					AutoCompoundUnlocking::<T>::remove(&ledger.stash);
+					AutoCompoundedEra::<T>::remove(&ledger.stash);
+				},
+			}
+			Ok(())
+		}
+
+		/// Force a new era to be planned once session `at_session` starts. After this, it will be
+		/// reset to normal (non-forced) behaviour.
+		///
+		/// If `at_session` has already started, this is the same as [`Call::force_new_era`].
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		///
+		/// # Warning
+		///
+		/// Planning an era only starts its election. The era itself starts once the relay chain
+		/// has activated the elected validators, a few sessions later.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::schedule_forced_era())]
+		pub fn schedule_forced_era(
+			origin: OriginFor<T>,
+			at_session: SessionIndex,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::set_force_era(Forcing::ForceScheduled(at_session));
+			Ok(())
+		}
+
+		/// Same as [`Call::unbond`], but once withdrawn through [`Call::withdraw_unbonded`], the
+		/// unlocked funds are sent to `dest` instead of staying in the stash.
+		///
+		/// The transfer is best effort: if it fails, the funds stay in the stash.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller. Not allowed for
+		/// virtual stakers.
+		///
+		/// Emits `Unbonded`, and `WithdrawnTo` once withdrawn.
+		#[pallet::call_index(39)]
+		#[pallet::weight(
+			T::WeightInfo::withdraw_unbonded_kill().saturating_add(T::WeightInfo::unbond_to())
+		)]
+		pub fn unbond_to(
+			origin: OriginFor<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let stash = Self::ledger(Controller(controller.clone()))?.stash;
+			ensure!(!Self::is_virtual_staker(&stash), Error::<T>::VirtualStakerNotAllowed);
+
+			Self::do_unbond(controller, value, Some(dest))
+		}
+
+		/// Trim the targets of the nominator `stash` to at most [`MaxNominationsOf`], keeping the
+		/// targets with the highest stake.
+		///
+		/// A nominator can only have more targets than allowed if `MaxNominations` was lowered,
+		/// making its nominations non-decodable. `num_targets` must be at least the number of
+		/// targets `stash` currently has.
+		///
+		/// The dispatch origin for this call must be _Signed_, by any account.
+		///
+		/// Emits `NominationsTrimmed`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::trim_nominations(*num_targets))]
+		pub fn trim_nominations(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			num_targets: u32,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let (targets, nominations) =
+				Self::unbounded_nominations(&stash).ok_or(Error::<T>::NothingToTrim)?;
+			let initial = targets.len() as u32;
+			ensure!(initial <= num_targets, Error::<T>::InvalidNumberOfNominations);
+			ensure!(initial > MaxNominationsOf::<T>::get(), Error::<T>::NothingToTrim);
+
+			let targets = Self::cap_targets(targets);
+			let removed = initial.saturating_sub(targets.len() as u32);
+			Self::do_add_nominator(&stash, Nominations { targets, ..nominations });
+
+			Self::deposit_event(Event::<T>::NominationsTrimmed { stash, removed });
+			Ok(Some(T::WeightInfo::trim_nominations(initial)).into())
+		}
+
+		/// Like [`Call::chill`], but keeps the targets of the nominator so that it can later
+		/// [`Call::resume`] nominating them.
+		///
+		/// The stash is removed from the nominators right away, and hence ignored by elections
+		/// while paused.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		///
+		/// Emits `Chilled` and `NominationsPaused`.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::chill_keep_targets())]
+		pub fn chill_keep_targets(origin: OriginFor<T>) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let stash = Self::ledger(Controller(controller))?.stash;
+			let nominations = Nominators::<T>::get(&stash).ok_or(Error::<T>::NotNominator)?;
+
+			Self::chill_stash(&stash);
+			PausedNominations::<T>::insert(&stash, nominations.targets);
+
+			Self::deposit_event(Event::<T>::NominationsPaused { stash });
+			Ok(())
+		}
+
+		/// Resume nominating the targets paused via [`Call::chill_keep_targets`].
+		///
+		/// Targets that are no longer validators, or are blocked, are dropped. The remaining ones
+		/// are checked as in [`Call::nominate`], e.g. against the nominations quota of the
+		/// current bond.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
+		///
+		/// Emits `NominationsResumed`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::resume(MaxNominationsOf::<T>::get()))]
+		pub fn resume(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller))?;
+			let stash = ledger.stash.clone();
+			let mut targets = PausedNominations::<T>::take(&stash).ok_or(Error::<T>::NotPaused)?;
+			let initial = targets.len() as u32;
+
+			targets.retain(|target| {
+				Validators::<T>::contains_key(target) && !Validators::<T>::get(target).blocked
+			});
+			let removed = initial.saturating_sub(targets.len() as u32);
+			let targets =
+				Self::check_nominate(&ledger, targets.into_inner()).map_err(Error::<T>::from)?;
+
+			let nominations = Nominations {
+				targets,
+				submitted_in: CurrentEra::<T>::get().unwrap_or(0),
+				suppressed: false,
+			};
+			Self::do_add_nominator(&stash, nominations);
+
+			Self::deposit_event(Event::<T>::NominationsResumed { stash, removed });
+			Ok(Some(T::WeightInfo::resume(initial)).into())
+		}
+
+		/// Plan the next era with the validators of the active era, along with their exposures,
+		/// and send them to the relay chain right away, as in
+		/// [`UnderSubscribedBehavior::KeepPreviousSet`].
+		///
+		/// This is the way out of an election halted as per [`UnderSubscribedBehavior::Halt`].
+		/// Alternatively, [`Call::force_new_era`] retries the election. Resets [`ForceEra`] to
+		/// [`Forcing::NotForcing`].
+		///
+		/// The dispatch origin must be Root.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::force_previous_validator_set(T::MaxValidatorSet::get()))]
+		pub fn force_previous_validator_set(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				session_rotation::Rotator::<T>::is_planning().is_none(),
+				Error::<T>::AlreadyPlanning
+			);
+
+			let id = session_rotation::Rotator::<T>::active_era().saturating_add(1);
+			CurrentEra::<T>::put(id);
+			let (validators, _) = session_rotation::EraElectionPlanner::<T>::keep_previous_set(id);
+			let count = validators.len() as u32;
+			session_rotation::EraElectionPlanner::<T>::send_validator_set(validators, id);
+			Self::set_force_era(Forcing::NotForcing);
+
+			Ok(Some(T::WeightInfo::force_previous_validator_set(count)).into())
+		}
 	}
 }
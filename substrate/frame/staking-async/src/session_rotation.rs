@@ -83,9 +83,10 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{Defensive, DefensiveMax, DefensiveSaturating, OnUnbalanced, TryCollect},
 };
-use sp_runtime::{Perbill, Percent, Saturating};
+use sp_runtime::{Percent, Saturating};
 use sp_staking::{
-	currency_to_vote::CurrencyToVote, Exposure, Page, PagedExposureMetadata, SessionIndex,
+	currency_to_vote::CurrencyToVote, Exposure, Page, PagedExposureMetadata, RewardBreakdown,
+	SessionIndex,
 };
 
 /// A handler for all era-based storage items.
@@ -97,8 +98,11 @@ use sp_staking::{
 /// [`ErasStakersPaged`]
 /// [`ErasStakersOverview`]
 /// [`ErasValidatorReward`]
+/// [`ErasRewardBreakdown`]
+/// [`ErasDuration`]
 /// [`ErasRewardPoints`]
 /// [`ErasTotalStake`]
+/// [`ErasSlashesApplied`]
 pub struct Eras<T: Config>(core::marker::PhantomData<T>);
 
 impl<T: Config> Eras<T> {
@@ -118,27 +122,77 @@ impl<T: Config> Eras<T> {
 		debug_assert!(cursor.maybe_cursor.is_none());
 
 		<ErasValidatorReward<T>>::remove(era);
+		<ErasRewardBreakdown<T>>::remove(era);
+		<ErasDuration<T>>::remove(era);
 		<ErasRewardPoints<T>>::remove(era);
 		<ErasTotalStake<T>>::remove(era);
+		<ErasSlashesApplied<T>>::remove(era);
 
 		// weight is registered in the main `relay_session_report` code path.
 		Pallet::<T>::deposit_event(Event::<T>::EraPruned { index: era });
 	}
 
-	pub(crate) fn set_validator_prefs(era: EraIndex, stash: &T::AccountId, prefs: ValidatorPrefs) {
+	/// Remove the exposures, validator preferences and total stake of `era`.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn clear_exposures(era: EraIndex) -> Weight {
+		let mut cursor = <ErasValidatorPrefs<T>>::clear_prefix(era, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
+		let mut removed = cursor.backend as u64;
+		cursor = <ErasStakersPaged<T>>::clear_prefix((era,), u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
+		removed.saturating_accrue(cursor.backend as u64);
+		cursor = <ErasStakersOverview<T>>::clear_prefix(era, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
+		removed.saturating_accrue(cursor.backend as u64);
+		<ErasTotalStake<T>>::remove(era);
+
+		T::DbWeight::get().writes(removed.saturating_add(1))
+	}
+
+	/// Replace the exposures, validator preferences and total stake of era `to` with those of era
+	/// `from`.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn copy_exposures(from: EraIndex, to: EraIndex) -> Weight {
+		let cleared = Self::clear_exposures(to);
+
+		let mut copied = 0u64;
+		for (validator, prefs) in <ErasValidatorPrefs<T>>::iter_prefix(from) {
+			<ErasValidatorPrefs<T>>::insert(to, validator, prefs);
+			copied += 1;
+		}
+		for (validator, overview) in <ErasStakersOverview<T>>::iter_prefix(from) {
+			<ErasStakersOverview<T>>::insert(to, validator, overview);
+			copied += 1;
+		}
+		for ((validator, page), exposure_page) in <ErasStakersPaged<T>>::iter_prefix((from,)) {
+			<ErasStakersPaged<T>>::insert((to, validator, page), exposure_page);
+			copied += 1;
+		}
+		<ErasTotalStake<T>>::insert(to, <ErasTotalStake<T>>::get(from));
+
+		T::DbWeight::get()
+			.reads_writes(copied.saturating_add(1), copied.saturating_add(1))
+			.saturating_add(cleared)
+	}
+
+	pub(crate) fn set_validator_prefs(
+		era: EraIndex,
+		stash: &T::AccountId,
+		prefs: ValidatorPrefs<BalanceOf<T>>,
+	) {
 		debug_assert_eq!(era, Rotator::<T>::planned_era(), "we only set prefs for planning era");
 		<ErasValidatorPrefs<T>>::insert(era, stash, prefs);
 	}
 
-	pub(crate) fn get_validator_prefs(era: EraIndex, stash: &T::AccountId) -> ValidatorPrefs {
+	pub(crate) fn get_validator_prefs(
+		era: EraIndex,
+		stash: &T::AccountId,
+	) -> ValidatorPrefs<BalanceOf<T>> {
 		<ErasValidatorPrefs<T>>::get(era, stash)
 	}
 
-	/// Returns validator commission for this era and page.
-	pub(crate) fn get_validator_commission(era: EraIndex, stash: &T::AccountId) -> Perbill {
-		Self::get_validator_prefs(era, stash).commission
-	}
-
 	/// Returns true if validator has one or more page of era rewards not claimed yet.
 	pub(crate) fn pending_rewards(era: EraIndex, validator: &T::AccountId) -> bool {
 		<ErasStakersOverview<T>>::get(&era, validator)
@@ -247,11 +301,17 @@ impl<T: Config> Eras<T> {
 	/// it updates the existing record by ensuring *intermediate* exposure pages are filled up with
 	/// `T::MaxExposurePageSize` number of backers per page and the remaining exposures are added
 	/// to new exposure pages.
+	///
+	/// Backers beyond [`Config::MaxBackersExposed`] are dropped, see [`Self::drop_excess_backers`].
+	/// Returns the total stake that ended up exposed.
 	pub fn upsert_exposure(
 		era: EraIndex,
 		validator: &T::AccountId,
 		mut exposure: Exposure<T::AccountId, BalanceOf<T>>,
-	) {
+	) -> BalanceOf<T> {
+		Self::drop_excess_backers(era, validator, &mut exposure);
+		let exposed = exposure.total;
+
 		let page_size = T::MaxExposurePageSize::get().defensive_max(1);
 
 		if let Some(stored_overview) = ErasStakersOverview::<T>::get(era, &validator) {
@@ -326,6 +386,41 @@ impl<T: Config> Eras<T> {
 				<ErasStakersPaged<T>>::insert((era, &validator, append_at), paged_exposure);
 			});
 		};
+
+		exposed
+	}
+
+	/// Drop the lowest staked backers of `exposure` that do not fit within
+	/// [`Config::MaxBackersExposed`], counting the ones already exposed for `validator` in `era`.
+	///
+	/// Election pages are processed starting from the most significant one, which holds the
+	/// highest staked voters, hence backers exposed in an earlier page take precedence.
+	///
+	/// An event is emitted for each dropped backer.
+	fn drop_excess_backers(
+		era: EraIndex,
+		validator: &T::AccountId,
+		exposure: &mut Exposure<T::AccountId, BalanceOf<T>>,
+	) {
+		let already_exposed =
+			ErasStakersOverview::<T>::get(era, validator).map_or(0, |o| o.nominator_count);
+		let free_slots = T::MaxBackersExposed::get().saturating_sub(already_exposed);
+		if exposure.others.len() as u32 <= free_slots {
+			return
+		}
+
+		exposure.others.sort_by_key(|e| core::cmp::Reverse(e.value));
+		let kept = exposure.split_others(free_slots);
+		let dropped = core::mem::replace(exposure, kept);
+
+		for IndividualExposure { who, value } in dropped.others {
+			Pallet::<T>::deposit_event(Event::<T>::NominatorNotExposed {
+				era,
+				validator: validator.clone(),
+				nominator: who,
+				stake: value,
+			});
+		}
 	}
 
 	pub(crate) fn set_validators_reward(era: EraIndex, amount: BalanceOf<T>) {
@@ -336,6 +431,59 @@ impl<T: Config> Eras<T> {
 		ErasValidatorReward::<T>::get(era)
 	}
 
+	/// Whether the rewards of `era` are skipped, as per [`Config::SkipZeroRewardEras`].
+	pub(crate) fn is_reward_skipped(era: EraIndex) -> bool {
+		T::SkipZeroRewardEras::get() &&
+			Self::get_validators_reward(era).is_some_and(|reward| reward.is_zero())
+	}
+
+	/// Mark all exposure pages of all validators of `era` as claimed.
+	pub(crate) fn set_era_as_claimed(era: EraIndex) {
+		let mut validators = 0u64;
+		for (validator, overview) in ErasStakersOverview::<T>::iter_prefix(era) {
+			let pages = (0..overview.page_count).collect::<Vec<_>>();
+			ClaimedRewards::<T>::insert(
+				era,
+				validator,
+				WeakBoundedVec::<_, _>::force_from(pages, Some("set_era_as_claimed")),
+			);
+			validators.saturating_inc();
+		}
+
+		Pallet::<T>::register_weight(
+			T::DbWeight::get().reads_writes(validators.saturating_add(1), validators),
+		);
+	}
+
+	pub(crate) fn set_reward_breakdown(era: EraIndex, breakdown: RewardBreakdown<BalanceOf<T>>) {
+		ErasRewardBreakdown::<T>::insert(era, breakdown);
+	}
+
+	pub(crate) fn set_era_duration(era: EraIndex, duration: u64) {
+		ErasDuration::<T>::insert(era, duration);
+	}
+
+	/// Move `to_remainder` and `burned` out of the staker rewards of `era` in its
+	/// [`ErasRewardBreakdown`].
+	pub(crate) fn reroute_staker_rewards(
+		era: EraIndex,
+		to_remainder: BalanceOf<T>,
+		burned: BalanceOf<T>,
+	) {
+		if to_remainder.is_zero() && burned.is_zero() {
+			return
+		}
+
+		ErasRewardBreakdown::<T>::mutate(era, |maybe_breakdown| {
+			if let Some(breakdown) = maybe_breakdown {
+				breakdown.stakers =
+					breakdown.stakers.saturating_sub(to_remainder.saturating_add(burned));
+				breakdown.remainder = breakdown.remainder.saturating_add(to_remainder);
+				breakdown.burned = breakdown.burned.saturating_add(burned);
+			}
+		});
+	}
+
 	/// Update the total exposure for all the elected validators in the era.
 	pub(crate) fn add_total_stake(era: EraIndex, stake: BalanceOf<T>) {
 		<ErasTotalStake<T>>::mutate(era, |total_stake| {
@@ -343,6 +491,11 @@ impl<T: Config> Eras<T> {
 		});
 	}
 
+	/// Count one more slash as applied in `era`.
+	pub(crate) fn note_slash_applied(era: EraIndex) {
+		<ErasSlashesApplied<T>>::mutate(era, |count| *count = count.saturating_add(1));
+	}
+
 	/// Check if the rewards for the given era and page index have been claimed.
 	pub(crate) fn is_rewards_claimed(era: EraIndex, validator: &T::AccountId, page: Page) -> bool {
 		ClaimedRewards::<T>::get(era, validator).contains(&page)
@@ -373,6 +526,38 @@ impl<T: Config> Eras<T> {
 	pub(crate) fn get_reward_points(era: EraIndex) -> EraRewardPoints<T> {
 		ErasRewardPoints::<T>::get(era)
 	}
+
+	/// Seed the reward points of the validators exposed in era `to` with
+	/// [`Config::RewardPointsCarryOver`] of the points they earned in era `from`.
+	pub(crate) fn carry_over_reward_points(from: EraIndex, to: EraIndex) {
+		let carry_over = T::RewardPointsCarryOver::get();
+		if carry_over.is_zero() {
+			return
+		}
+
+		let carried = ErasRewardPoints::<T>::get(from)
+			.individual
+			.into_iter()
+			.filter(|(validator, _)| ErasStakersOverview::<T>::contains_key(to, validator))
+			.map(|(validator, points)| (validator, carry_over * points))
+			.filter(|(_, points)| !points.is_zero())
+			.collect::<Vec<_>>();
+		if carried.is_empty() {
+			return
+		}
+
+		ErasRewardPoints::<T>::mutate(to, |era_rewards| {
+			for (validator, points) in carried {
+				match era_rewards.individual.get_mut(&validator) {
+					Some(individual) => individual.saturating_accrue(points),
+					None => {
+						let _ = era_rewards.individual.try_insert(validator, points).defensive();
+					},
+				}
+				era_rewards.total.saturating_accrue(points);
+			}
+		});
+	}
 }
 
 #[cfg(any(feature = "try-runtime", test, feature = "runtime-benchmarks"))]
@@ -473,6 +658,7 @@ impl<T: Config> Eras<T> {
 ///
 /// * `CurrentEra`: The current planning era
 /// * `ActiveEra`: The current active era
+/// * `ActiveEraSessions`: The number of sessions of the active era
 /// * `BondedEras`: the list of ACTIVE eras and their session index
 pub struct Rotator<T: Config>(core::marker::PhantomData<T>);
 
@@ -539,6 +725,11 @@ impl<T: Config> Rotator<T> {
 		ActiveEra::<T>::get().map(|a| a.index).defensive_unwrap_or(0)
 	}
 
+	/// The number of sessions of the active era.
+	pub fn active_era_sessions() -> SessionIndex {
+		ActiveEraSessions::<T>::get().unwrap_or_else(T::SessionsPerEra::get)
+	}
+
 	/// Next era that is planned to be started.
 	///
 	/// Returns None if no era is planned.
@@ -611,6 +802,12 @@ impl<T: Config> Rotator<T> {
 			},
 			// always plan the new era.
 			Forcing::ForceAlways => true,
+			// force plan new era only once, when the scheduled session has come.
+			Forcing::ForceScheduled(at) if starting >= at => {
+				ForceEra::<T>::put(Forcing::NotForcing);
+				true
+			},
+			Forcing::ForceScheduled(_) => Self::is_plan_era_deadline(starting),
 			// never force.
 			Forcing::ForceNone => false,
 		};
@@ -659,9 +856,11 @@ impl<T: Config> Rotator<T> {
 		// finalize the ending era.
 		Self::end_era(&ending_era, new_era_start_timestamp);
 
-		// start the next era.
+		// start the next era, with the latest number of sessions per era.
 		Self::start_era_inc_active_era(new_era_start_timestamp);
+		ActiveEraSessions::<T>::put(T::SessionsPerEra::get());
 		Self::start_era_update_bonded_eras(starting_era, starting_session);
+		Eras::<T>::carry_over_reward_points(ending_era.index, starting_era);
 
 		// discard old era information that is no longer needed.
 		Self::cleanup_old_era(starting_era);
@@ -695,6 +894,33 @@ impl<T: Config> Rotator<T> {
 			.find_map(|(e, s)| if e == era { Some(s) } else { None })
 	}
 
+	/// The sessions of a given era, if it is still bonded.
+	///
+	/// For the active era, these are the sessions it is planned to last, see
+	/// [`Self::active_era_sessions`].
+	pub fn era_sessions(era: EraIndex) -> Option<core::ops::Range<SessionIndex>> {
+		let bonded = BondedEras::<T>::get();
+		let position = bonded.iter().position(|(e, _)| *e == era)?;
+		let start = bonded[position].1;
+		let end = match bonded.get(position + 1) {
+			Some((_, next_start)) => *next_start,
+			None => start.saturating_add(Self::active_era_sessions()),
+		};
+		Some(start..end)
+	}
+
+	/// The era a given session belongs to, if that era is still bonded.
+	pub fn session_era(session: SessionIndex) -> Option<EraIndex> {
+		BondedEras::<T>::get()
+			.into_iter()
+			.rev()
+			.find(|(_, start)| *start <= session)
+			.map(|(era, _)| era)
+			.filter(|era| {
+				Self::era_sessions(*era).is_some_and(|sessions| sessions.contains(&session))
+			})
+	}
+
 	fn start_era_update_bonded_eras(starting_era: EraIndex, start_session: SessionIndex) {
 		let bonding_duration = T::BondingDuration::get();
 
@@ -771,6 +997,19 @@ impl<T: Config> Rotator<T> {
 
 		// Set ending era reward.
 		Eras::<T>::set_validators_reward(ending_era.index, validator_payout);
+		if Eras::<T>::is_reward_skipped(ending_era.index) {
+			log!(
+				debug,
+				"era {:?} has no validator reward, marking it as claimed",
+				ending_era.index
+			);
+			Eras::<T>::set_era_as_claimed(ending_era.index);
+		}
+		Eras::<T>::set_era_duration(ending_era.index, era_duration);
+		Eras::<T>::set_reward_breakdown(
+			ending_era.index,
+			RewardBreakdown { stakers: validator_payout, remainder, burned: Zero::zero() },
+		);
 		T::RewardRemainder::on_unbalanced(asset::issue::<T>(remainder));
 	}
 
@@ -788,9 +1027,10 @@ impl<T: Config> Rotator<T> {
 
 	/// Returns whether we are at the session where we should plan the new era.
 	fn is_plan_era_deadline(start_session: SessionIndex) -> bool {
-		let planning_era_offset = T::PlanningEraOffset::get().min(T::SessionsPerEra::get());
+		let sessions_per_era = Self::active_era_sessions();
+		let planning_era_offset = T::PlanningEraOffset::get().min(sessions_per_era);
 		// session at which we should plan the new era.
-		let target_plan_era_session = T::SessionsPerEra::get().saturating_sub(planning_era_offset);
+		let target_plan_era_session = sessions_per_era.saturating_sub(planning_era_offset);
 		let era_start_session = Self::active_era_start_session_index();
 
 		// progress of the active era in sessions.
@@ -888,24 +1128,95 @@ impl<T: Config> EraElectionPlanner<T> {
 			// if current page was `Some`, and next is `None`, we have finished an election and
 			// we can report it now.
 			if maybe_next_page.is_none() {
-				use pallet_staking_async_rc_client::RcClientInterface;
 				let id = CurrentEra::<T>::get().defensive_unwrap_or(0);
-				let prune_up_to = Self::get_prune_up_to();
-				let rc_validators = ElectableStashes::<T>::take().into_iter().collect::<Vec<_>>();
+				let mut rc_validators =
+					ElectableStashes::<T>::take().into_iter().collect::<Vec<_>>();
+
+				let minimum = T::MinimumValidatorCount::get();
+				if (rc_validators.len() as u32) < minimum {
+					let behavior = T::UnderSubscribedBehavior::get();
+					crate::log!(
+						warn,
+						"election yielded {:?} validators, less than the minimum of {:?}: {:?}",
+						rc_validators.len(),
+						minimum,
+						behavior
+					);
+					Pallet::<T>::deposit_event(Event::ElectionUnderSubscribed {
+						elected: rc_validators.len() as u32,
+						minimum,
+						behavior,
+					});
 
-				crate::log!(
-					info,
-					"Sending new validator set of size {:?} to RC. ID: {:?}, prune_up_to: {:?}",
-					rc_validators.len(),
-					id,
-					prune_up_to
-				);
+					match behavior {
+						UnderSubscribedBehavior::UseWhatWeGot => {},
+						UnderSubscribedBehavior::KeepPreviousSet => {
+							let (previous_set, weight) = Self::keep_previous_set(id);
+							Pallet::<T>::register_weight(weight);
+							rc_validators = previous_set;
+						},
+						UnderSubscribedBehavior::Halt => {
+							Pallet::<T>::register_weight(Self::halt_planning(id));
+							return
+						},
+					}
+				}
 
-				T::RcClientInterface::validator_set(rc_validators, id, prune_up_to);
+				Self::send_validator_set(rc_validators, id);
 			}
 		}
 	}
 
+	/// Use the validators of the active era, along with their exposures, as the validator set of
+	/// the planned era `id`.
+	///
+	/// Returns the sorted validators, and the consumed weight.
+	pub(crate) fn keep_previous_set(id: EraIndex) -> (Vec<T::AccountId>, Weight) {
+		let weight = Eras::<T>::copy_exposures(Rotator::<T>::active_era(), id);
+		let mut validators = ErasStakersOverview::<T>::iter_key_prefix(id).collect::<Vec<_>>();
+		validators.sort();
+		let weight = weight.saturating_add(T::DbWeight::get().reads(validators.len() as u64));
+		(validators, weight)
+	}
+
+	/// Undo the planning of era `id`, as per [`UnderSubscribedBehavior::Halt`].
+	///
+	/// The exposures collected for `id` are removed, [`CurrentEra`] is set back to the active era
+	/// and no further era is planned until governance intervenes, see
+	/// [`crate::Call::force_previous_validator_set`].
+	///
+	/// Returns the consumed weight.
+	fn halt_planning(id: EraIndex) -> Weight {
+		crate::log!(warn, "halting the planning of era {:?}", id);
+		let weight = Eras::<T>::clear_exposures(id);
+		CurrentEra::<T>::put(id.defensive_saturating_sub(1));
+		Pallet::<T>::set_force_era(Forcing::ForceNone);
+		Self::cleanup();
+		weight.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Send the validator set of the planned era `id` to the relay chain.
+	pub(crate) fn send_validator_set(validators: Vec<T::AccountId>, id: EraIndex) {
+		use pallet_staking_async_rc_client::RcClientInterface;
+		let prune_up_to = Self::get_prune_up_to();
+
+		let minimum_elected_stake = ErasStakersOverview::<T>::iter_prefix_values(id)
+			.map(|overview| overview.total)
+			.min()
+			.unwrap_or_default();
+		MinimumElectedStake::<T>::put(minimum_elected_stake);
+
+		crate::log!(
+			info,
+			"Sending new validator set of size {:?} to RC. ID: {:?}, prune_up_to: {:?}",
+			validators.len(),
+			id,
+			prune_up_to
+		);
+
+		T::RcClientInterface::validator_set(validators, id, prune_up_to);
+	}
+
 	/// Get the right value of the first session that needs to be pruned on the RC's historical
 	/// session pallet.
 	fn get_prune_up_to() -> Option<SessionIndex> {
@@ -1012,11 +1323,10 @@ impl<T: Config> EraElectionPlanner<T> {
 			);
 			// build elected stash.
 			elected_stashes_page.push(stash.clone());
-			// accumulate total stake.
-			total_stake_page = total_stake_page.saturating_add(exposure.total);
-			// set or update staker exposure for this era.
 			total_backers += exposure.others.len() as u32;
-			Eras::<T>::upsert_exposure(new_planned_era, &stash, exposure);
+			// set or update staker exposure for this era, and accumulate the exposed stake.
+			let exposed = Eras::<T>::upsert_exposure(new_planned_era, &stash, exposure);
+			total_stake_page = total_stake_page.saturating_add(exposed);
 		});
 
 		let elected_stashes: BoundedVec<_, MaxWinnersPerPageOf<T::ElectionProvider>> =
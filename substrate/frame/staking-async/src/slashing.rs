@@ -41,17 +41,21 @@
 //! Based on research at <https://research.web3.foundation/Polkadot/security/slashing/npos>
 
 use crate::{
-	asset, log, session_rotation::Eras, BalanceOf, Config, NegativeImbalanceOf, OffenceQueue,
-	OffenceQueueEras, PagedExposure, Pallet, Perbill, ProcessingOffence, SlashRewardFraction,
-	UnappliedSlash, UnappliedSlashes, ValidatorSlashInEra, WeightInfo,
+	asset, log, session_rotation::Eras, BalanceOf, Config, ErasSlashReward, NegativeImbalanceOf,
+	Nominators, OffenceQueue, OffenceQueueEras, PagedExposure, Pallet, Perbill,
+	PostSlashDustThreshold, ProcessingOffence, SlashRewardFraction, UnappliedSlash,
+	UnappliedSlashes, ValidatorSlashInEra, Validators, WeightInfo,
 };
 use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::traits::{Defensive, DefensiveSaturating, Get, Imbalance, OnUnbalanced};
+use frame_support::{
+	traits::{Defensive, DefensiveSaturating, Get, Imbalance, OnUnbalanced},
+	BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Saturating, Zero},
-	RuntimeDebug, WeakBoundedVec, Weight,
+	WeakBoundedVec, Weight,
 };
 use sp_staking::{EraIndex, StakingInterface};
 
@@ -77,10 +81,13 @@ pub(crate) struct SlashParams<'a, T: 'a + Config> {
 
 /// Represents an offence record within the staking system, capturing details about a slashing
 /// event.
-#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebug)]
-pub struct OffenceRecord<AccountId> {
-	/// The account ID of the entity that reported the offence.
-	pub reporter: Option<AccountId>,
+#[derive(
+	CloneNoBound, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct OffenceRecord<T: Config> {
+	/// The unique accounts that reported the offence, in the order they were reported.
+	pub reporters: BoundedVec<T::AccountId, T::MaxReporters>,
 
 	/// Era at which the offence was reported.
 	pub reported_era: EraIndex,
@@ -111,7 +118,7 @@ pub struct OffenceRecord<AccountId> {
 /// - `ProcessingOffence`
 /// - `OffenceQueue`
 /// - `OffenceQueueEras`
-fn next_offence<T: Config>() -> Option<(EraIndex, T::AccountId, OffenceRecord<T::AccountId>)> {
+fn next_offence<T: Config>() -> Option<(EraIndex, T::AccountId, OffenceRecord<T>)> {
 	let maybe_processing_offence = ProcessingOffence::<T>::get();
 
 	if let Some((offence_era, offender, offence_record)) = maybe_processing_offence {
@@ -213,7 +220,7 @@ pub(crate) fn process_offence<T: Config>() -> Weight {
 	let slash_defer_duration = T::SlashDeferDuration::get();
 	let slash_era = offence_era.saturating_add(slash_defer_duration);
 
-	add_db_reads_writes(3, 3);
+	add_db_reads_writes(4, 4);
 	let Some(mut unapplied) = compute_slash::<T>(SlashParams {
 		stash: &offender,
 		slash: offence_record.slash_fraction,
@@ -248,8 +255,8 @@ pub(crate) fn process_offence<T: Config>() -> Weight {
 		offence_record.reported_era,
 	);
 
-	// add the reporter to the unapplied slash.
-	unapplied.reporter = offence_record.reporter;
+	// add the reporters to the unapplied slash.
+	unapplied.reporters = offence_record.reporters;
 
 	if slash_defer_duration == 0 {
 		// Apply right away.
@@ -303,6 +310,15 @@ pub(crate) fn compute_slash<T: Config>(params: SlashParams<T>) -> Option<Unappli
 		slash_nominators::<T>(params.clone(), &mut nominators_slashed);
 	reward_payout += nom_reward_payout;
 
+	let max_reward = T::MaxSlashReward::get();
+	if !max_reward.is_zero() {
+		// clamp the reward of all pages of the offender together, not each page on its own.
+		ErasSlashReward::<T>::mutate(params.slash_era, params.stash, |paid| {
+			reward_payout = reward_payout.min(max_reward.saturating_sub(*paid));
+			paid.saturating_accrue(reward_payout);
+		});
+	}
+
 	(nom_slashed + val_slashed > Zero::zero()).then_some(UnappliedSlash {
 		validator: params.stash.clone(),
 		own: val_slashed,
@@ -310,7 +326,7 @@ pub(crate) fn compute_slash<T: Config>(params: SlashParams<T>) -> Option<Unappli
 			nominators_slashed,
 			Some("slashed nominators not expected to be larger than the bounds"),
 		),
-		reporter: None,
+		reporters: Default::default(),
 		payout: reward_payout,
 	})
 }
@@ -381,10 +397,40 @@ fn slash_nominators<T: Config>(
 	(total_slashed, reward_payout)
 }
 
+/// Simulates the slash of `validator` and its nominators for an offence in `slash_era` with the
+/// given `slash_fraction`, without altering any storage.
+///
+/// Since we only slash for the _maximum_ slash received in an era, any prior slash of the
+/// validator in the same era (see [`ValidatorSlashInEra`]) is accounted for, and only the marginal
+/// increase is projected. Consequently, a slash that is not greater than the prior one projects
+/// zero.
+///
+/// Returns the total amount that would be slashed, across the validator and all of its nominators.
+pub fn simulate_slash<T: Config>(
+	slash_era: EraIndex,
+	validator: &T::AccountId,
+	slash_fraction: Perbill,
+) -> BalanceOf<T> {
+	let prior_slash = ValidatorSlashInEra::<T>::get(slash_era, validator)
+		.map_or(Zero::zero(), |(fraction, _)| fraction);
+	if slash_fraction <= prior_slash {
+		return Zero::zero()
+	}
+
+	let marginal_slash =
+		|value: BalanceOf<T>| (slash_fraction * value).saturating_sub(prior_slash * value);
+	let exposure = Eras::<T>::get_full_exposure(slash_era, validator);
+	exposure.others.iter().fold(marginal_slash(exposure.own), |total, nominator| {
+		total.saturating_add(marginal_slash(nominator.value))
+	})
+}
+
 /// Clear slashing metadata for an obsolete era.
 pub(crate) fn clear_era_metadata<T: Config>(obsolete_era: EraIndex) {
 	#[allow(deprecated)]
 	ValidatorSlashInEra::<T>::remove_prefix(&obsolete_era, None);
+	#[allow(deprecated)]
+	ErasSlashReward::<T>::remove_prefix(&obsolete_era, None);
 }
 
 // apply the slash to a stash account, deducting any missing funds from the reward
@@ -420,12 +466,25 @@ pub fn do_slash<T: Config>(
 		}
 	}
 
+	let active = ledger.active;
 	let _ = ledger
 		.update()
 		.defensive_proof("ledger fetched from storage so it exists in storage; qed.");
 
 	// trigger the event
 	<Pallet<T>>::deposit_event(super::Event::<T>::Slashed { staker: stash.clone(), amount: value });
+
+	// chill the staker if what is left is dust for its role.
+	let maybe_threshold = if Validators::<T>::contains_key(stash) {
+		Some(T::PostSlashDustThreshold::validator())
+	} else if Nominators::<T>::contains_key(stash) {
+		Some(T::PostSlashDustThreshold::nominator())
+	} else {
+		None
+	};
+	if maybe_threshold.is_some_and(|threshold| active < threshold) {
+		<Pallet<T>>::chill_stash(stash);
+	}
 }
 
 /// Apply a previously-unapplied slash.
@@ -455,13 +514,34 @@ pub(crate) fn apply_slash<T: Config>(unapplied_slash: UnappliedSlash<T>, slash_e
 			&mut slashed_imbalance,
 			slash_era,
 		);
+
+		if T::SuppressSlashedNominations::get() {
+			Nominators::<T>::mutate(nominator, |maybe_nominations| {
+				if let Some(nominations) = maybe_nominations {
+					nominations.suppressed = true;
+				}
+			});
+		}
 	}
 
-	pay_reporters::<T>(
-		reward_payout,
-		slashed_imbalance,
-		&unapplied_slash.reporter.map(|v| crate::vec![v]).unwrap_or_default(),
-	);
+	pay_reporters::<T>(reward_payout, slashed_imbalance, &unapplied_slash.reporters);
+}
+
+/// Returns the unique accounts among `reporters`, keeping the first [`Config::MaxReporters`] of
+/// them in the order they were reported.
+pub(crate) fn unique_reporters<T: Config>(
+	reporters: Vec<T::AccountId>,
+) -> BoundedVec<T::AccountId, T::MaxReporters> {
+	let mut unique = BoundedVec::new();
+	for reporter in reporters {
+		if unique.contains(&reporter) {
+			continue
+		}
+		if unique.try_push(reporter).is_err() {
+			break
+		}
+	}
+	unique
 }
 
 /// Apply a reward payout to some reporters, paying the rewards out of the slashed imbalance.
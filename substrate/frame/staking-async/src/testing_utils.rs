@@ -37,10 +37,16 @@ const SEED: u32 = 0;
 pub fn clear_validators_and_nominators<T: Config>() {
 	#[allow(deprecated)]
 	Validators::<T>::remove_all();
+	#[allow(deprecated)]
+	ValidatorSince::<T>::remove_all(None);
 
 	// whenever we touch nominators counter we should update `T::VoterList` as well.
 	#[allow(deprecated)]
 	Nominators::<T>::remove_all();
+	#[allow(deprecated)]
+	Backers::<T>::remove_all(None);
+	#[allow(deprecated)]
+	BackersCount::<T>::remove_all(None);
 
 	// NOTE: safe to call outside block production
 	T::VoterList::unsafe_clear();
@@ -18,6 +18,7 @@
 //! Tests concerning bond, bond_extra, unbond, rebond, withdraw and chill for stakers.
 
 use super::*;
+use frame_election_provider_support::ElectionDataProvider;
 use frame_support::{hypothetically_ok, traits::Currency};
 
 #[test]
@@ -317,7 +318,9 @@ fn cannot_bond_extra_to_lower_than_ed() {
 					active: 0,
 					unlocking: bounded_vec![UnlockChunk {
 						value: 11 * 1000,
-						era: active_era() + 3
+						era: active_era() + 3,
+						dest: None,
+						withdrawable_era: None
 					}],
 				}
 			);
@@ -358,7 +361,12 @@ fn unbonding_works() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: active_era() + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: active_era() + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -373,7 +381,12 @@ fn unbonding_works() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: active_era() + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: active_era() + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -388,7 +401,12 @@ fn unbonding_works() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: 1 + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: 1 + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -405,7 +423,12 @@ fn unbonding_works() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: 1 + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: 1 + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -415,7 +438,7 @@ fn unbonding_works() {
 		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![Event::Withdrawn { stash: 11, amount: 500 }]
+			vec![Event::Withdrawn { stash: 11, amount: 500, remaining_chunks: 0 }]
 		);
 
 		// then
@@ -454,7 +477,12 @@ fn unbonding_multi_chunk() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: active_era() + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: active_era() + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -475,8 +503,8 @@ fn unbonding_multi_chunk() {
 				total: 1000,
 				active: 250,
 				unlocking: bounded_vec![
-					UnlockChunk { value: 500, era: 1 + 3 },
-					UnlockChunk { value: 250, era: 2 + 3 }
+					UnlockChunk { value: 500, era: 1 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 250, era: 2 + 3, dest: None, withdrawable_era: None }
 				],
 			},
 		);
@@ -487,7 +515,7 @@ fn unbonding_multi_chunk() {
 		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![Event::Withdrawn { stash: 11, amount: 500 }]
+			vec![Event::Withdrawn { stash: 11, amount: 500, remaining_chunks: 1 }]
 		);
 
 		// then
@@ -497,7 +525,12 @@ fn unbonding_multi_chunk() {
 				stash: 11,
 				total: 500,
 				active: 250,
-				unlocking: bounded_vec![UnlockChunk { value: 250, era: 2 + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 250,
+					era: 2 + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -507,7 +540,7 @@ fn unbonding_multi_chunk() {
 		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![Event::Withdrawn { stash: 11, amount: 250 }]
+			vec![Event::Withdrawn { stash: 11, amount: 250, remaining_chunks: 0 }]
 		);
 
 		// then
@@ -546,7 +579,11 @@ fn full_unbonding_works() {
 		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
 		assert_eq!(
 			staking_events_since_last_call(),
-			vec![Event::StakerRemoved { stash: 11 }, Event::Withdrawn { stash: 11, amount: 1000 }]
+			vec![
+				Event::StakerRemoved { stash: 11 },
+				Event::Withdrawn { stash: 11, amount: 1000, remaining_chunks: 0 },
+				Event::StashFullyUnbonded { stash: 11 }
+			]
 		);
 
 		// storage is clean, balance is unheld
@@ -579,7 +616,12 @@ fn unbonding_merges_if_era_exists() {
 				stash: 11,
 				total: 1000,
 				active: 500,
-				unlocking: bounded_vec![UnlockChunk { value: 500, era: 1 + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500,
+					era: 1 + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 
@@ -593,12 +635,86 @@ fn unbonding_merges_if_era_exists() {
 				stash: 11,
 				total: 1000,
 				active: 250,
-				unlocking: bounded_vec![UnlockChunk { value: 500 + 250, era: 1 + 3 }],
+				unlocking: bounded_vec![UnlockChunk {
+					value: 500 + 250,
+					era: 1 + 3,
+					dest: None,
+					withdrawable_era: None
+				}],
 			},
 		);
 	});
 }
 
+#[test]
+fn unbonding_merges_within_merge_window() {
+	ExtBuilder::default()
+		.max_unlock_chunks(3)
+		.bonding_duration(7)
+		.build_and_execute(|| {
+			// given
+			UnlockingChunkMergeWindow::set(1);
+
+			// when
+			Staking::unbond(RuntimeOrigin::signed(11), 100).unwrap();
+			Session::roll_until_active_era(2);
+			Staking::unbond(RuntimeOrigin::signed(11), 100).unwrap();
+
+			// then the first chunk is merged into, and postponed to, the era of the second.
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap(),
+				StakingLedgerInspect {
+					stash: 11,
+					total: 1000,
+					active: 800,
+					unlocking: bounded_vec![UnlockChunk {
+						value: 200,
+						era: 2 + 7,
+						dest: None,
+						withdrawable_era: None
+					}],
+				},
+			);
+
+			// when
+			Session::roll_until_active_era(4);
+			Staking::unbond(RuntimeOrigin::signed(11), 100).unwrap();
+
+			// then chunks outside of the window are not merged.
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap(),
+				StakingLedgerInspect {
+					stash: 11,
+					total: 1000,
+					active: 700,
+					unlocking: bounded_vec![
+						UnlockChunk { value: 200, era: 2 + 7, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 100, era: 4 + 7, dest: None, withdrawable_era: None },
+					],
+				},
+			);
+
+			// when an offence in era 2 is slashed.
+			add_slash_in_era(11, 2, Perbill::from_percent(100));
+			Session::roll_until_active_era(5);
+
+			// then the merged chunk is slashed too, including the funds unbonded in era 1 which
+			// would have otherwise unlocked in era `1 + 7` and escaped this slash.
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap(),
+				StakingLedgerInspect {
+					stash: 11,
+					total: 100,
+					active: 70,
+					unlocking: bounded_vec![
+						UnlockChunk { value: 20, era: 2 + 7, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 10, era: 4 + 7, dest: None, withdrawable_era: None },
+					],
+				},
+			);
+		});
+}
+
 #[test]
 fn unbonding_rejects_if_max_chunks() {
 	ExtBuilder::default()
@@ -631,9 +747,9 @@ fn unbonding_rejects_if_max_chunks() {
 					total: 1000,
 					active: 250,
 					unlocking: bounded_vec![
-						UnlockChunk { value: 250, era: 1 + 7 },
-						UnlockChunk { value: 250, era: 2 + 7 },
-						UnlockChunk { value: 250, era: 3 + 7 },
+						UnlockChunk { value: 250, era: 1 + 7, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 250, era: 2 + 7, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 250, era: 3 + 7, dest: None, withdrawable_era: None },
 					],
 				},
 			);
@@ -673,9 +789,9 @@ fn unbonding_auto_withdraws_if_any() {
 				total: 1000,
 				active: 250,
 				unlocking: bounded_vec![
-					UnlockChunk { value: 250, era: 1 + 3 },
-					UnlockChunk { value: 250, era: 2 + 3 },
-					UnlockChunk { value: 250, era: 3 + 3 },
+					UnlockChunk { value: 250, era: 1 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 250, era: 2 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 250, era: 3 + 3, dest: None, withdrawable_era: None },
 				],
 			},
 		);
@@ -691,9 +807,9 @@ fn unbonding_auto_withdraws_if_any() {
 				total: 750,
 				active: 150,
 				unlocking: bounded_vec![
-					UnlockChunk { value: 250, era: 2 + 3 },
-					UnlockChunk { value: 250, era: 3 + 3 },
-					UnlockChunk { value: 100, era: 4 + 3 },
+					UnlockChunk { value: 250, era: 2 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 250, era: 3 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 100, era: 4 + 3, dest: None, withdrawable_era: None },
 				],
 			},
 		);
@@ -727,7 +843,12 @@ fn unbonding_caps_to_ledger_active() {
 					stash: 11,
 					total: 1000,
 					active: 0,
-					unlocking: bounded_vec![UnlockChunk { value: 1000, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 1000,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 		});
@@ -761,7 +882,12 @@ fn unbond_avoids_dust() {
 					stash: 11,
 					total: 1000,
 					active: 0,
-					unlocking: bounded_vec![UnlockChunk { value: 1000, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 1000,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 		});
@@ -811,8 +937,8 @@ fn reducing_max_unlocking_chunks_abrupt() {
 
 		// then an unlocking chunk is added at `current_era + bonding_duration`
 		// => 10 + 3 = 13
-		let expected_unlocking: BoundedVec<UnlockChunk<Balance>, MaxUnlockingChunks> =
-			bounded_vec![UnlockChunk { value: 20 as Balance, era: 13 as EraIndex }];
+		let expected_unlocking: BoundedVec<UnlockChunk<Balance, AccountId>, MaxUnlockingChunks> =
+			bounded_vec![UnlockChunk { value: 20 as Balance, era: 13 as EraIndex, dest: None, withdrawable_era: None }];
 		assert!(matches!(Staking::ledger(3.into()),
 			Ok(StakingLedger {
 				unlocking,
@@ -825,8 +951,10 @@ fn reducing_max_unlocking_chunks_abrupt() {
 		assert_ok!(Staking::unbond(RuntimeOrigin::signed(3), 50));
 
 		// then another unlock chunk is added
-		let expected_unlocking: BoundedVec<UnlockChunk<Balance>, MaxUnlockingChunks> =
-			bounded_vec![UnlockChunk { value: 20, era: 13 }, UnlockChunk { value: 50, era: 14 }];
+		let expected_unlocking: BoundedVec<UnlockChunk<Balance, AccountId>, MaxUnlockingChunks> = bounded_vec![
+			UnlockChunk { value: 20, era: 13, dest: None, withdrawable_era: None },
+			UnlockChunk { value: 50, era: 14, dest: None, withdrawable_era: None }
+		];
 		assert!(matches!(Staking::ledger(3.into()),
 			Ok(StakingLedger {
 				unlocking,
@@ -903,6 +1031,131 @@ fn switching_roles() {
 	});
 }
 
+#[test]
+fn role_changes_respect_cooldown() {
+	ExtBuilder::default().build_and_execute(|| {
+		RoleChangeCooldown::set(2);
+		assert_eq!(current_era(), 1);
+
+		// 41 never had a role, so it can take one right away.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(41), Default::default()));
+
+		// chilling is always allowed, but it cannot validate again within the cooldown.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(41)));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(41), Default::default()),
+			Error::<T>::RoleChangeTooSoon
+		);
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(41), vec![11]),
+			Error::<T>::RoleChangeTooSoon
+		);
+		assert_eq!(
+			Staking::api_can_nominate(41, vec![11]),
+			Err(sp_staking::NominateError::RoleChangeTooSoon)
+		);
+
+		// 11 validates since era 0, so it cannot switch to nominating until era 2.
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(11), vec![21]),
+			Error::<T>::RoleChangeTooSoon
+		);
+
+		// keeping the same role is not a role change.
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { commission: Perbill::from_percent(5), ..Default::default() }
+		));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+
+		Session::roll_until_active_era(2);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(11), vec![21]));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(41), Default::default()),
+			Error::<T>::RoleChangeTooSoon
+		);
+
+		// the cooldown of 41 is over in era 3.
+		Session::roll_until_active_era(3);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(41), Default::default()));
+	});
+}
+
+#[test]
+fn paused_nominations_can_be_resumed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let is_voter = |who: AccountId| {
+			<Staking as ElectionDataProvider>::electing_voters(DataProviderBounds::default(), 0)
+				.unwrap()
+				.iter()
+				.any(|(v, _, _)| *v == who)
+		};
+		assert_eq!(Nominators::<T>::get(101).unwrap().targets.into_inner(), vec![11, 21]);
+		assert!(is_voter(101));
+
+		// 101 pauses, keeping its targets, and is no longer a voter.
+		assert_ok!(Staking::chill_keep_targets(RuntimeOrigin::signed(101)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Chilled { stash: 101 }, Event::NominationsPaused { stash: 101 }]
+		);
+		assert!(!Nominators::<T>::contains_key(101));
+		assert_eq!(PausedNominations::<T>::get(101).unwrap().into_inner(), vec![11, 21]);
+		assert!(!is_voter(101));
+		assert_noop!(
+			Staking::chill_keep_targets(RuntimeOrigin::signed(101)),
+			Error::<T>::NotNominator
+		);
+
+		// one of its targets chills while paused, across an era.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(21)));
+		Session::roll_until_active_era(2);
+		assert!(!is_voter(101));
+		let _ = staking_events_since_last_call();
+
+		// resuming restores the targets that are still validators.
+		assert_ok!(Staking::resume(RuntimeOrigin::signed(101)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::NominationsResumed { stash: 101, removed: 1 }]
+		);
+		let nominations = Nominators::<T>::get(101).unwrap();
+		assert_eq!(nominations.targets.into_inner(), vec![11]);
+		assert_eq!(nominations.submitted_in, 2);
+		assert!(!PausedNominations::<T>::contains_key(101));
+		assert!(is_voter(101));
+		assert_noop!(Staking::resume(RuntimeOrigin::signed(101)), Error::<T>::NotPaused);
+	});
+}
+
+#[test]
+fn resume_revalidates_paused_nominations() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(41), vec![11, 21, 31]));
+		assert_ok!(Staking::chill_keep_targets(RuntimeOrigin::signed(41)));
+
+		// with a stake of 222, 41 can only nominate 2 targets.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(41), 4000 - 222));
+		assert_noop!(Staking::resume(RuntimeOrigin::signed(41)), Error::<T>::TooManyTargets);
+
+		// once 31 is chilled, the remaining targets fit the quota.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(31)));
+		assert_ok!(Staking::resume(RuntimeOrigin::signed(41)));
+		assert_eq!(Nominators::<T>::get(41).unwrap().targets.into_inner(), vec![11, 21]);
+
+		// if no target is left, it cannot resume.
+		assert_ok!(Staking::chill_keep_targets(RuntimeOrigin::signed(41)));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(21)));
+		assert_noop!(Staking::resume(RuntimeOrigin::signed(41)), Error::<T>::EmptyTargets);
+
+		// chilling drops the paused nominations.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(41)));
+		assert!(!PausedNominations::<T>::contains_key(41));
+		assert_noop!(Staking::resume(RuntimeOrigin::signed(41)), Error::<T>::NotPaused);
+	});
+}
+
 #[test]
 fn bond_with_no_staked_value() {
 	// Behavior when someone bonds with no staked value.
@@ -930,7 +1183,12 @@ fn bond_with_no_staked_value() {
 					stash: 1,
 					active: 0,
 					total: 5,
-					unlocking: bounded_vec![UnlockChunk { value: 5, era: 4 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 5,
+						era: 4,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1105,7 +1363,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 100,
-					unlocking: bounded_vec![UnlockChunk { value: 900, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 900,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1129,7 +1392,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 100,
-					unlocking: bounded_vec![UnlockChunk { value: 900, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 900,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1141,7 +1409,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 600,
-					unlocking: bounded_vec![UnlockChunk { value: 400, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 400,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1167,7 +1440,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 100,
-					unlocking: bounded_vec![UnlockChunk { value: 900, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 900,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1179,7 +1457,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 600,
-					unlocking: bounded_vec![UnlockChunk { value: 400, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 400,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 		})
@@ -1207,7 +1490,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 600,
-					unlocking: bounded_vec![UnlockChunk { value: 400, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 400,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1222,8 +1510,8 @@ mod rebond {
 					total: 1000,
 					active: 300,
 					unlocking: bounded_vec![
-						UnlockChunk { value: 400, era: 1 + 3 },
-						UnlockChunk { value: 300, era: 2 + 3 },
+						UnlockChunk { value: 400, era: 1 + 3, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 300, era: 2 + 3, dest: None, withdrawable_era: None },
 					],
 				}
 			);
@@ -1239,9 +1527,9 @@ mod rebond {
 					total: 1000,
 					active: 100,
 					unlocking: bounded_vec![
-						UnlockChunk { value: 400, era: 1 + 3 },
-						UnlockChunk { value: 300, era: 2 + 3 },
-						UnlockChunk { value: 200, era: 3 + 3 },
+						UnlockChunk { value: 400, era: 1 + 3, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 300, era: 2 + 3, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 200, era: 3 + 3, dest: None, withdrawable_era: None },
 					],
 				}
 			);
@@ -1255,8 +1543,8 @@ mod rebond {
 					total: 1000,
 					active: 500,
 					unlocking: bounded_vec![
-						UnlockChunk { value: 400, era: 1 + 3 },
-						UnlockChunk { value: 100, era: 2 + 3 },
+						UnlockChunk { value: 400, era: 1 + 3, dest: None, withdrawable_era: None },
+						UnlockChunk { value: 100, era: 2 + 3, dest: None, withdrawable_era: None },
 					],
 				}
 			);
@@ -1279,7 +1567,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 100,
-					unlocking: bounded_vec![UnlockChunk { value: 900, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 900,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 			assert_eq!(
@@ -1295,7 +1588,12 @@ mod rebond {
 					stash: 11,
 					total: 1000,
 					active: 200,
-					unlocking: bounded_vec![UnlockChunk { value: 800, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 800,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 			assert_eq!(
@@ -1348,7 +1646,12 @@ mod rebond {
 						stash: 21,
 						total: 11 * 1000,
 						active: 0,
-						unlocking: bounded_vec![UnlockChunk { value: 11 * 1000, era: 4 }],
+						unlocking: bounded_vec![UnlockChunk {
+							value: 11 * 1000,
+							era: 4,
+							dest: None,
+							withdrawable_era: None
+						}],
 					}
 				);
 
@@ -1392,6 +1695,7 @@ mod reap {
 
 				// WHEN: we set the ledger to below min validator bond but above min nominator bond.
 				Ledger::<Test>::insert(11, StakingLedger::<Test>::new(11, 1499));
+				sync_total_bonded();
 
 				// THEN: still can't reap as the balance is above min nominator bond.
 				assert_noop!(
@@ -1401,6 +1705,7 @@ mod reap {
 
 				// WHEN: set ledger to below ED
 				Ledger::<Test>::insert(11, StakingLedger::<Test>::new(11, 9));
+				sync_total_bonded();
 
 				// THEN: reap-able
 				assert_ok!(Staking::reap_stash(RuntimeOrigin::signed(20), 11, 0));
@@ -1439,6 +1744,7 @@ mod reap {
 				// no easy way to cause an account to go below ED, we tweak their staking ledger
 				// instead.
 				Ledger::<Test>::insert(11, StakingLedger::<Test>::new(11, 0));
+				sync_total_bonded();
 
 				// reap-able
 				assert_ok!(Staking::reap_stash(RuntimeOrigin::signed(20), 11, 0));
@@ -1536,6 +1842,126 @@ mod nominate {
 			);
 		});
 	}
+
+	#[test]
+	fn kick_is_bounded_by_max_kicks() {
+		ExtBuilder::default().validator_count(4).nominate(true).build_and_execute(|| {
+			// given
+			MaxKicks::set(1);
+			assert_eq!(Nominators::<Test>::get(&101).unwrap().targets, vec![11, 21]);
+
+			// too many nominators to kick at once.
+			assert_noop!(
+				Staking::kick(RuntimeOrigin::signed(11), vec![101, 101]),
+				Error::<Test>::TooManyKicks
+			);
+
+			// within the bound works.
+			assert_ok!(Staking::kick(RuntimeOrigin::signed(11), vec![101]));
+			assert_eq!(Nominators::<Test>::get(&101).unwrap().targets, vec![21]);
+			assert_eq!(
+				*staking_events().last().unwrap(),
+				Event::Kicked { nominator: 101, stash: 11 }
+			);
+		});
+	}
+
+	#[test]
+	fn force_set_blocked_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			MaxBlockedUpdates::set(3);
+			assert!(!Staking::api_is_validator_blocked(11));
+			assert!(!Staking::api_is_validator_blocked(21));
+
+			// only the admin origin can use it.
+			assert_noop!(
+				Staking::force_set_blocked(RuntimeOrigin::signed(2), vec![(11, true)]),
+				BadOrigin
+			);
+
+			// bounded by `MaxBlockedUpdates`.
+			assert_noop!(
+				Staking::force_set_blocked(
+					RuntimeOrigin::root(),
+					vec![(11, true), (21, true), (31, true), (41, true)]
+				),
+				Error::<Test>::TooManyBlockedUpdates
+			);
+
+			// non-validators and unchanged flags are skipped.
+			assert_ok!(Staking::force_set_blocked(
+				RuntimeOrigin::root(),
+				vec![(11, true), (21, false), (101, true)]
+			));
+			assert!(Staking::api_is_validator_blocked(11));
+			assert!(!Staking::api_is_validator_blocked(21));
+			assert!(!Staking::api_is_validator_blocked(101));
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![Event::ValidatorBlockedForced { stash: 11, blocked: true }]
+			);
+
+			// blocked validators can no longer be nominated by new nominators.
+			bond_nominator(301, 500, vec![21]);
+			assert_noop!(
+				Staking::nominate(RuntimeOrigin::signed(301), vec![11]),
+				Error::<Test>::BadTarget
+			);
+
+			// and can be unblocked again.
+			assert_ok!(Staking::force_set_blocked(RuntimeOrigin::root(), vec![(11, false)]));
+			assert!(!Staking::api_is_validator_blocked(11));
+			assert_eq!(
+				*staking_events().last().unwrap(),
+				Event::ValidatorBlockedForced { stash: 11, blocked: false }
+			);
+		});
+	}
+
+	#[test]
+	fn process_auto_renominate_removes_non_validators() {
+		ExtBuilder::default().nominate(false).build_and_execute(|| {
+			// given 1 nominating 11, 21 and 31.
+			bond_nominator(1, 1000, vec![11, 21, 31]);
+
+			// not opted in.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(31)));
+			assert_noop!(
+				Staking::process_auto_renominate(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::AutoRenominateDisabled
+			);
+
+			// when opted in, 31 is removed and 11 and 21 are kept.
+			assert_ok!(Staking::set_auto_renominate(RuntimeOrigin::signed(1), true));
+			assert_ok!(Staking::process_auto_renominate(RuntimeOrigin::signed(2), 1));
+			assert_eq!(Nominators::<Test>::get(1).unwrap().targets, vec![11, 21]);
+			assert_eq!(
+				*staking_events().last().unwrap(),
+				Event::AutoRenominated { stash: 1, removed: 1 }
+			);
+
+			// all targets are validators.
+			assert_noop!(
+				Staking::process_auto_renominate(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::NothingToRenominate
+			);
+
+			// when no valid target is left, 1 is chilled.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(21)));
+			assert_ok!(Staking::process_auto_renominate(RuntimeOrigin::signed(2), 1));
+			assert!(!Nominators::<Test>::contains_key(1));
+			assert_eq!(
+				staking_events_since_last_call().into_iter().rev().take(2).collect::<Vec<_>>(),
+				vec![Event::AutoRenominated { stash: 1, removed: 2 }, Event::Chilled { stash: 1 }]
+			);
+
+			// opting out.
+			assert_ok!(Staking::set_auto_renominate(RuntimeOrigin::signed(1), false));
+			assert!(!AutoRenominate::<Test>::contains_key(1));
+		});
+	}
 }
 
 mod staking_bounds_chill_other {
@@ -1587,6 +2013,36 @@ mod staking_bounds_chill_other {
 			})
 	}
 
+	#[test]
+	fn role_minimum_is_checked_against_active_bond() {
+		ExtBuilder::default()
+			.existential_deposit(100)
+			.balance_factor(100)
+			.min_nominator_bond(1_000)
+			.min_validator_bond(1_500)
+			.build_and_execute(|| {
+				// bonded and nominating at the nominator minimum.
+				assert_ok!(Staking::bond(RuntimeOrigin::signed(3), 1000, RewardDestination::Stash));
+				assert_ok!(Staking::nominate(RuntimeOrigin::signed(3), vec![11]));
+
+				// switching to validating is refused, and the nominations are kept.
+				assert_noop!(
+					Staking::validate(RuntimeOrigin::signed(3), ValidatorPrefs::default()),
+					Error::<Test>::InsufficientBond,
+				);
+				assert!(Nominators::<Test>::contains_key(3));
+
+				// once the nominator minimum is raised above the active bond, they can no longer
+				// update their nominations either.
+				MinNominatorBond::<Test>::put(1_200);
+				assert_noop!(
+					Staking::nominate(RuntimeOrigin::signed(3), vec![21]),
+					Error::<Test>::InsufficientBond,
+				);
+				assert_eq!(Nominators::<Test>::get(3).unwrap().targets.into_inner(), vec![11]);
+			})
+	}
+
 	#[test]
 	fn chill_other_works() {
 		ExtBuilder::default()
@@ -1628,7 +2084,11 @@ mod staking_bounds_chill_other {
 							Event::Bonded { stash: b, amount: 1500 },
 							Event::ValidatorPrefsSet {
 								stash: b,
-								prefs: ValidatorPrefs { commission: Zero::zero(), blocked: false }
+								prefs: ValidatorPrefs {
+									commission: Zero::zero(),
+									blocked: false,
+									max_reward_per_era: None
+								}
 							}
 						]
 					);
@@ -1937,3 +2397,194 @@ mod staking_bounds_chill_other {
 		})
 	}
 }
+
+#[test]
+fn emergency_bonding_duration_shortens_new_unbonds() {
+	ExtBuilder::default().bonding_duration(7).build_and_execute(|| {
+		// given an unbond with the full bonding duration.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+
+		// only the admin can set the override.
+		assert_noop!(
+			Staking::set_emergency_bonding_duration(RuntimeOrigin::signed(11), Some(2)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), Some(2)));
+
+		// when
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 50));
+
+		// then only the new chunk matures sooner.
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 850,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 100, era: 1 + 7, dest: None, withdrawable_era: None },
+					UnlockChunk {
+						value: 50,
+						era: 1 + 7,
+						dest: None,
+						withdrawable_era: Some(1 + 2)
+					}
+				],
+			},
+		);
+
+		// and it can be withdrawn once it does.
+		Session::roll_until_active_era(3);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Withdrawn { stash: 11, amount: 50, remaining_chunks: 1 }]
+		);
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 950,
+				active: 850,
+				unlocking: bounded_vec![UnlockChunk {
+					value: 100,
+					era: 1 + 7,
+					dest: None,
+					withdrawable_era: None
+				}],
+			},
+		);
+
+		// overrides longer than the bonding duration are capped.
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), Some(10)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 10));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap().unlocking.last().unwrap().unlock_era(),
+			3 + 7
+		);
+
+		// and clearing it restores the bonding duration.
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), None));
+		assert_eq!(EmergencyBondingDuration::<T>::get(), None);
+	});
+}
+
+#[test]
+fn emergency_bonding_duration_keeps_full_slashing_window() {
+	ExtBuilder::default().bonding_duration(3).build_and_execute(|| {
+		// given a shortened bonding duration.
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), Some(1)));
+		Session::roll_until_active_era(4);
+		let _ = staking_events_since_last_call();
+
+		// when an offence is reported for an era older than the shortened duration, but within
+		// the full bonding duration.
+		add_slash_in_era(11, 1, Perbill::from_percent(10));
+		Session::roll_next();
+
+		// then it is still slashed.
+		assert!(
+			staking_events_since_last_call().contains(&Event::Slashed { staker: 11, amount: 100 })
+		);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 900);
+	});
+}
+
+#[test]
+fn emergency_unbonds_are_slashed_like_other_unbonds() {
+	ExtBuilder::default().bonding_duration(3).build_and_execute(|| {
+		// given an unbond with the full bonding duration in era 1.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+
+		// and an emergency unbond in era 2, which can be withdrawn before the first one.
+		Session::roll_until_active_era(2);
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), Some(1)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 200));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap().unlocking.into_inner(),
+			vec![
+				UnlockChunk { value: 100, era: 1 + 3, dest: None, withdrawable_era: None },
+				UnlockChunk { value: 200, era: 2 + 3, dest: None, withdrawable_era: Some(2 + 1) }
+			]
+		);
+		let _ = staking_events_since_last_call();
+
+		// when an offence of era 2 is slashed before the emergency unbond is withdrawn.
+		add_slash_in_era(11, 2, Perbill::from_percent(10));
+		Session::roll_next();
+
+		// then the emergency chunk is slashed proportionally along with the active stake, while
+		// the chunk unbonded before the offence is not.
+		assert!(
+			staking_events_since_last_call().contains(&Event::Slashed { staker: 11, amount: 90 })
+		);
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 910,
+				active: 630,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 100, era: 1 + 3, dest: None, withdrawable_era: None },
+					UnlockChunk {
+						value: 180,
+						era: 2 + 3,
+						dest: None,
+						withdrawable_era: Some(2 + 1)
+					}
+				],
+			},
+		);
+
+		// and what is left of it can still be withdrawn early.
+		Session::roll_until_active_era(3);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Withdrawn { stash: 11, amount: 180, remaining_chunks: 1 }]
+		);
+	});
+}
+
+#[test]
+fn unbond_to_sends_withdrawn_funds_to_dest() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given a plain unbond and an unbond to 777, in the same era.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 50));
+		assert_ok!(Staking::unbond_to(RuntimeOrigin::signed(11), 100, 777));
+		assert_ok!(Staking::unbond_to(RuntimeOrigin::signed(11), 20, 777));
+
+		// then chunks are only merged if they have the same destination.
+		let era = 1 + BondingDuration::get();
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 830,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 50, era, dest: None, withdrawable_era: None },
+					UnlockChunk { value: 120, era, dest: Some(777), withdrawable_era: None }
+				],
+			},
+		);
+
+		// when
+		Session::roll_until_active_era(era);
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+
+		// then only the funds unbonded to 777 are sent there.
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Withdrawn { stash: 11, amount: 170, remaining_chunks: 0 },
+				Event::WithdrawnTo { stash: 11, dest: 777, amount: 120 }
+			]
+		);
+		assert_eq!(asset::total_balance::<T>(&777), 120);
+		assert_eq!(asset::staked::<T>(&11), 830);
+	});
+}
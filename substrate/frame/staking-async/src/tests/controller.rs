@@ -85,3 +85,55 @@ fn change_controller_already_paired_once_stash() {
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), ValidatorPrefs::default()));
 	})
 }
+
+#[test]
+fn force_set_controller_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::bonded(&11), Some(11));
+
+		// only admin origin.
+		assert_noop!(Staking::force_set_controller(RuntimeOrigin::signed(11), 11, 1000), BadOrigin);
+
+		// cannot set the current controller again.
+		assert_noop!(
+			Staking::force_set_controller(RuntimeOrigin::root(), 11, 11),
+			Error::<Test>::AlreadyPaired
+		);
+
+		// cannot collide with another stash or controller.
+		assert_noop!(
+			Staking::force_set_controller(RuntimeOrigin::root(), 11, 21),
+			Error::<Test>::AlreadyPaired
+		);
+
+		// not bonded.
+		assert_noop!(
+			Staking::force_set_controller(RuntimeOrigin::root(), 1000, 1001),
+			Error::<Test>::NotStash
+		);
+
+		// set to a fresh account.
+		assert_ok!(Staking::force_set_controller(RuntimeOrigin::root(), 11, 1000));
+		assert_eq!(Staking::bonded(&11), Some(1000));
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::ControllerForced { stash: 11, controller: 1000 }
+		);
+		let ledger = Staking::ledger(StakingAccount::Controller(1000)).unwrap();
+		assert_eq!(ledger.stash, 11);
+		assert_eq!(ledger.controller(), Some(1000));
+		assert!(Ledger::<Test>::get(&11).is_none());
+
+		// `1000` is now in control, and cannot be used as controller of another stash.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(1000)));
+		assert_noop!(
+			Staking::force_set_controller(RuntimeOrigin::root(), 21, 1000),
+			Error::<Test>::AlreadyPaired
+		);
+
+		// and it can be set back to the stash.
+		assert_ok!(Staking::force_set_controller(RuntimeOrigin::root(), 11, 11));
+		assert_eq!(Staking::bonded(&11), Some(11));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), ValidatorPrefs::default()));
+	})
+}
@@ -290,13 +290,41 @@ fn nomination_quota_checks_at_nominate_works() {
 }
 
 #[test]
-#[should_panic]
-#[cfg(debug_assertions)]
+fn max_nominators_per_validator_checked_at_nominate() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		MaxNominatorsPerValidator::set(2);
+		bond(61, 1000);
+		bond(71, 1000);
+		bond(81, 1000);
+
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(61), vec![11]));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(71), vec![11, 21]));
+		assert_eq!((BackersCount::<Test>::get(11), BackersCount::<Test>::get(21)), (2, 1));
+
+		// 11 is full, 21 is not.
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(81), vec![11, 21]),
+			Error::<Test>::ValidatorFull
+		);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(81), vec![21]));
+
+		// existing nominators of a full validator can keep nominating it.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(71), vec![11]));
+
+		// once a nominator leaves, there is room again.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(61)));
+		assert_eq!(BackersCount::<Test>::get(11), 1);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(81), vec![11, 21]));
+		assert_eq!((BackersCount::<Test>::get(11), BackersCount::<Test>::get(21)), (2, 1));
+	});
+}
+
+#[test]
 fn change_of_absolute_max_nominations() {
 	use frame_election_provider_support::ElectionDataProvider;
 	ExtBuilder::default()
-		.add_staker(61, 10, StakerStatus::Nominator(vec![1]))
-		.add_staker(71, 10, StakerStatus::Nominator(vec![1, 2, 3]))
+		.add_staker(61, 10, StakerStatus::Nominator(vec![11]))
+		.add_staker(71, 10, StakerStatus::Nominator(vec![11, 21, 31]))
 		.balance_factor(10)
 		.build_and_execute(|| {
 			// pre-condition
@@ -377,11 +405,14 @@ fn change_of_absolute_max_nominations() {
 			// but its value cannot be decoded and default is returned.
 			assert!(Nominators::<Test>::get(71).is_none());
 
-			assert_eq!(Staking::electing_voters(bounds, 0).unwrap().len(), 3 + 2);
+			// it is still part of the snapshot though, with its targets capped.
+			let voters = Staking::electing_voters(bounds, 0).unwrap();
+			assert_eq!(voters.len(), 3 + 3);
+			assert_eq!(voters.iter().find(|(v, _, _)| *v == 71).unwrap().2.len(), 2);
 			assert!(Nominators::<Test>::contains_key(101));
 
-			// abrupt change from 2 to 1, this should cause some nominators to be non-decodable,
-			// and thus non-existent unless they update.
+			// abrupt change from 2 to 1, this should cause some more nominators to be
+			// non-decodable.
 			AbsoluteMaxNominations::set(1);
 
 			assert_eq!(
@@ -401,10 +432,10 @@ fn change_of_absolute_max_nominations() {
 			assert!(Nominators::<Test>::contains_key(61));
 			assert!(Nominators::<Test>::get(71).is_none());
 			assert!(Nominators::<Test>::get(61).is_some());
-			assert_eq!(Staking::electing_voters(bounds, 0).unwrap().len(), 3 + 1);
+			assert_eq!(Staking::electing_voters(bounds, 0).unwrap().len(), 3 + 3);
 
 			// now one of them can revive themselves by re-nominating to a proper value.
-			assert_ok!(Staking::nominate(RuntimeOrigin::signed(71), vec![1]));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(71), vec![11]));
 			assert_eq!(
 				Nominators::<Test>::iter()
 					.map(|(k, n)| (k, n.targets.len()))
@@ -422,6 +453,59 @@ fn change_of_absolute_max_nominations() {
 		})
 }
 
+#[test]
+fn trim_nominations_works() {
+	ExtBuilder::default()
+		.add_staker(61, 10, StakerStatus::Nominator(vec![11]))
+		.add_staker(71, 10, StakerStatus::Nominator(vec![11, 21, 31]))
+		.balance_factor(10)
+		.build_and_execute(|| {
+			// 11 is no longer a validator, and thus the least staked target of 71.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+			let submitted_in = Nominators::<Test>::get(71).unwrap().submitted_in;
+
+			// nothing to trim while within the limit.
+			assert_noop!(
+				Staking::trim_nominations(RuntimeOrigin::signed(1), 71, 3),
+				Error::<Test>::NothingToTrim
+			);
+
+			AbsoluteMaxNominations::set(2);
+			assert!(Nominators::<Test>::get(71).is_none());
+
+			// other nominators and non-nominators have nothing to trim.
+			for who in [61, 11, 1] {
+				assert_noop!(
+					Staking::trim_nominations(RuntimeOrigin::signed(1), who, 3),
+					Error::<Test>::NothingToTrim
+				);
+			}
+
+			// the witness must cover all targets.
+			assert_noop!(
+				Staking::trim_nominations(RuntimeOrigin::signed(1), 71, 2),
+				Error::<Test>::InvalidNumberOfNominations
+			);
+
+			// anyone can trim.
+			assert_ok!(Staking::trim_nominations(RuntimeOrigin::signed(1), 71, 3));
+			assert_eq!(
+				*staking_events().last().unwrap(),
+				Event::NominationsTrimmed { stash: 71, removed: 1 }
+			);
+			let nominations = Nominators::<Test>::get(71).unwrap();
+			assert_eq!(nominations.targets.into_inner(), vec![21, 31]);
+			assert_eq!(nominations.submitted_in, submitted_in);
+			assert!(!Staking::api_backers_of(11).contains(&71));
+
+			// and only once.
+			assert_noop!(
+				Staking::trim_nominations(RuntimeOrigin::signed(1), 71, 3),
+				Error::<Test>::NothingToTrim
+			);
+		})
+}
+
 #[test]
 fn nomination_quota_max_changes_decoding() {
 	use frame_election_provider_support::ElectionDataProvider;
@@ -289,6 +289,30 @@ fn less_than_needed_candidates_works() {
 	});
 }
 
+#[test]
+fn minimum_elected_stake_is_updated_per_election() {
+	ExtBuilder::default().build_and_execute(|| {
+		let cutoff_of = |era| {
+			ErasStakersOverview::<T>::iter_prefix_values(era)
+				.map(|o| o.total)
+				.min()
+				.unwrap()
+		};
+
+		// set by the election of era 1.
+		assert_eq!(active_era(), 1);
+		let cutoff = cutoff_of(1);
+		assert_eq!(cutoff, 1250);
+		assert_eq!(Staking::api_minimum_elected_stake(), cutoff);
+
+		// the only nominator leaves, lowering the cutoff of the next election.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		Session::roll_until_active_era(3);
+		assert_eq!(cutoff_of(3), 1000);
+		assert_eq!(Staking::api_minimum_elected_stake(), 1000);
+	})
+}
+
 mod paged_exposures {
 	use super::*;
 
@@ -470,6 +494,90 @@ mod paged_exposures {
 			);
 		})
 	}
+
+	#[test]
+	fn store_stakers_info_drops_excess_backers() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxBackersExposed::set(3);
+			let era = current_era();
+			let initial_total = ErasTotalStake::<T>::get(era);
+
+			let exposures_page_one = bounded_vec![
+				// exactly at the limit, nothing is dropped.
+				(
+					1,
+					Exposure {
+						total: 1000 + 600,
+						own: 1000,
+						others: vec![
+							IndividualExposure { who: 101, value: 100 },
+							IndividualExposure { who: 102, value: 300 },
+							IndividualExposure { who: 103, value: 200 },
+						],
+					}
+				),
+				// one over the limit, the lowest stake is dropped.
+				(
+					2,
+					Exposure {
+						total: 1000 + 1000,
+						own: 1000,
+						others: vec![
+							IndividualExposure { who: 104, value: 100 },
+							IndividualExposure { who: 105, value: 400 },
+							IndividualExposure { who: 106, value: 200 },
+							IndividualExposure { who: 107, value: 300 },
+						],
+					}
+				),
+			];
+			EraElectionPlanner::<T>::store_stakers_info(exposures_page_one, era);
+
+			assert_eq!(
+				ErasStakersOverview::<T>::get(era, &1).unwrap(),
+				PagedExposureMetadata { total: 1600, own: 1000, nominator_count: 3, page_count: 1 },
+			);
+			assert_eq!(
+				ErasStakersOverview::<T>::get(era, &2).unwrap(),
+				PagedExposureMetadata { total: 1900, own: 1000, nominator_count: 3, page_count: 1 },
+			);
+			assert_eq!(
+				ErasStakersPaged::<T>::get((era, &2, 0))
+					.unwrap()
+					.others
+					.iter()
+					.map(|e| e.who)
+					.collect::<Vec<_>>(),
+				vec![105, 107, 106]
+			);
+			assert_eq!(ErasTotalStake::<T>::get(era), initial_total + 1600 + 1900);
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![Event::NominatorNotExposed { era, validator: 2, nominator: 104, stake: 100 }]
+			);
+
+			// a later page cannot expose more backers for validator 1.
+			let exposures_page_two = bounded_vec![(
+				1,
+				Exposure {
+					total: 1000 + 50,
+					own: 1000,
+					others: vec![IndividualExposure { who: 108, value: 50 }],
+				}
+			)];
+			EraElectionPlanner::<T>::store_stakers_info(exposures_page_two, era);
+
+			assert_eq!(
+				ErasStakersOverview::<T>::get(era, &1).unwrap(),
+				PagedExposureMetadata { total: 1600, own: 1000, nominator_count: 3, page_count: 1 },
+			);
+			assert_eq!(ErasTotalStake::<T>::get(era), initial_total + 1600 + 1900 + 1000);
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![Event::NominatorNotExposed { era, validator: 1, nominator: 108, stake: 50 }]
+			);
+		})
+	}
 }
 
 mod electable_stashes {
@@ -1070,3 +1178,165 @@ mod paged_on_initialize_era_election_planner {
 	// 	})
 	// }
 }
+
+mod under_subscribed_election {
+	use super::*;
+	use pallet_staking_async_rc_client::ValidatorSetReport;
+
+	fn under_subscribed_event(behavior: UnderSubscribedBehavior) -> Event<Test> {
+		Event::ElectionUnderSubscribed { elected: 1, minimum: 2, behavior }
+	}
+
+	#[test]
+	fn use_what_we_got() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given an election that yields a single validator.
+			MinimumValidatorCount::set(2);
+			ValidatorCount::<Test>::set(1);
+
+			// when
+			Session::roll_until_active_era(2);
+
+			// then
+			assert!(staking_events()
+				.contains(&under_subscribed_event(UnderSubscribedBehavior::UseWhatWeGot)));
+			assert_eq!(ReceivedValidatorSets::get_last().new_validator_set.len(), 1);
+			assert_eq!(era_exposures(2).len(), 1);
+		})
+	}
+
+	#[test]
+	fn keep_previous_set() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given an election that yields a single validator.
+			MinimumValidatorCount::set(2);
+			UnderSubscribed::set(UnderSubscribedBehavior::KeepPreviousSet);
+			ValidatorCount::<Test>::set(1);
+
+			// when
+			Session::roll_until_active_era(2);
+
+			// then the validators and exposures of era 1 are kept.
+			assert!(staking_events()
+				.contains(&under_subscribed_event(UnderSubscribedBehavior::KeepPreviousSet)));
+			assert_eq!(
+				ReceivedValidatorSets::get_last(),
+				ValidatorSetReport {
+					id: 2,
+					leftover: false,
+					new_validator_set: vec![11, 21],
+					prune_up_to: None
+				}
+			);
+			assert_eq_uvec!(era_exposures(2), era_exposures(1));
+			assert_eq!(ErasTotalStake::<Test>::get(2), ErasTotalStake::<Test>::get(1));
+			assert_eq!(Eras::<Test>::get_validator_prefs(2, &11), ValidatorPrefs::default());
+		})
+	}
+
+	#[test]
+	fn halt() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given an election that yields a single validator.
+			MinimumValidatorCount::set(2);
+			UnderSubscribed::set(UnderSubscribedBehavior::Halt);
+			ValidatorCount::<Test>::set(1);
+
+			// when
+			Session::roll_until_session(12);
+
+			// then no validator set is sent, and era 2 is never activated.
+			assert!(
+				staking_events().contains(&under_subscribed_event(UnderSubscribedBehavior::Halt))
+			);
+			assert!(ReceivedValidatorSets::get().values().all(|report| report.id != 2));
+			assert_eq!(active_era(), 1);
+
+			// and the planning of era 2 is rolled back, with no new era planned.
+			assert_eq!(current_era(), 1);
+			assert!(era_exposures(2).is_empty());
+			assert_eq!(ForceEra::<Test>::get(), Forcing::ForceNone);
+		})
+	}
+
+	#[test]
+	fn halt_can_be_resolved_with_previous_set() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a halted election.
+			MinimumValidatorCount::set(2);
+			UnderSubscribed::set(UnderSubscribedBehavior::Halt);
+			ValidatorCount::<Test>::set(1);
+			Session::roll_until_session(12);
+			assert_eq!(current_era(), 1);
+
+			// only root can force the previous set.
+			assert_noop!(
+				Staking::force_previous_validator_set(RuntimeOrigin::signed(11)),
+				DispatchError::BadOrigin
+			);
+
+			// when
+			assert_ok!(Staking::force_previous_validator_set(RuntimeOrigin::root()));
+
+			// then the validators and exposures of era 1 are sent for era 2.
+			assert_eq!(current_era(), 2);
+			assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
+			assert_eq!(
+				ReceivedValidatorSets::get_last(),
+				ValidatorSetReport {
+					id: 2,
+					leftover: false,
+					new_validator_set: vec![11, 21],
+					prune_up_to: None
+				}
+			);
+			assert_eq_uvec!(era_exposures(2), era_exposures(1));
+
+			// cannot be forced again while era 2 is planned.
+			assert_noop!(
+				Staking::force_previous_validator_set(RuntimeOrigin::root()),
+				Error::<Test>::AlreadyPlanning
+			);
+
+			// and era 2 is activated as usual.
+			Session::roll_until_active_era(2);
+			assert_eq!(active_era(), 2);
+		})
+	}
+
+	#[test]
+	fn halt_can_be_resolved_with_new_election() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a halted election.
+			MinimumValidatorCount::set(2);
+			UnderSubscribed::set(UnderSubscribedBehavior::Halt);
+			ValidatorCount::<Test>::set(1);
+			Session::roll_until_session(12);
+			assert_eq!(current_era(), 1);
+
+			// when the election is retried with enough validators.
+			ValidatorCount::<Test>::set(2);
+			assert_ok!(Staking::force_new_era(RuntimeOrigin::root()));
+
+			// then era 2 is planned and activated.
+			Session::roll_until_active_era(2);
+			assert_eq!(ReceivedValidatorSets::get_last().new_validator_set, vec![11, 21]);
+			assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
+		})
+	}
+
+	#[test]
+	fn enough_validators_is_noop() {
+		ExtBuilder::default().build_and_execute(|| {
+			MinimumValidatorCount::set(2);
+			UnderSubscribed::set(UnderSubscribedBehavior::Halt);
+
+			Session::roll_until_active_era(2);
+
+			assert!(!staking_events()
+				.iter()
+				.any(|e| matches!(e, Event::ElectionUnderSubscribed { .. })));
+			assert_eq!(ReceivedValidatorSets::get_last().new_validator_set, vec![11, 21]);
+		})
+	}
+}
@@ -180,6 +180,64 @@ fn forcing_force_new() {
 		});
 }
 
+#[test]
+fn forcing_force_scheduled() {
+	ExtBuilder::default().session_per_era(6).build_and_execute(|| {
+		assert_eq!(CurrentIndex::get(), 6);
+		assert_eq!(active_era(), 1);
+		let _ = staking_events_since_last_call();
+
+		assert_noop!(
+			Staking::schedule_forced_era(RuntimeOrigin::signed(11), 8),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Staking::schedule_forced_era(RuntimeOrigin::root(), 8));
+		assert_eq!(ForceEra::<T>::get(), Forcing::ForceScheduled(8));
+
+		// nothing is forced until session 8 starts, where the next era is planned.
+		Session::roll_until_active_era(2);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::ForceEra { mode: Forcing::ForceScheduled(8) },
+				Event::SessionRotated { starting_session: 7, active_era: 1, planned_era: 1 },
+				Event::SessionRotated { starting_session: 8, active_era: 1, planned_era: 2 },
+				Event::PagedElectionProceeded { page: 0, result: Ok(2) },
+				Event::SessionRotated { starting_session: 9, active_era: 1, planned_era: 2 },
+				Event::EraPaid { era_index: 1, validator_payout: 10000, remainder: 10000 },
+				Event::SessionRotated { starting_session: 10, active_era: 2, planned_era: 2 }
+			]
+		);
+
+		// and then it is back to normal.
+		assert_eq!(ForceEra::<T>::get(), Forcing::NotForcing);
+	});
+}
+
+#[test]
+fn forcing_force_scheduled_in_the_past() {
+	ExtBuilder::default().session_per_era(6).build_and_execute(|| {
+		assert_eq!(CurrentIndex::get(), 6);
+		let _ = staking_events_since_last_call();
+
+		// a session that has already started is the same as `ForceNew`.
+		assert_ok!(Staking::schedule_forced_era(RuntimeOrigin::root(), 2));
+		Session::roll_until_active_era(2);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::ForceEra { mode: Forcing::ForceScheduled(2) },
+				Event::SessionRotated { starting_session: 7, active_era: 1, planned_era: 2 },
+				Event::PagedElectionProceeded { page: 0, result: Ok(2) },
+				Event::SessionRotated { starting_session: 8, active_era: 1, planned_era: 2 },
+				Event::EraPaid { era_index: 1, validator_payout: 7500, remainder: 7500 },
+				Event::SessionRotated { starting_session: 9, active_era: 2, planned_era: 2 }
+			]
+		);
+		assert_eq!(ForceEra::<T>::get(), Forcing::NotForcing);
+	});
+}
+
 #[test]
 fn activation_timestamp_when_no_planned_era() {
 	// maybe not needed, as we have the id check
@@ -364,6 +422,57 @@ fn progress_many_eras_with_try_state() {
 	})
 }
 
+/// The start session of each of the given eras, after rolling until the last one is active.
+fn era_start_sessions(eras: core::ops::RangeInclusive<EraIndex>) -> Vec<SessionIndex> {
+	Session::roll_until_active_era(*eras.end());
+	eras.map(|era| Rotator::<T>::era_start_session_index(era).unwrap()).collect()
+}
+
+#[test]
+fn lengthening_sessions_per_era_applies_from_next_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(SessionsPerEra::get(), 3);
+		assert_eq!(Rotator::<T>::active_era_start_session_index(), 3);
+
+		// lengthen in the middle of era 1.
+		Session::roll_to_next_session();
+		SessionsPerEra::set(5);
+		assert_eq!(Rotator::<T>::active_era_sessions(), 3);
+
+		// era 1 still lasts 3 sessions, the following ones 5.
+		assert_eq!(era_start_sessions(1..=4), vec![3, 6, 11, 16]);
+		assert_eq!(Rotator::<T>::active_era_sessions(), 5);
+	});
+}
+
+#[test]
+fn shortening_sessions_per_era_applies_from_next_era() {
+	ExtBuilder::default().session_per_era(5).build_and_execute(|| {
+		let era_1_start = Rotator::<T>::active_era_start_session_index();
+		let _ = staking_events_since_last_call();
+
+		// shorten after era 1 has lasted longer than the new length.
+		Session::roll_to_next_session();
+		Session::roll_to_next_session();
+		Session::roll_to_next_session();
+		SessionsPerEra::set(2);
+
+		// era 1 still lasts 5 sessions, the following ones 2.
+		let starts = era_start_sessions(1..=4);
+		assert_eq!(starts.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>(), vec![5, 2, 2]);
+		assert_eq!(starts[0], era_1_start);
+
+		// exactly one era was paid per era boundary.
+		assert_eq!(
+			staking_events_since_last_call()
+				.into_iter()
+				.filter(|e| matches!(e, Event::EraPaid { .. }))
+				.count(),
+			3
+		);
+	});
+}
+
 mod inflation {
 	use super::*;
 
@@ -467,3 +576,67 @@ mod inflation {
 		})
 	}
 }
+
+#[test]
+fn api_bonded_eras_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::api_bonded_eras(), BondedEras::<T>::get().into_inner());
+
+		Session::roll_until_active_era(5);
+
+		// only the last `BondingDuration + 1` eras are kept.
+		let bonded_eras = Staking::api_bonded_eras();
+		assert_eq!(bonded_eras.len() as u32, BondingDuration::get() + 1);
+		assert_eq!(bonded_eras.iter().map(|(era, _)| *era).collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+		assert_eq!(bonded_eras, BondedEras::<T>::get().into_inner());
+	});
+}
+
+#[test]
+fn api_session_era_mapping_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given
+		Session::roll_until_active_era(5);
+		assert_eq!(Staking::api_bonded_eras(), vec![(2, 6), (3, 9), (4, 12), (5, 15)]);
+
+		// then ended eras span until the next era starts.
+		assert_eq!(Staking::api_sessions_in_era(2), vec![6, 7, 8]);
+		assert_eq!(Staking::api_sessions_in_era(4), vec![12, 13, 14]);
+		assert_eq!(Staking::api_era_for_session(6), Some(2));
+		assert_eq!(Staking::api_era_for_session(14), Some(4));
+
+		// and the active era spans its planned sessions.
+		assert_eq!(Staking::api_sessions_in_era(5), vec![15, 16, 17]);
+		assert_eq!(Staking::api_era_for_session(17), Some(5));
+
+		// pruned and future sessions and eras are unknown.
+		assert!(Staking::api_sessions_in_era(1).is_empty());
+		assert!(Staking::api_sessions_in_era(6).is_empty());
+		assert_eq!(Staking::api_era_for_session(5), None);
+		assert_eq!(Staking::api_era_for_session(18), None);
+	});
+}
+
+#[test]
+fn smoothed_era_payout_dampens_anomalous_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		type Smoothed = SmoothedEraPayout<T, OneTokenPerMillisecond, ConstU32<3>>;
+		let payout_for = |duration| {
+			let (payout, rest) = Smoothed::era_payout(0, 0, duration);
+			payout + rest
+		};
+
+		// a steady era duration is paid out as-is.
+		assert_eq!(payout_for(1000), 1000);
+		assert_eq!(payout_for(1000), 1000);
+
+		// an anomalous era is averaged over the last 3 eras.
+		assert_eq!(payout_for(4000), 2000);
+		// and keeps affecting the payout until it is out of the window.
+		assert_eq!(payout_for(1000), 2000);
+		assert_eq!(payout_for(1000), 2000);
+		assert_eq!(payout_for(1000), 1000);
+
+		assert_eq!(RecentEraDurations::<T>::get().into_inner(), vec![1000, 1000, 1000]);
+	});
+}
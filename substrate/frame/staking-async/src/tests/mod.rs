@@ -30,7 +30,7 @@ use frame_support::{
 };
 use mock::*;
 use sp_runtime::{
-	assert_eq_error_rate, bounded_vec, traits::BadOrigin, Perbill, Percent, TokenError,
+	assert_eq_error_rate, bounded_vec, traits::BadOrigin, Perbill, Percent, Perquintill, TokenError,
 };
 use sp_staking::{Stake, StakingAccount, StakingInterface};
 use substrate_test_utils::assert_eq_uvec;
@@ -144,7 +144,7 @@ fn basic_setup_works() {
 		assert_eq_uvec!(
 			<Validators<Test>>::iter().collect::<Vec<_>>(),
 			vec![
-				(31, ValidatorPrefs::default()),
+				(31, ValidatorPrefs::<Balance>::default()),
 				(21, ValidatorPrefs::default()),
 				(11, ValidatorPrefs::default())
 			]
@@ -386,11 +386,33 @@ mod validator_count {
 			);
 		})
 	}
+
+	#[test]
+	fn api_intended_validator_count_works() {
+		ExtBuilder::default().validator_count(3).build_and_execute(|| {
+			assert_eq!(Staking::api_intended_validator_count(), 3);
+
+			// the intended count is reported even if fewer validators are elected.
+			assert_ok!(Staking::set_validator_count(RuntimeOrigin::root(), 10));
+			assert_eq!(Staking::api_intended_validator_count(), 10);
+		})
+	}
+
+	#[test]
+	fn api_minimum_validator_count_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Staking::api_minimum_validator_count(), 0);
+
+			MinimumValidatorCount::set(2);
+			assert_eq!(Staking::api_minimum_validator_count(), 2);
+		})
+	}
 }
 
 mod staking_interface {
+	use crate::session_rotation::Eras;
 	use frame_support::storage::with_storage_layer;
-	use sp_staking::StakingInterface;
+	use sp_staking::{RewardBreakdown, StakingInterface};
 
 	use super::*;
 
@@ -443,7 +465,12 @@ mod staking_interface {
 						stash: 11,
 						total: 1000,
 						active: 0,
-						unlocking: bounded_vec![UnlockChunk { value: 1000, era: 4 }],
+						unlocking: bounded_vec![UnlockChunk {
+							value: 1000,
+							era: 4,
+							dest: None,
+							withdrawable_era: None
+						}],
 					},
 				);
 
@@ -485,6 +512,732 @@ mod staking_interface {
 			assert!(Staking::status(&42).is_err());
 		})
 	}
+
+	#[test]
+	fn api_staker_status_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Staking::api_staker_status(11), Some(StakerStatus::Validator));
+			assert_eq!(
+				Staking::api_staker_status(101),
+				Some(StakerStatus::Nominator(vec![11, 21]))
+			);
+			assert_eq!(Staking::api_staker_status(41), Some(StakerStatus::Idle));
+			// not bonded.
+			assert_eq!(Staking::api_staker_status(42), None);
+		})
+	}
+
+	#[test]
+	fn api_validator_since_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// genesis validators.
+			assert_eq!(Staking::api_validator_since(11), Some(0));
+			assert_eq!(Staking::api_validator_since(101), None);
+			assert_eq!(Staking::api_validator_since(41), None);
+
+			// 41 declares its intent in era 1.
+			assert_eq!(current_era(), 1);
+			assert_ok!(Staking::validate(RuntimeOrigin::signed(41), Default::default()));
+			assert_eq!(Staking::api_validator_since(41), Some(1));
+
+			// validating again later does not change it.
+			Session::roll_until_active_era(2);
+			assert_ok!(Staking::validate(
+				RuntimeOrigin::signed(41),
+				ValidatorPrefs { commission: Perbill::from_percent(5), ..Default::default() }
+			));
+			assert_eq!(Staking::api_validator_since(41), Some(1));
+
+			// it is cleared on chill, and starts over on the next `validate`.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(41)));
+			assert_eq!(Staking::api_validator_since(41), None);
+			assert_ok!(Staking::validate(RuntimeOrigin::signed(41), Default::default()));
+			assert_eq!(Staking::api_validator_since(41), Some(current_era()));
+			assert_ne!(current_era(), 1);
+		})
+	}
+
+	#[test]
+	fn api_validator_oversubscribed_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let era = active_era();
+			assert_eq!(ErasStakersOverview::<T>::get(era, &11).unwrap().nominator_count, 1);
+			assert_eq!(BackersCount::<T>::get(&11), 1);
+
+			// all backers are exposed.
+			assert!(!Staking::api_validator_oversubscribed(era, 11));
+			assert!(!Staking::api_validator_oversubscribed(era, 21));
+
+			// 11 gets a backer that is not part of its exposure.
+			bond_nominator(201, 500, vec![11]);
+			assert!(Staking::api_validator_oversubscribed(era, 11));
+			assert!(!Staking::api_validator_oversubscribed(era, 21));
+
+			// not exposed in this era, or any era that was not planned.
+			assert!(!Staking::api_validator_oversubscribed(era, 41));
+			assert!(!Staking::api_validator_oversubscribed(era + 10, 11));
+		})
+	}
+
+	#[test]
+	fn api_chilled_since_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// never chilled, or never a staker at all.
+			assert!(!Staking::api_is_chilled(11));
+			assert_eq!(Staking::api_chilled_since(41), None);
+			assert_eq!(Staking::api_chilled_since(42), None);
+
+			// 11 is chilled in era 1.
+			assert_eq!(current_era(), 1);
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+			assert!(Staking::api_is_chilled(11));
+			assert_eq!(Staking::api_chilled_since(11), Some(1));
+
+			// chilling again while idle does not change it.
+			Session::roll_until_active_era(2);
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+			assert_eq!(Staking::api_chilled_since(11), Some(1));
+
+			// validating again clears it.
+			assert_ok!(Staking::validate(RuntimeOrigin::signed(11), Default::default()));
+			assert!(!Staking::api_is_chilled(11));
+			assert_eq!(Staking::api_chilled_since(11), None);
+
+			// as does nominating again.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+			assert_eq!(Staking::api_chilled_since(101), Some(current_era()));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+			assert_eq!(Staking::api_chilled_since(101), None);
+		})
+	}
+
+	#[test]
+	fn api_era_points_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(active_era(), 1);
+			assert_eq!(Staking::api_era_points(1), Some((0, vec![])));
+
+			Eras::<T>::reward_active_era(vec![(11, 100), (21, 60)]);
+			assert_eq!(Staking::api_era_points(1), Some((160, vec![(11, 100), (21, 60)])));
+
+			// not active yet.
+			assert_eq!(Staking::api_era_points(2), None);
+
+			// pruned.
+			HistoryDepth::set(3);
+			Session::roll_until_active_era(5);
+			assert_eq!(Staking::api_era_points(1), None);
+			assert_eq!(Staking::api_era_points(2), Some((0, vec![])));
+		})
+	}
+
+	#[test]
+	fn api_validator_exposure_hash_works() {
+		use sp_runtime::traits::Hash;
+		ExtBuilder::default().build_and_execute(|| {
+			let era = active_era();
+			let mut exposure = Eras::<T>::get_full_exposure(era, &11);
+			assert_eq!(exposure.others.len(), 1);
+			assert_eq!(
+				Staking::api_validator_exposure_hash(era, 11),
+				Some(<T as frame_system::Config>::Hashing::hash_of(&exposure))
+			);
+
+			// nominators are hashed sorted by account, regardless of how they are paged.
+			exposure.others.insert(0, IndividualExposure { who: 201, value: 100 });
+			exposure.others.insert(0, IndividualExposure { who: 301, value: 100 });
+			exposure.total += 200;
+			ErasStakersOverview::<T>::remove(era, &11);
+			let _ = ErasStakersPaged::<T>::clear_prefix((era, &11), u32::MAX, None);
+			Eras::<T>::upsert_exposure(era, &11, exposure.clone());
+			exposure.others.sort_by_key(|n| n.who);
+			assert_eq!(
+				Staking::api_validator_exposure_hash(era, 11),
+				Some(<T as frame_system::Config>::Hashing::hash_of(&exposure))
+			);
+
+			// not exposed, or not an era with exposures.
+			assert_eq!(Staking::api_validator_exposure_hash(era, 41), None);
+			assert_eq!(Staking::api_validator_exposure_hash(era + 10, 11), None);
+		})
+	}
+
+	#[test]
+	fn api_staking_rate_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let staked = ErasTotalStake::<T>::get(active_era());
+			assert!(!staked.is_zero());
+			assert_eq!(
+				Staking::api_staking_rate(),
+				Perquintill::from_rational(staked, asset::total_issuance::<T>())
+			);
+
+			// issuance changes are reflected right away.
+			let _ = asset::mint_creating::<T>(&1337, asset::total_issuance::<T>());
+			assert_eq!(
+				Staking::api_staking_rate(),
+				Perquintill::from_rational(staked, asset::total_issuance::<T>())
+			);
+		})
+	}
+
+	#[test]
+	fn api_min_bonds_work() {
+		ExtBuilder::default()
+			.min_nominator_bond(20)
+			.min_validator_bond(50)
+			.build_and_execute(|| {
+				assert_eq!(Staking::api_min_nominator_bond(), 20);
+				assert_eq!(Staking::api_min_validator_bond(), 50);
+
+				// never below the existential deposit.
+				ExistentialDeposit::set(30);
+				assert_eq!(Staking::api_min_nominator_bond(), 30);
+				assert_eq!(Staking::api_min_validator_bond(), 50);
+			})
+	}
+
+	#[test]
+	fn api_total_unlocking_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Staking::api_total_unlocking(11), 0);
+			// not bonded.
+			assert_eq!(Staking::api_total_unlocking(42), 0);
+
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+			Session::roll_until_active_era(2);
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 50));
+			assert_eq!(Staking::api_total_unlocking(11), 150);
+
+			// withdrawn chunks are no longer unlocking.
+			Session::roll_until_active_era(1 + BondingDuration::get());
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+			assert_eq!(Staking::api_total_unlocking(11), 50);
+		})
+	}
+
+	#[test]
+	fn api_reward_destination_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			use sp_staking::RewardDestination as Dest;
+			assert_eq!(Staking::api_reward_destination(11), Some(Dest::Staked));
+			// not bonded.
+			assert_eq!(Staking::api_reward_destination(42), None);
+
+			assert_ok!(Staking::set_payee(
+				RuntimeOrigin::signed(11),
+				RewardDestination::Account(42)
+			));
+			assert_eq!(Staking::api_reward_destination(11), Some(Dest::Account(42)));
+
+			// a not yet migrated controller destination is reported as the controller account.
+			#[allow(deprecated)]
+			Payee::<T>::insert(11, RewardDestination::Controller);
+			assert_eq!(Staking::api_reward_destination(11), Some(Dest::Account(11)));
+		})
+	}
+
+	#[test]
+	fn api_batch_lookups_work() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(
+				Staking::api_bonded_many(vec![11, 42, 101]),
+				vec![Some(11), None, Some(101)]
+			);
+			assert_eq!(Staking::api_ledger_exists_many(vec![11, 42, 101]), vec![true, false, true]);
+			assert!(Staking::api_bonded_many(vec![]).is_empty());
+
+			// lookups past the cap are ignored.
+			let many = vec![11; MAX_BATCH_LOOKUP as usize + 1];
+			assert_eq!(Staking::api_bonded_many(many.clone()).len(), MAX_BATCH_LOOKUP as usize);
+			assert_eq!(Staking::api_ledger_exists_many(many).len(), MAX_BATCH_LOOKUP as usize);
+		})
+	}
+
+	#[test]
+	fn api_validator_commissions_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let sorted_commissions = || {
+				let mut commissions = Staking::api_validator_commissions();
+				commissions.sort();
+				commissions
+			};
+			assert_eq!(
+				sorted_commissions(),
+				vec![(11, Perbill::zero(), false), (21, Perbill::zero(), false)]
+			);
+
+			// changes are only reflected once the validator is elected again.
+			assert_ok!(Staking::validate(
+				RuntimeOrigin::signed(11),
+				ValidatorPrefs {
+					commission: Perbill::from_percent(10),
+					blocked: true,
+					max_reward_per_era: None
+				}
+			));
+			assert_eq!(
+				sorted_commissions(),
+				vec![(11, Perbill::zero(), false), (21, Perbill::zero(), false)]
+			);
+
+			Session::roll_until_active_era(2);
+			assert_eq!(
+				sorted_commissions(),
+				vec![(11, Perbill::from_percent(10), true), (21, Perbill::zero(), false)]
+			);
+		})
+	}
+
+	#[test]
+	fn api_exposed_stashes_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// validators and their nominators, each listed once.
+			assert_eq!(Staking::api_exposed_stashes(1), vec![11, 21, 101]);
+			// nothing exposed in eras that are not planned yet.
+			assert!(Staking::api_exposed_stashes(3).is_empty());
+		})
+	}
+
+	#[test]
+	fn api_nominator_exposure_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// the election splits the stake of 101 across its targets.
+			let exposure = Staking::api_nominator_exposure(1, 101);
+			assert_eq!(
+				exposure,
+				vec![
+					(11, Staking::eras_stakers(1, &11).others[0].value),
+					(21, Staking::eras_stakers(1, &21).others[0].value)
+				]
+			);
+			assert_eq!(exposure.iter().map(|(_, value)| value).sum::<Balance>(), 500);
+
+			// not a nominator, or not exposed in the era.
+			assert!(Staking::api_nominator_exposure(1, 11).is_empty());
+			assert!(Staking::api_nominator_exposure(3, 101).is_empty());
+
+			// the exposure of the era is kept when the nominations change afterwards.
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![31]));
+			assert_eq!(Staking::api_nominator_exposure(1, 101), exposure);
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+			assert_eq!(Staking::api_nominator_exposure(1, 101), exposure);
+		})
+	}
+
+	#[test]
+	fn api_can_nominate_works() {
+		use sp_staking::NominateError;
+
+		ExtBuilder::default().build_and_execute(|| {
+			// re-nominating, or an idle stash nominating a validator, is fine.
+			assert_eq!(Staking::api_can_nominate(101, vec![21, 11, 11]), Ok(()));
+			assert_eq!(Staking::api_can_nominate(41, vec![31]), Ok(()));
+
+			// not bonded.
+			assert_eq!(Staking::api_can_nominate(1, vec![11]), Err(NominateError::NotStash));
+			// no targets, too many targets, or not a validator.
+			assert_eq!(Staking::api_can_nominate(101, vec![]), Err(NominateError::EmptyTargets));
+			assert_eq!(
+				Staking::api_can_nominate(101, (1..=17).collect()),
+				Err(NominateError::TooManyTargets)
+			);
+			assert_eq!(Staking::api_can_nominate(101, vec![11, 41]), Err(NominateError::BadTarget));
+
+			// only new nominators are subject to the nominator count limit.
+			MaxNominatorsCount::<T>::put(1);
+			assert_eq!(
+				Staking::api_can_nominate(41, vec![11]),
+				Err(NominateError::TooManyNominators)
+			);
+			assert_eq!(Staking::api_can_nominate(101, vec![11]), Ok(()));
+
+			// below the minimum nominator bond.
+			MinNominatorBond::<T>::put(1000);
+			assert_eq!(
+				Staking::api_can_nominate(101, vec![11]),
+				Err(NominateError::InsufficientBond)
+			);
+
+			// and the same checks fail the extrinsic.
+			assert_noop!(
+				Staking::nominate(RuntimeOrigin::signed(101), vec![11]),
+				Error::<T>::InsufficientBond
+			);
+			assert_eq!(Nominators::<T>::get(101).unwrap().targets.into_inner(), vec![11, 21]);
+		})
+	}
+
+	#[test]
+	fn api_historical_validator_set_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			HistoryDepth::set(2);
+			let sorted = |mut set: Vec<AccountId>| {
+				set.sort();
+				set
+			};
+
+			// given
+			Session::roll_until_active_era(4);
+
+			// then past and active eras within the history depth are known.
+			assert_eq!(Staking::api_historical_validator_set(2).map(sorted), Some(vec![11, 21]));
+			assert_eq!(Staking::api_historical_validator_set(4).map(sorted), Some(vec![11, 21]));
+
+			// but not pruned, or not yet planned eras.
+			assert_eq!(Staking::api_historical_validator_set(1), None);
+			assert_eq!(Staking::api_historical_validator_set(6), None);
+		})
+	}
+
+	#[test]
+	fn api_page_claim_status_works() {
+		ExtBuilder::default().exposures_page_size(1).build_and_execute(|| {
+			// given 11 with two nominators, hence two pages, in era 3.
+			bond_nominator(301, 500, vec![11]);
+			bond_nominator(302, 500, vec![11]);
+			Session::roll_until_active_era(3);
+			Staking::reward_by_ids(vec![(11, 1)]);
+			Session::roll_until_active_era(4);
+			assert_eq!(Staking::api_page_claim_status(3, 11), vec![(0, false), (1, false)]);
+
+			// when
+			assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 3, 0));
+
+			// then
+			assert_eq!(Staking::api_page_claim_status(3, 11), vec![(0, true), (1, false)]);
+
+			// not exposed in the era.
+			assert!(Staking::api_page_claim_status(3, 31).is_empty());
+		})
+	}
+
+	#[test]
+	fn api_total_bonded_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let sum = || Ledger::<Test>::iter_values().map(|l| l.total).sum::<Balance>();
+			let initial = Staking::api_total_bonded();
+			assert_eq!(initial, sum());
+
+			// bond and bond extra.
+			bond_nominator(301, 500, vec![11]);
+			asset::set_stakeable_balance::<Test>(&301, 1000);
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(301), 100));
+			assert_eq!(Staking::api_total_bonded(), initial + 600);
+
+			// unbonding funds are still bonded until withdrawn.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(301)));
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(301), 600));
+			assert_eq!(Staking::api_total_bonded(), initial + 600);
+
+			Session::roll_until_active_era(1 + BondingDuration::get());
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(301), 0));
+			assert_eq!(Staking::api_total_bonded(), initial);
+
+			// slashes reduce it as well.
+			add_slash(11);
+			Session::roll_next();
+			assert!(Staking::api_total_bonded() < initial);
+			assert_eq!(Staking::api_total_bonded(), sum());
+		})
+	}
+
+	#[test]
+	fn api_era_reward_breakdown_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// 11 and 101 would be paid 7500 in total, capped to 5000 before the 10% commission.
+			Eras::<T>::reward_active_era(vec![(11, 1)]);
+			Eras::<T>::set_validator_prefs(
+				1,
+				&11,
+				ValidatorPrefs {
+					commission: Perbill::from_percent(10),
+					max_reward_per_era: Some(5000),
+					..Default::default()
+				},
+			);
+			// and 101 has no reward destination.
+			assert_ok!(Staking::set_payee(RuntimeOrigin::signed(101), RewardDestination::None));
+
+			// not ended yet.
+			assert_eq!(Staking::api_era_reward_breakdown(1), None);
+
+			Session::roll_until_active_era(2);
+			let Some(Event::EraPaid { era_index: 1, validator_payout, remainder }) =
+				staking_events()
+					.into_iter()
+					.find(|e| matches!(e, Event::EraPaid { era_index: 1, .. }))
+			else {
+				panic!("era 1 was paid");
+			};
+			assert_eq!(validator_payout, 7500);
+			assert_eq!(
+				Staking::api_era_reward_breakdown(1),
+				Some(RewardBreakdown { stakers: 7500, remainder, burned: 0 })
+			);
+
+			// 11 gets 4100, the 2500 above the cap goes to the remainder and the 900 of 101 is
+			// never minted.
+			mock::make_all_reward_payment(1);
+			assert_eq!(
+				Staking::api_era_reward_breakdown(1),
+				Some(RewardBreakdown { stakers: 4100, remainder: remainder + 2500, burned: 900 })
+			);
+
+			// pruned along with the rest of the era.
+			Session::roll_until_active_era(HistoryDepth::get() + 2);
+			assert_eq!(Staking::api_era_reward_breakdown(1), None);
+		})
+	}
+
+	#[test]
+	fn api_pending_slashes_works() {
+		ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+			assert_eq!(Staking::api_pending_slashes(11), vec![]);
+
+			// deferred to start of era 3.
+			add_slash(11);
+			Session::roll_next();
+			assert_eq!(Staking::api_pending_slashes(11), vec![(3, 100)]);
+			assert_eq!(Staking::api_pending_slashes(101), vec![(3, 25)]);
+			assert_eq!(Staking::api_pending_slashes(21), vec![]);
+
+			// a second offence applied in the same era is summed up.
+			Session::roll_until_active_era(2);
+			add_slash_in_era(11, 1, Perbill::from_percent(15));
+			Session::roll_next();
+			assert_eq!(Staking::api_pending_slashes(11), vec![(3, 150)]);
+			assert_eq!(Staking::api_pending_slashes(101), vec![(3, 37)]);
+
+			// nothing is pending once the slashes are applied, one per block.
+			Session::roll_until_active_era(3);
+			Session::roll_next();
+			Session::roll_next();
+			assert_eq!(Staking::api_pending_slashes(11), vec![]);
+			assert_eq!(Staking::api_pending_slashes(101), vec![]);
+		})
+	}
+
+	#[test]
+	fn api_slashes_applied_in_works() {
+		ExtBuilder::default()
+			.slash_defer_duration(2)
+			.validator_count(3)
+			.build_and_execute(|| {
+				HistoryDepth::set(2);
+
+				// given three slashes deferred to era 3, one of which is cancelled.
+				add_slash(11);
+				add_slash(21);
+				add_slash(31);
+				Session::roll_next();
+				assert_ok!(Staking::cancel_deferred_slash(
+					RuntimeOrigin::root(),
+					3,
+					vec![(21, Perbill::from_percent(10))]
+				));
+
+				// then nothing is applied until era 3 starts.
+				Session::roll_until_active_era(3);
+				assert_eq!(Staking::api_slashes_applied_in(3), 0);
+
+				// and the cancelled slash is not counted once all are processed, one per block.
+				Session::roll_next();
+				Session::roll_next();
+				Session::roll_next();
+				assert_eq!(UnappliedSlashes::<T>::iter_prefix(3).count(), 0);
+				assert_eq!(Staking::api_slashes_applied_in(3), 2);
+				assert_eq!(Staking::api_slashes_applied_in(2), 0);
+
+				// the count is pruned along with the era.
+				Session::roll_until_active_era(6);
+				assert_eq!(Staking::api_slashes_applied_in(3), 0);
+			})
+	}
+
+	#[test]
+	fn api_unapplied_slashes_works() {
+		ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+			assert_eq!(Staking::api_unapplied_slashes(3), vec![]);
+
+			// deferred to start of era 3.
+			add_slash(11);
+			Session::roll_next();
+			assert_eq!(
+				Staking::api_unapplied_slashes(3),
+				vec![sp_staking::UnappliedSlash {
+					validator: 11,
+					own: 100,
+					others: vec![(101, 25)],
+					reporters: vec![],
+					payout: 12,
+				}]
+			);
+			assert_eq!(Staking::api_unapplied_slashes(2), vec![]);
+
+			// nothing is returned once the slash is applied.
+			Session::roll_until_active_era(3);
+			Session::roll_next();
+			assert_eq!(Staking::api_unapplied_slashes(3), vec![]);
+		})
+	}
+
+	#[test]
+	fn api_exposure_overview_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(
+				Staking::api_exposure_overview(1, 11),
+				Some(PagedExposureMetadata {
+					total: 1250,
+					own: 1000,
+					nominator_count: 1,
+					page_count: 1
+				})
+			);
+
+			// 31 is a validator, but was not elected.
+			assert_eq!(Staking::api_exposure_overview(1, 31), None);
+			assert_eq!(Staking::api_exposure_overview(2, 11), None);
+		})
+	}
+
+	#[test]
+	fn api_eras_stakers_clipped_works() {
+		ExtBuilder::default().exposures_page_size(1).build_and_execute(|| {
+			// given 11 with two nominators, hence two pages, in era 3.
+			bond_nominator(301, 500, vec![11]);
+			bond_nominator(302, 500, vec![11]);
+			Session::roll_until_active_era(3);
+			let overview = Staking::api_exposure_overview(3, 11).unwrap();
+			assert_eq!(overview.page_count, 2);
+
+			// synthesized from the first page, but with the total of all pages.
+			let clipped = Staking::api_eras_stakers_clipped(3, 11).unwrap();
+			assert_eq!((clipped.total, clipped.own), (overview.total, overview.own));
+			assert_eq!(clipped.others, ErasStakersPaged::<T>::get((3, 11, 0)).unwrap().others);
+			assert_eq!(clipped.others.len(), 1);
+
+			// not exposed in the era.
+			assert_eq!(Staking::api_eras_stakers_clipped(3, 31), None);
+			assert_eq!(Staking::api_eras_stakers_clipped(4, 11), None);
+		})
+	}
+
+	#[test]
+	fn api_exposure_page_works() {
+		ExtBuilder::default().exposures_page_size(1).build_and_execute(|| {
+			// given 11 with two nominators, hence two pages, in era 3.
+			bond_nominator(301, 500, vec![11]);
+			bond_nominator(302, 500, vec![11]);
+			Session::roll_until_active_era(3);
+			let overview = Staking::api_exposure_overview(3, 11).unwrap();
+			assert_eq!(overview.page_count, 2);
+
+			// the own stake is only part of the first page.
+			let (metadata, page) = Staking::api_exposure_page(3, 11, 0).unwrap();
+			assert_eq!(metadata, overview);
+			assert_eq!(page, ErasStakersPaged::<T>::get((3, 11, 0)).unwrap().into());
+			let (metadata, page) = Staking::api_exposure_page(3, 11, 1).unwrap();
+			assert_eq!(metadata, PagedExposureMetadata { own: 0, ..overview });
+			assert_eq!(page, ErasStakersPaged::<T>::get((3, 11, 1)).unwrap().into());
+
+			// past the last page, or not exposed.
+			assert_eq!(Staking::api_exposure_page(3, 11, 2), None);
+			assert_eq!(Staking::api_exposure_page(3, 31, 0), None);
+		})
+	}
+
+	#[test]
+	fn api_targets_of_and_backers_of_work() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Staking::api_targets_of(101), vec![11, 21]);
+			assert_eq!(Staking::api_backers_of(11), vec![101]);
+			assert_eq!(Staking::api_backers_of(21), vec![101]);
+			assert!(Staking::api_backers_of(31).is_empty());
+			assert!(Staking::api_targets_of(11).is_empty());
+
+			// re-nominating moves the backing.
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![21, 31]));
+			assert_eq!(Staking::api_targets_of(101), vec![21, 31]);
+			assert!(Staking::api_backers_of(11).is_empty());
+			assert_eq!(Staking::api_backers_of(21), vec![101]);
+			assert_eq!(Staking::api_backers_of(31), vec![101]);
+
+			// kicking removes a single edge.
+			assert_ok!(Staking::kick(RuntimeOrigin::signed(31), vec![101]));
+			assert_eq!(Staking::api_targets_of(101), vec![21]);
+			assert!(Staking::api_backers_of(31).is_empty());
+
+			// chilling removes them all.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+			assert!(Staking::api_targets_of(101).is_empty());
+			assert!(Staking::api_backers_of(21).is_empty());
+		})
+	}
+
+	#[test]
+	fn api_nomination_age_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// validators and unknown accounts are not nominators.
+			assert_eq!(Staking::api_nomination_age(11), None);
+			assert_eq!(Staking::api_nomination_age(1337), None);
+
+			// genesis nominations are submitted in era 0.
+			assert_eq!(current_era(), 1);
+			assert_eq!(Staking::api_nomination_age(101), Some(1));
+			Session::roll_until_active_era(3);
+			assert_eq!(Staking::api_nomination_age(101), Some(current_era()));
+
+			// re-nominating resets the age.
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+			assert_eq!(Staking::api_nomination_age(101), Some(0));
+
+			Session::roll_until_active_era(4);
+			assert_eq!(Staking::api_nomination_age(101), Some(1));
+
+			// chilling removes the nominations.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+			assert_eq!(Staking::api_nomination_age(101), None);
+		})
+	}
+
+	#[test]
+	fn api_unbonding_schedule_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// nothing unbonding, or not bonded.
+			assert!(Staking::api_unbonding_schedule(11).is_empty());
+			assert!(Staking::api_unbonding_schedule(1).is_empty());
+
+			// when unbonding in two different eras.
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+			Session::roll_until_active_era(2);
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 50));
+
+			// then
+			assert_eq!(Staking::api_unbonding_schedule(11), vec![(4, 100), (5, 50)]);
+		})
+	}
+
+	#[test]
+	fn api_fast_unstake_eligible_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// idle and never exposed.
+			assert!(Staking::api_fast_unstake_eligible(41));
+			// not bonded.
+			assert!(!Staking::api_fast_unstake_eligible(42));
+			// validators and nominators are not chilled.
+			assert!(!Staking::api_fast_unstake_eligible(11));
+			assert!(!Staking::api_fast_unstake_eligible(101));
+
+			// chilled, but still exposed in a recent era.
+			assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+			assert!(!Staking::api_fast_unstake_eligible(101));
+
+			// funds unlocking.
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(41), 100));
+			assert!(!Staking::api_fast_unstake_eligible(41));
+		})
+	}
 }
 
 mod staking_unchecked {
@@ -522,7 +1275,12 @@ mod staking_unchecked {
 					stash: 10,
 					total: 1100,
 					active: 1100 - 200,
-					unlocking: bounded_vec![UnlockChunk { value: 200, era: 1 + 3 }],
+					unlocking: bounded_vec![UnlockChunk {
+						value: 200,
+						era: 1 + 3,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1253,3 +2011,235 @@ fn slash_kicks_validators_not_nominators_and_disables_nominator_for_kicked_valid
 		});
 }
 */
+
+mod migrations {
+	use super::*;
+	use crate::migrations::{v18, v19, v20, v21, v22, v23};
+	use frame_support::{
+		migrations::SteppedMigration,
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	#[test]
+	fn migrate_v17_to_v18_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given all prefs in the v17 encoding.
+			StorageVersion::new(17).put::<Staking>();
+			let old = |prefs: ValidatorPrefs<Balance>| v18::OldValidatorPrefs {
+				commission: prefs.commission,
+				blocked: prefs.blocked,
+			};
+			for (who, prefs) in Validators::<Test>::iter().collect::<Vec<_>>() {
+				unhashed::put(&Validators::<Test>::hashed_key_for(who), &old(prefs));
+			}
+			for (era, who, prefs) in ErasValidatorPrefs::<Test>::iter().collect::<Vec<_>>() {
+				unhashed::put(&ErasValidatorPrefs::<Test>::hashed_key_for(era, who), &old(prefs));
+			}
+			unhashed::put(
+				&Validators::<Test>::hashed_key_for(11),
+				&v18::OldValidatorPrefs { commission: Perbill::from_percent(5), blocked: true },
+			);
+			let validators = Validators::<Test>::count();
+
+			// when
+			v18::MigrateV17ToV18::<Test>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 18);
+			assert_eq!(Validators::<Test>::count(), validators);
+			assert_eq!(
+				Validators::<Test>::get(11),
+				ValidatorPrefs {
+					commission: Perbill::from_percent(5),
+					blocked: true,
+					max_reward_per_era: None
+				}
+			);
+			assert_eq!(ErasValidatorPrefs::<Test>::get(1, 21), ValidatorPrefs::default());
+		})
+	}
+
+	#[test]
+	fn migrate_v18_to_v19_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given an offence and an unapplied slash in the v18 encoding.
+			StorageVersion::new(18).put::<Staking>();
+			let old_record = |reporter: Option<AccountId>| v19::OldOffenceRecord {
+				reporter,
+				reported_era: 1,
+				exposure_page: 0,
+				slash_fraction: Perbill::from_percent(10),
+				prior_slash_fraction: Perbill::zero(),
+			};
+			unhashed::put(&OffenceQueue::<Test>::hashed_key_for(1, 11), &old_record(Some(1)));
+			unhashed::put(&OffenceQueue::<Test>::hashed_key_for(1, 21), &old_record(None));
+			let processing: (EraIndex, AccountId, _) = (1, 31, old_record(Some(2)));
+			unhashed::put(&ProcessingOffence::<Test>::hashed_key(), &processing);
+			let key = (11, Perbill::from_percent(10), 0);
+			unhashed::put(
+				&UnappliedSlashes::<Test>::hashed_key_for(3, key),
+				&v19::OldUnappliedSlash::<Test> {
+					validator: 11,
+					own: 100,
+					others: bounded_vec![(101, 25)],
+					reporter: Some(1),
+					payout: 12,
+				},
+			);
+
+			// when
+			v19::MigrateV18ToV19::<Test>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 19);
+			assert_eq!(OffenceQueue::<Test>::get(1, 11).unwrap().reporters.into_inner(), vec![1]);
+			assert!(OffenceQueue::<Test>::get(1, 21).unwrap().reporters.is_empty());
+			assert_eq!(ProcessingOffence::<Test>::get().unwrap().2.reporters.into_inner(), vec![2]);
+			assert_eq!(
+				UnappliedSlashes::<Test>::get(3, key).unwrap(),
+				UnappliedSlash {
+					validator: 11,
+					own: 100,
+					others: bounded_vec![(101, 25)],
+					reporters: bounded_vec![1],
+					payout: 12,
+				}
+			);
+		})
+	}
+
+	#[test]
+	fn migrate_v19_to_v20_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given nominations without a backers index.
+			StorageVersion::new(19).put::<Staking>();
+			let _ = Backers::<Test>::clear(u32::MAX, None);
+			let _ = BackersCount::<Test>::clear(u32::MAX, None);
+			assert!(Staking::api_backers_of(11).is_empty());
+
+			// when the version is bumped, and the index is built one nominator per step.
+			v20::MigrateV19ToV20::<Test>::on_runtime_upgrade();
+			let step = <Test as Config>::WeightInfo::migration_v20_step();
+			let mut cursor = None;
+			let mut steps = 0;
+			loop {
+				let mut meter = WeightMeter::with_limit(step);
+				cursor = v20::LazyMigrationV19ToV20::<Test>::step(cursor, &mut meter).unwrap();
+				steps += 1;
+				if cursor.is_none() {
+					break
+				}
+			}
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 20);
+			assert_eq!(steps, Nominators::<Test>::count() + 1);
+			assert_ok!(Staking::check_backers());
+			assert_eq!(Staking::api_backers_of(11), vec![101]);
+			assert_eq!(Staking::api_backers_of(21), vec![101]);
+			assert!(Staking::api_backers_of(31).is_empty());
+		})
+	}
+
+	#[test]
+	fn migrate_v20_to_v21_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a ledger with unlocking chunks in the v20 encoding.
+			StorageVersion::new(20).put::<Staking>();
+			assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+			let ledger = Ledger::<Test>::get(11).unwrap();
+			unhashed::put(
+				&Ledger::<Test>::hashed_key_for(11),
+				&v21::OldStakingLedger::<Test> {
+					stash: ledger.stash,
+					total: ledger.total,
+					active: ledger.active,
+					unlocking: bounded_vec![v21::OldUnlockChunk { value: 100, era: 4 }],
+				},
+			);
+
+			// when
+			v21::MigrateV20ToV21::<Test>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 21);
+			assert_eq!(
+				unhashed::get::<v22::OldStakingLedger<Test>>(&Ledger::<Test>::hashed_key_for(11))
+					.unwrap()
+					.unlocking
+					.into_inner(),
+				vec![v22::OldUnlockChunk { value: 100, era: 4, dest: None }],
+			);
+
+			// and the ledger decodes again once migrated to v22.
+			v22::MigrateV21ToV22::<Test>::on_runtime_upgrade();
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap().unlocking.into_inner(),
+				vec![UnlockChunk { value: 100, era: 4, dest: None, withdrawable_era: None }],
+			);
+		})
+	}
+
+	#[test]
+	fn migrate_v21_to_v22_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a ledger with unlocking chunks in the v21 encoding.
+			StorageVersion::new(21).put::<Staking>();
+			assert_ok!(Staking::unbond_to(RuntimeOrigin::signed(11), 100, 777));
+			let ledger = Ledger::<Test>::get(11).unwrap();
+			unhashed::put(
+				&Ledger::<Test>::hashed_key_for(11),
+				&v22::OldStakingLedger::<Test> {
+					stash: ledger.stash,
+					total: ledger.total,
+					active: ledger.active,
+					unlocking: bounded_vec![v22::OldUnlockChunk {
+						value: 100,
+						era: 4,
+						dest: Some(777)
+					}],
+				},
+			);
+
+			// when
+			v22::MigrateV21ToV22::<Test>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 22);
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap(),
+				StakingLedgerInspect {
+					stash: 11,
+					total: 1000,
+					active: 900,
+					unlocking: bounded_vec![UnlockChunk {
+						value: 100,
+						era: 4,
+						dest: Some(777),
+						withdrawable_era: None
+					}],
+				},
+			);
+		})
+	}
+
+	#[test]
+	fn migrate_v22_to_v23_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given bonds that are not reflected in `TotalBonded`.
+			StorageVersion::new(22).put::<Staking>();
+			let total = TotalBonded::<Test>::take();
+			assert!(!total.is_zero());
+
+			// when
+			v23::MigrateV22ToV23::<Test>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(StorageVersion::get::<Staking>(), 23);
+			assert_eq!(TotalBonded::<Test>::get(), total);
+			assert_eq!(Staking::api_total_bonded(), total);
+		})
+	}
+}
@@ -18,7 +18,8 @@
 use super::*;
 use crate::session_rotation::Eras;
 use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo, WithPostDispatchInfo};
-use sp_runtime::{bounded_btree_map, traits::Dispatchable};
+use sp_runtime::{bounded_btree_map, traits::Dispatchable, FixedU128};
+use sp_staking::PayoutDryRun;
 
 #[test]
 fn rewards_with_nominator_should_work() {
@@ -342,6 +343,196 @@ fn reward_destination_staked() {
 	});
 }
 
+#[test]
+fn auto_compound_unlocking_rebonds_fraction_of_unlocking_on_payout() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_eq!(Staking::payee(11.into()), Some(RewardDestination::Staked));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 400));
+		assert_ok!(Staking::set_auto_compound_unlocking(
+			RuntimeOrigin::signed(11),
+			Some(Perbill::from_percent(50))
+		));
+		assert_eq!(AutoCompoundUnlocking::<T>::get(11), Some(Perbill::from_percent(50)));
+
+		// reward era 1 and payout at era 2
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		mock::make_all_reward_payment(1);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None },
+				Event::Bonded { stash: 11, amount: 200 },
+				Event::Rewarded { stash: 11, dest: RewardDestination::Staked, amount: 7500 }
+			]
+		);
+
+		// the reward and half of the unlocking chunk are bonded.
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 8500,
+				active: 600 + 7500 + 200,
+				unlocking: bounded_vec![UnlockChunk {
+					value: 200,
+					era: 4,
+					dest: None,
+					withdrawable_era: None
+				}],
+			}
+		);
+
+		// opting out stops compounding.
+		assert_ok!(Staking::set_auto_compound_unlocking(RuntimeOrigin::signed(11), None));
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(3);
+		mock::make_all_reward_payment(2);
+		assert_eq!(Staking::ledger(11.into()).unwrap().unlocking.into_inner()[0].value, 200);
+	});
+}
+
+#[test]
+fn auto_compound_unlocking_rebonds_once_per_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 101 nominates both 11 and 21, so is paid twice per era.
+		assert_eq!(Staking::payee(101.into()), Some(RewardDestination::Staked));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(101), 400));
+		assert_ok!(Staking::set_auto_compound_unlocking(
+			RuntimeOrigin::signed(101),
+			Some(Perbill::from_percent(50))
+		));
+		let compounded = || {
+			staking_events_since_last_call()
+				.into_iter()
+				.filter(|e| matches!(e, Event::Bonded { stash: 101, .. }))
+				.collect::<Vec<_>>()
+		};
+
+		Eras::<T>::reward_active_era(vec![(11, 1), (21, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		mock::make_all_reward_payment(1);
+		assert_eq!(compounded(), vec![Event::Bonded { stash: 101, amount: 200 }]);
+		assert_eq!(AutoCompoundedEra::<T>::get(101), Some(1));
+		assert_eq!(Staking::ledger(101.into()).unwrap().unlocking.into_inner()[0].value, 200);
+
+		// the next era compounds again, once.
+		Eras::<T>::reward_active_era(vec![(11, 1), (21, 1)]);
+		Session::roll_until_active_era(3);
+		let _ = staking_events_since_last_call();
+
+		mock::make_all_reward_payment(2);
+		assert_eq!(compounded(), vec![Event::Bonded { stash: 101, amount: 100 }]);
+		assert_eq!(AutoCompoundedEra::<T>::get(101), Some(2));
+		assert_eq!(Staking::ledger(101.into()).unwrap().unlocking.into_inner()[0].value, 100);
+
+		// opting out clears the record.
+		assert_ok!(Staking::set_auto_compound_unlocking(RuntimeOrigin::signed(101), None));
+		assert!(!AutoCompoundedEra::<T>::contains_key(101));
+	});
+}
+
+#[test]
+fn auto_compound_unlocking_skips_unbond_to_and_emergency_chunks() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 200));
+		assert_ok!(Staking::unbond_to(RuntimeOrigin::signed(11), 100, 7));
+		assert_ok!(Staking::set_emergency_bonding_duration(RuntimeOrigin::root(), Some(1)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 50));
+		let chunks = |ledger: StakingLedger<T>| {
+			ledger
+				.unlocking
+				.into_iter()
+				.map(|chunk| (chunk.value, chunk.dest, chunk.withdrawable_era.is_some()))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			chunks(Staking::ledger(11.into()).unwrap()),
+			vec![(200, None, false), (100, Some(7), false), (50, None, true)]
+		);
+
+		assert_ok!(Staking::set_auto_compound_unlocking(
+			RuntimeOrigin::signed(11),
+			Some(Perbill::from_percent(100))
+		));
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		mock::make_all_reward_payment(1);
+		assert!(
+			staking_events_since_last_call().contains(&Event::Bonded { stash: 11, amount: 200 })
+		);
+
+		// only the chunk that returns to the stash at the full bonding duration is rebonded.
+		let ledger = Staking::ledger(11.into()).unwrap();
+		assert_eq!(ledger.active, 1000 - 350 + 7500 + 200);
+		assert_eq!(chunks(ledger), vec![(100, Some(7), false), (50, None, true)]);
+	});
+}
+
+#[test]
+fn auto_compound_unlocking_only_rebonds_what_is_left_after_slash() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 400));
+		assert_ok!(Staking::set_auto_compound_unlocking(
+			RuntimeOrigin::signed(11),
+			Some(Perbill::from_percent(100))
+		));
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+
+		// slash of era 1 hits the unlocking chunk as well.
+		add_slash(11);
+		Session::roll_next();
+		let slashed = Staking::ledger(11.into()).unwrap();
+		let unlocking_left = slashed.unlocking.iter().map(|c| c.value).sum::<Balance>();
+		assert!(unlocking_left < 400);
+
+		Session::roll_until_active_era(2);
+		mock::make_all_reward_payment(1);
+
+		// all that is left unlocking is rebonded, nothing more.
+		let ledger = Staking::ledger(11.into()).unwrap();
+		assert!(ledger.unlocking.is_empty());
+		assert_eq!(ledger.total, slashed.total + 7500);
+		assert_eq!(ledger.active, slashed.active + 7500 + unlocking_left);
+	});
+}
+
+#[test]
+fn auto_compound_unlocking_respects_config() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 400));
+		assert_ok!(Staking::set_auto_compound_unlocking(
+			RuntimeOrigin::signed(11),
+			Some(Perbill::from_percent(50))
+		));
+
+		// cannot opt in once disallowed, but can always opt out.
+		AllowAutoCompoundUnlocking::set(false);
+		assert_noop!(
+			Staking::set_auto_compound_unlocking(
+				RuntimeOrigin::signed(11),
+				Some(Perbill::from_percent(10))
+			),
+			Error::<T>::AutoCompoundUnlockingNotAllowed
+		);
+
+		// existing opt-ins are not honoured either.
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		mock::make_all_reward_payment(1);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 600 + 7500);
+
+		assert_ok!(Staking::set_auto_compound_unlocking(RuntimeOrigin::signed(11), None));
+		assert!(!AutoCompoundUnlocking::<T>::contains_key(11));
+	});
+}
+
 #[test]
 fn reward_to_stake_works() {
 	ExtBuilder::default()
@@ -500,6 +691,116 @@ fn reward_destination_account() {
 	});
 }
 
+#[test]
+fn dead_payee_reward_is_burned_by_default() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(7)));
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		// the reward of 7500 can no longer create 7.
+		ExistentialDeposit::set(8000);
+		let remainder_before = mock::RewardRemainderUnbalanced::get();
+		let breakdown_before = ErasRewardBreakdown::<T>::get(1).unwrap();
+
+		mock::make_all_reward_payment(1);
+		assert_eq!(ClaimedRewards::<T>::get(1, &11), vec![0]);
+		// no reward is reported, as nothing is paid out.
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None }]
+		);
+		assert!(RecentRewards::<T>::get(&11).is_empty());
+
+		assert_eq!(asset::total_balance::<T>(&7), 0);
+		assert_eq!(asset::total_balance::<T>(&11), 1001);
+		assert_eq!(mock::RewardRemainderUnbalanced::get(), remainder_before);
+
+		// and it is accounted for as burned.
+		let breakdown = ErasRewardBreakdown::<T>::get(1).unwrap();
+		assert_eq!(breakdown.stakers, breakdown_before.stakers - 7500);
+		assert_eq!(breakdown.remainder, breakdown_before.remainder);
+		assert_eq!(breakdown.burned, breakdown_before.burned + 7500);
+	});
+}
+
+#[test]
+fn dead_payee_reward_can_go_to_stash() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		DeadPayee::set(DeadPayeeBehavior::ToStash);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(7)));
+		// enough free balance for the stash to stay above the raised ED below.
+		asset::set_stakeable_balance::<T>(&11, 2000);
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		ExistentialDeposit::set(8000);
+		mock::make_all_reward_payment(1);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None },
+				Event::Rewarded { stash: 11, dest: RewardDestination::Stash, amount: 7500 }
+			]
+		);
+
+		// paid into the stash, but not bonded.
+		assert_eq!(asset::total_balance::<T>(&7), 0);
+		assert_eq!(asset::total_balance::<T>(&11), 2001 + 7500);
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 1000);
+	});
+}
+
+#[test]
+fn dead_payee_reward_can_go_to_remainder() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		DeadPayee::set(DeadPayeeBehavior::ToRemainder);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(7)));
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		ExistentialDeposit::set(8000);
+		let remainder_before = mock::RewardRemainderUnbalanced::get();
+		let breakdown_before = ErasRewardBreakdown::<T>::get(1).unwrap();
+
+		mock::make_all_reward_payment(1);
+		assert_eq!(ClaimedRewards::<T>::get(1, &11), vec![0]);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None }]
+		);
+
+		assert_eq!(asset::total_balance::<T>(&7), 0);
+		assert_eq!(asset::total_balance::<T>(&11), 1001);
+		assert_eq!(mock::RewardRemainderUnbalanced::get(), remainder_before + 7500);
+
+		// and it is accounted for as such.
+		let breakdown = ErasRewardBreakdown::<T>::get(1).unwrap();
+		assert_eq!(breakdown.stakers, breakdown_before.stakers - 7500);
+		assert_eq!(breakdown.remainder, breakdown_before.remainder + 7500);
+		assert_eq!(breakdown.burned, breakdown_before.burned);
+	});
+}
+
+#[test]
+fn dead_payee_behavior_does_not_apply_to_existing_accounts() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		DeadPayee::set(DeadPayeeBehavior::ToRemainder);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Account(7)));
+		asset::set_stakeable_balance::<T>(&7, 1000);
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+
+		ExistentialDeposit::set(8000);
+		mock::make_all_reward_payment(1);
+		assert_eq!(asset::total_balance::<T>(&7), 1001 + 7500);
+	});
+}
+
 #[test]
 fn validator_prefs_no_commission() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -521,6 +822,99 @@ fn validator_prefs_no_commission() {
 	});
 }
 
+#[test]
+fn validator_prefs_max_reward_per_era_caps_payout() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 and 101 would be paid 7500 in total, capped to 5000 before the 10% commission.
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Eras::<T>::set_validator_prefs(
+			1,
+			&11,
+			ValidatorPrefs {
+				commission: Perbill::from_percent(10),
+				max_reward_per_era: Some(5000),
+				..Default::default()
+			},
+		);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+		let remainder_before = mock::RewardRemainderUnbalanced::get();
+
+		mock::make_all_reward_payment(1);
+
+		// 500 commission, and the remaining 4500 split 80/20.
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None },
+				Event::Rewarded { stash: 11, dest: RewardDestination::Staked, amount: 4100 },
+				Event::Rewarded { stash: 101, dest: RewardDestination::Staked, amount: 900 }
+			]
+		);
+		// the excess goes to the reward remainder.
+		assert_eq!(mock::RewardRemainderUnbalanced::get(), remainder_before + 2500);
+	});
+}
+
+#[test]
+fn validator_prefs_max_reward_per_era_above_payout_is_noop() {
+	ExtBuilder::default().build_and_execute(|| {
+		Eras::<T>::reward_active_era(vec![(11, 1)]);
+		Eras::<T>::set_validator_prefs(
+			1,
+			&11,
+			ValidatorPrefs { max_reward_per_era: Some(10_000), ..Default::default() },
+		);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+		let remainder_before = mock::RewardRemainderUnbalanced::get();
+
+		mock::make_all_reward_payment(1);
+
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None },
+				Event::Rewarded { stash: 11, dest: RewardDestination::Staked, amount: 6000 },
+				Event::Rewarded { stash: 101, dest: RewardDestination::Staked, amount: 1500 }
+			]
+		);
+		assert_eq!(mock::RewardRemainderUnbalanced::get(), remainder_before);
+	});
+}
+
+#[test]
+fn validators_below_min_era_points_are_not_rewarded() {
+	ExtBuilder::default().build_and_execute(|| {
+		MinEraPointsForReward::set(5);
+		// 11 is just above the threshold and gets 60% of the 7500 era payout, 21 is just below.
+		Eras::<T>::reward_active_era(vec![(11, 6), (21, 4)]);
+		Session::roll_until_active_era(2);
+		let _ = staking_events_since_last_call();
+		let remainder_before = mock::RewardRemainderUnbalanced::get();
+		let breakdown_before = ErasRewardBreakdown::<T>::get(1).unwrap().remainder;
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 21, 1));
+
+		// 4500 is split 80/20 between 11 and 101, and nothing is paid out for 21.
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::PayoutStarted { era_index: 1, validator_stash: 11, page: 0, next: None },
+				Event::Rewarded { stash: 11, dest: RewardDestination::Staked, amount: 3600 },
+				Event::Rewarded { stash: 101, dest: RewardDestination::Staked, amount: 900 },
+				Event::PayoutStarted { era_index: 1, validator_stash: 21, page: 0, next: None },
+			]
+		);
+		// the share of 21 goes to the reward remainder instead.
+		assert_eq!(mock::RewardRemainderUnbalanced::get(), remainder_before + 3000);
+		assert_eq!(ErasRewardBreakdown::<T>::get(1).unwrap().remainder, breakdown_before + 3000);
+		assert_eq!(Staking::api_estimate_validator_era_reward(1, 21), None);
+		assert_eq!(Staking::api_estimate_validator_era_reward(1, 11), Some(4500));
+	});
+}
+
 #[test]
 fn validator_prefs_100_commission() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -572,7 +966,11 @@ fn min_commission_works() {
 		// account 11 controls the stash of itself.
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+			ValidatorPrefs {
+				commission: Perbill::from_percent(5),
+				blocked: false,
+				max_reward_per_era: None
+			}
 		));
 
 		// event emitted should be correct
@@ -580,7 +978,11 @@ fn min_commission_works() {
 			*staking_events().last().unwrap(),
 			Event::ValidatorPrefsSet {
 				stash: 11,
-				prefs: ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+				prefs: ValidatorPrefs {
+					commission: Perbill::from_percent(5),
+					blocked: false,
+					max_reward_per_era: None
+				}
 			}
 		);
 
@@ -599,7 +1001,11 @@ fn min_commission_works() {
 		assert_noop!(
 			Staking::validate(
 				RuntimeOrigin::signed(11),
-				ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+				ValidatorPrefs {
+					commission: Perbill::from_percent(5),
+					blocked: false,
+					max_reward_per_era: None
+				}
 			),
 			Error::<T>::CommissionTooLow
 		);
@@ -607,12 +1013,20 @@ fn min_commission_works() {
 		// can only change to higher.
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false }
+			ValidatorPrefs {
+				commission: Perbill::from_percent(10),
+				blocked: false,
+				max_reward_per_era: None
+			}
 		));
 
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			ValidatorPrefs {
+				commission: Perbill::from_percent(15),
+				blocked: false,
+				max_reward_per_era: None
+			}
 		));
 	})
 }
@@ -644,7 +1058,11 @@ fn set_min_commission_works_with_admin_origin() {
 		assert_noop!(
 			Staking::validate(
 				RuntimeOrigin::signed(11),
-				ValidatorPrefs { commission: Perbill::from_percent(14), blocked: false }
+				ValidatorPrefs {
+					commission: Perbill::from_percent(14),
+					blocked: false,
+					max_reward_per_era: None
+				}
 			),
 			Error::<T>::CommissionTooLow
 		);
@@ -652,14 +1070,22 @@ fn set_min_commission_works_with_admin_origin() {
 		// setting commission >= min_commission works
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			ValidatorPrefs {
+				commission: Perbill::from_percent(15),
+				blocked: false,
+				max_reward_per_era: None
+			}
 		));
 	})
 }
 
 #[test]
 fn force_apply_min_commission_works() {
-	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), blocked: false };
+	let prefs = |c| ValidatorPrefs {
+		commission: Perbill::from_percent(c),
+		blocked: false,
+		max_reward_per_era: None,
+	};
 	let validators = || Validators::<T>::iter().collect::<Vec<_>>();
 	ExtBuilder::default().build_and_execute(|| {
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(31), prefs(10)));
@@ -1442,7 +1868,11 @@ fn test_commission_paid_across_pages() {
 		bond_validator(11, balance);
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(commission), blocked: false }
+			ValidatorPrefs {
+				commission: Perbill::from_percent(commission),
+				blocked: false,
+				max_reward_per_era: None
+			}
 		));
 		assert_eq!(Validators::<T>::count(), 1);
 
@@ -1485,6 +1915,47 @@ fn test_commission_paid_across_pages() {
 	});
 }
 
+#[test]
+fn validator_total_commission_is_counted_once_per_page() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		// all of the reward is commission, so whatever 11 is paid is its commission.
+		bond_validator(11, 1);
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { commission: Perbill::one(), ..Default::default() }
+		));
+		for i in 0..200 {
+			bond_nominator(1000 + i, 1 + i as Balance, vec![11]);
+		}
+
+		Session::roll_until_active_era(2);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		assert_eq!(Eras::<T>::exposure_page_count(2, &11), 4);
+		Session::roll_until_active_era(3);
+
+		let mut paid = 0;
+		for page in [2, 0, 3, 1] {
+			let before = asset::stakeable_balance::<T>(&11);
+			assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 2, page));
+			paid += asset::stakeable_balance::<T>(&11) - before;
+			assert_eq!(ValidatorTotalCommission::<T>::get(11), paid);
+		}
+		assert_eq_error_rate!(paid, validator_payout_for(time_per_era()), 4);
+		assert_eq!(Staking::api_validator_total_commission(11), paid);
+
+		// claiming a page again does not count it twice.
+		let err_weight = <T as Config>::WeightInfo::payout_stakers_alive_staked(0);
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 2, 0),
+			Error::<T>::AlreadyClaimed.with_weight(err_weight)
+		);
+		assert_eq!(ValidatorTotalCommission::<T>::get(11), paid);
+
+		// nominators earn no commission.
+		assert_eq!(ValidatorTotalCommission::<T>::get(1000), 0);
+	});
+}
+
 #[test]
 fn payout_stakers_handles_weight_refund() {
 	// Note: this test relies on the assumption that `payout_stakers_alive_staked` is solely used by
@@ -1686,3 +2157,414 @@ fn test_runtime_api_pending_rewards() {
 		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), validator_two, 0));
 	});
 }
+
+#[test]
+fn test_runtime_api_estimate_validator_era_reward() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given
+		Pallet::<T>::reward_by_ids(vec![(11, 1), (21, 3)]);
+
+		// era reward not known yet.
+		assert_eq!(Staking::api_estimate_validator_era_reward(1, 11), None);
+
+		// when
+		Session::roll_until_active_era(2);
+		let era_payout = ErasValidatorReward::<T>::get(1).unwrap();
+
+		// then
+		assert_eq!(
+			Staking::api_estimate_validator_era_reward(1, 11),
+			Some(Perbill::from_rational(1u32, 4) * era_payout)
+		);
+		assert_eq!(
+			Staking::api_estimate_validator_era_reward(1, 21),
+			Some(Perbill::from_rational(3u32, 4) * era_payout)
+		);
+		// no points.
+		assert_eq!(Staking::api_estimate_validator_era_reward(1, 31), None);
+	});
+}
+
+#[test]
+fn test_runtime_api_estimate_nominator_apr() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given
+		Pallet::<T>::reward_by_ids(vec![(11, 1), (21, 1)]);
+
+		// no era has ended yet.
+		assert_eq!(Staking::api_estimate_nominator_apr(101), None);
+
+		// when
+		Session::roll_until_active_era(2);
+		assert_eq!(ErasDuration::<T>::get(1), Some(time_per_era()));
+
+		// pretend era 1 lasted a year, with a payout small enough not to saturate the estimate.
+		let year = 1000 * 3600 * 24 * 36525 / 100;
+		ErasDuration::<T>::insert(1, year);
+		ErasValidatorReward::<T>::insert(1, 400);
+
+		// then 101 gets its share of half of the payout of both of its validators.
+		let reward = [11, 21]
+			.into_iter()
+			.map(|v| {
+				let exposure = Staking::eras_stakers(1, &v);
+				Perbill::from_rational(exposure.others[0].value, exposure.total) *
+					(Perbill::from_percent(50) * 400)
+			})
+			.sum::<Balance>();
+		assert!(reward > 0);
+		assert_eq!(
+			Staking::api_estimate_nominator_apr(101),
+			Some(FixedU128::from_rational(reward, 500))
+		);
+
+		// the estimate is not capped: the same payout over a tenth of a year is ten times more.
+		ErasDuration::<T>::insert(1, year / 10);
+		assert_eq!(
+			Staking::api_estimate_nominator_apr(101),
+			Some(FixedU128::from_rational(reward * 10, 500))
+		);
+		assert!(Staking::api_estimate_nominator_apr(101).unwrap() > FixedU128::one());
+
+		// the exposure of the ended era is used, not the current nominations.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(
+			Staking::api_estimate_nominator_apr(101),
+			Some(FixedU128::from_rational(reward * 10, 500))
+		);
+
+		// validators and idle stashes are not exposed as nominators.
+		assert_eq!(Staking::api_estimate_nominator_apr(11), None);
+		assert_eq!(Staking::api_estimate_nominator_apr(41), None);
+	});
+}
+
+#[test]
+fn recent_rewards_are_kept_per_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		let mut paid = vec![];
+		for era in 1..=4 {
+			reward_all_elected();
+			Session::roll_until_active_era(era + 1);
+			let _ = staking_events_since_last_call();
+			make_all_reward_payment(era);
+
+			// 101 is paid by both of its validators, and these are summed up.
+			let rewards = staking_events_since_last_call()
+				.into_iter()
+				.filter_map(|e| match e {
+					Event::Rewarded { stash: 101, amount, .. } => Some(amount),
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(rewards.len(), 2);
+			paid.push((era, rewards.into_iter().sum::<Balance>()));
+		}
+
+		// only the last `MaxRecentRewards` eras are kept.
+		assert_eq!(MaxRecentRewards::get(), 3);
+		assert_eq!(RecentRewards::<T>::get(101).into_inner(), paid[1..].to_vec());
+		assert_eq!(Staking::api_recent_rewards(101, 10), vec![paid[3], paid[2], paid[1]]);
+		assert_eq!(Staking::api_recent_rewards(101, 1), vec![paid[3]]);
+		assert_eq!(Staking::api_recent_rewards(11, 10).len(), 3);
+		assert!(Staking::api_recent_rewards(41, 10).is_empty());
+
+		// eras past the history depth are no longer returned.
+		let history_depth = HistoryDepth::get();
+		HistoryDepth::set(1);
+		assert_eq!(Staking::api_recent_rewards(101, 10), vec![paid[3]]);
+		HistoryDepth::set(history_depth);
+	});
+}
+
+#[test]
+fn recent_rewards_are_not_kept_if_disabled() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaxRecentRewards::set(0);
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+		make_all_reward_payment(1);
+
+		assert!(!RecentRewards::<T>::contains_key(101));
+		assert!(Staking::api_recent_rewards(101, 10).is_empty());
+	});
+}
+
+#[test]
+fn auto_payout_on_idle_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given
+		AutoPayoutPerBlock::set(1);
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+		assert!(Eras::<T>::pending_rewards(1, &11));
+		assert!(Eras::<T>::pending_rewards(1, &21));
+		let now = System::block_number();
+
+		// when there is not enough weight, nothing is paid out.
+		assert_eq!(Staking::on_idle(now, Weight::zero()), Weight::zero());
+		assert!(Eras::<T>::pending_rewards(1, &11));
+		assert!(Eras::<T>::pending_rewards(1, &21));
+		assert_eq!(AutoPayoutCursor::<T>::get(), None);
+
+		// then one page is paid out per block.
+		let order = ErasStakersOverview::<T>::iter_key_prefix(1).collect::<Vec<_>>();
+		assert_eq!(order.len(), 2);
+
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(!Eras::<T>::pending_rewards(1, &order[0]));
+		assert!(Eras::<T>::pending_rewards(1, &order[1]));
+
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(!Eras::<T>::pending_rewards(1, &order[1]));
+		assert_eq!(AutoPayoutCursor::<T>::get(), Some((1, Some(order[0]))));
+
+		// the cursor moves past the last validator, and marks the era as done.
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert_eq!(AutoPayoutCursor::<T>::get(), Some((1, None)));
+		assert_eq!(
+			staking_events_since_last_call()
+				.into_iter()
+				.filter(|e| matches!(e, Event::PayoutStarted { .. }))
+				.count(),
+			2
+		);
+
+		// nothing left to do for this era.
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(staking_events_since_last_call().is_empty());
+	});
+}
+
+#[test]
+fn auto_payout_disabled_by_default() {
+	ExtBuilder::default().build_and_execute(|| {
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+
+		assert_eq!(Staking::on_idle(System::block_number(), Weight::MAX), Weight::zero());
+		assert!(Eras::<T>::pending_rewards(1, &11));
+		assert_eq!(AutoPayoutCursor::<T>::get(), None);
+	});
+}
+
+#[test]
+fn auto_payout_of_expiring_eras_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given era 1 rewards that no one claims.
+		HistoryDepth::set(2);
+		AutoPayoutExpiring::set(1);
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+		let now = System::block_number();
+
+		// while era 1 is not the oldest era that can be paid out, it is left alone.
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(Eras::<T>::pending_rewards(1, &11));
+		assert!(Eras::<T>::pending_rewards(1, &21));
+
+		// when era 1 can only be paid out until the next era is planned.
+		while CurrentEra::<T>::get() != Some(1 + HistoryDepth::get()) {
+			Session::roll_next();
+		}
+
+		// then one page is paid out per block.
+		let order = ErasStakersOverview::<T>::iter_key_prefix(1).collect::<Vec<_>>();
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(!Eras::<T>::pending_rewards(1, &order[0]));
+		assert!(Eras::<T>::pending_rewards(1, &order[1]));
+
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(!Eras::<T>::pending_rewards(1, &order[1]));
+		assert_eq!(AutoPayoutExpiringCursor::<T>::get(), Some((1, Some(order[0]))));
+
+		// and the regular auto payout is not involved.
+		assert_eq!(AutoPayoutCursor::<T>::get(), None);
+	});
+}
+
+#[test]
+fn auto_payout_of_expiring_eras_stops_at_the_history_depth() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given era 1 as the oldest era that can be paid out.
+		HistoryDepth::set(2);
+		AutoPayoutExpiring::set(1);
+		reward_all_elected();
+		while CurrentEra::<T>::get() != Some(1 + HistoryDepth::get()) {
+			Session::roll_next();
+		}
+		let now = System::block_number();
+		let order = ErasStakersOverview::<T>::iter_key_prefix(1).collect::<Vec<_>>();
+
+		// and only one of its pages is paid out in time.
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(!Eras::<T>::pending_rewards(1, &order[0]));
+
+		// when the next era is planned.
+		while CurrentEra::<T>::get() != Some(2 + HistoryDepth::get()) {
+			Session::roll_next();
+		}
+
+		let first = ErasStakersOverview::<T>::iter_key_prefix(2).next().unwrap();
+		assert!(Eras::<T>::pending_rewards(2, &first));
+
+		// then era 1 can no longer be paid out, and auto payout moves on to era 2.
+		let _ = Staking::on_idle(now, Weight::MAX);
+		assert!(Eras::<T>::pending_rewards(1, &order[1]));
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), order[1], 1, 0),
+			Error::<T>::InvalidEraToReward
+				.with_weight(<T as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+		assert!(!Eras::<T>::pending_rewards(2, &first));
+	});
+}
+
+#[test]
+fn reward_points_carry_over_into_next_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		RewardPointsCarryOver::set(Perbill::from_percent(50));
+		assert_eq_uvec!(Session::validators(), vec![11, 21]);
+
+		// 41 is not exposed in the next era.
+		Eras::<T>::reward_active_era(vec![(11, 100), (21, 60), (41, 10)]);
+		Session::roll_until_active_era(2);
+		assert_eq!(
+			ErasRewardPoints::<T>::get(2),
+			EraRewardPoints { total: 80, individual: bounded_btree_map![11 => 50, 21 => 30] }
+		);
+		// the ending era is left intact.
+		assert_eq!(ErasRewardPoints::<T>::get(1).total, 170);
+
+		// points earned on top of the carried ones are carried over again.
+		Eras::<T>::reward_active_era(vec![(11, 10)]);
+		Session::roll_until_active_era(3);
+		assert_eq!(
+			ErasRewardPoints::<T>::get(3),
+			EraRewardPoints { total: 45, individual: bounded_btree_map![11 => 30, 21 => 15] }
+		);
+	});
+}
+
+#[test]
+fn reward_points_not_carried_over_by_default() {
+	ExtBuilder::default().build_and_execute(|| {
+		Eras::<T>::reward_active_era(vec![(11, 100), (21, 60)]);
+		Session::roll_until_active_era(2);
+		assert_eq!(ErasRewardPoints::<T>::get(2), Default::default());
+	});
+}
+
+#[test]
+fn dry_run_payout_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		reward_all_elected();
+
+		// the era reward is not known yet.
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 0), PayoutDryRun::default());
+
+		Session::roll_until_active_era(2);
+		let expected = PayoutDryRun { nominator_payouts: 1, newly_claimed: true };
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 0), expected);
+		// it does not mutate anything.
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 0), expected);
+		assert!(ClaimedRewards::<T>::get(1, 11).is_empty());
+
+		// non-existent page, or not a validator.
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 1), PayoutDryRun::default());
+		assert_eq!(Staking::api_dry_run_payout(1, 41, 0), PayoutDryRun::default());
+
+		// nominators without a reward destination are not paid out.
+		Payee::<T>::insert(101, RewardDestination::None);
+		assert_eq!(
+			Staking::api_dry_run_payout(1, 21, 0),
+			PayoutDryRun { nominator_payouts: 0, newly_claimed: true }
+		);
+
+		// once paid out, nothing is left to do.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 0), PayoutDryRun::default());
+	});
+}
+
+#[test]
+fn dry_run_payout_mirrors_reward_checks() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 earns fewer points than required for a reward.
+		MinEraPointsForReward::set(5);
+		Eras::<T>::reward_active_era(vec![(11, 1), (21, 10)]);
+		Session::roll_until_active_era(2);
+
+		// its page is claimed, without paying out any nominator.
+		let claimed_only = PayoutDryRun { nominator_payouts: 0, newly_claimed: true };
+		assert_eq!(Staking::api_dry_run_payout(1, 11, 0), claimed_only);
+		assert_eq!(
+			Staking::api_dry_run_payout(1, 21, 0),
+			PayoutDryRun { nominator_payouts: 1, newly_claimed: true }
+		);
+
+		// era 2 has no reward and is skipped: nothing can be paid out, even if the pages were not
+		// marked as claimed.
+		SkipZeroRewardEras::set(true);
+		MaxStakedRewards::<T>::put(Percent::zero());
+		Eras::<T>::reward_active_era(vec![(11, 10), (21, 10)]);
+		Session::roll_until_active_era(3);
+		ClaimedRewards::<T>::remove(2, 11);
+		assert_eq!(Staking::api_dry_run_payout(2, 11, 0), PayoutDryRun::default());
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 2, 0),
+			Error::<T>::NothingToPayout
+				.with_weight(<T as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+	});
+}
+
+#[test]
+fn zero_reward_eras_are_paid_out_by_default() {
+	ExtBuilder::default().build_and_execute(|| {
+		// nothing goes to stakers in era 1.
+		MaxStakedRewards::<T>::put(Percent::zero());
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+		assert_eq!(ErasValidatorReward::<T>::get(1), Some(0));
+		assert!(ClaimedRewards::<T>::get(1, 11).is_empty());
+
+		// the page is claimed, even though nothing is transferred.
+		let balance = asset::stakeable_balance::<T>(&11);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(ClaimedRewards::<T>::get(1, 11), vec![0]);
+		assert_eq!(asset::stakeable_balance::<T>(&11), balance);
+	});
+}
+
+#[test]
+fn zero_reward_eras_can_be_skipped() {
+	ExtBuilder::default().build_and_execute(|| {
+		SkipZeroRewardEras::set(true);
+		MaxStakedRewards::<T>::put(Percent::zero());
+		reward_all_elected();
+		Session::roll_until_active_era(2);
+
+		// all pages of era 1 are marked claimed as it ends.
+		assert_eq!(ErasValidatorReward::<T>::get(1), Some(0));
+		assert_eq!(ClaimedRewards::<T>::get(1, 11), vec![0]);
+		assert_eq!(ClaimedRewards::<T>::get(1, 21), vec![0]);
+
+		let err_weight = <T as Config>::WeightInfo::payout_stakers_alive_staked(0);
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<T>::NothingToPayout.with_weight(err_weight)
+		);
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 21, 1, 0),
+			Error::<T>::NothingToPayout.with_weight(err_weight)
+		);
+
+		// eras with a reward are not affected.
+		MaxStakedRewards::<T>::kill();
+		reward_all_elected();
+		Session::roll_until_active_era(3);
+		assert!(ClaimedRewards::<T>::get(2, 11).is_empty());
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 2));
+	});
+}
@@ -17,6 +17,7 @@
 
 use super::*;
 use crate::{session_rotation::Eras, slashing};
+use frame_election_provider_support::ElectionDataProvider;
 use pallet_staking_async_rc_client as rc_client;
 use sp_runtime::{Perquintill, Rounding};
 use sp_staking::StakingInterface;
@@ -187,9 +188,9 @@ fn add_slash_works() {
 }
 
 #[test]
-fn only_first_reporter_receive_the_slice() {
-	// This test verifies that the first reporter of the offence receive their slice from the
-	// slashed amount.
+fn unique_reporters_share_the_slice() {
+	// This test verifies that every unique reporter of the offence receives an equal slice of the
+	// reward, no matter how many times they reported it.
 	ExtBuilder::default().nominate(false).build_and_execute(|| {
 		// The reporters' reward is calculated from the total exposure.
 		assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1000);
@@ -201,10 +202,12 @@ fn only_first_reporter_receive_the_slice() {
 			session_mock::Session::current_index(),
 			vec![rc_client::Offence {
 				offender: 11,
-				reporters: vec![1, 2],
+				reporters: vec![1, 2, 1, 1, 2],
 				slash_fraction: Perbill::from_percent(50),
 			}],
 		);
+		assert_eq!(OffenceQueue::<T>::get(1, 11).unwrap().reporters.into_inner(), vec![1, 2]);
+
 		Session::roll_next();
 		assert_eq!(
 			staking_events_since_last_call(),
@@ -219,10 +222,39 @@ fn only_first_reporter_receive_the_slice() {
 			]
 		);
 
+		// each reporter is paid once.
 		let reward = 500 / 10;
-		assert_eq!(asset::total_balance::<T>(&1), initial_balance_1 + reward);
-		// second reporter got nothing
-		assert_eq!(asset::total_balance::<T>(&2), initial_balance_2);
+		assert_eq!(asset::total_balance::<T>(&1), initial_balance_1 + reward / 2);
+		assert_eq!(asset::total_balance::<T>(&2), initial_balance_2 + reward / 2);
+	});
+}
+
+#[test]
+fn only_first_max_reporters_receive_the_slice() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		MaxReporters::set(2);
+		let initial_balances = [1, 2, 3].map(|who| asset::total_balance::<T>(&who));
+
+		<Staking as rc_client::AHStakingInterface>::on_new_offences(
+			session_mock::Session::current_index(),
+			vec![rc_client::Offence {
+				offender: 11,
+				reporters: vec![1, 1, 2, 3],
+				slash_fraction: Perbill::from_percent(50),
+			}],
+		);
+		Session::roll_next();
+
+		// the first two unique reporters split the reward, the third gets nothing.
+		let reward = 500 / 10;
+		assert_eq!(
+			[1, 2, 3].map(|who| asset::total_balance::<T>(&who)),
+			[
+				initial_balances[0] + reward / 2,
+				initial_balances[1] + reward / 2,
+				initial_balances[2]
+			]
+		);
 	});
 }
 
@@ -954,7 +986,12 @@ fn staker_cannot_bail_deferred_slash() {
 					active: 0,
 					total: 500,
 					stash: 101,
-					unlocking: bounded_vec![UnlockChunk { era: 4u32, value: 500 }],
+					unlocking: bounded_vec![UnlockChunk {
+						era: 4u32,
+						value: 500,
+						dest: None,
+						withdrawable_era: None
+					}],
 				}
 			);
 
@@ -1049,7 +1086,7 @@ fn remove_deferred() {
 						validator: 11,
 						own: 100,
 						others: bounded_vec![(101, 25)],
-						reporter: None,
+						reporters: Default::default(),
 						// 10% of the slash
 						payout: (100 + 25) / 10
 					}
@@ -1060,7 +1097,7 @@ fn remove_deferred() {
 						validator: 11,
 						own: 50,
 						others: bounded_vec![(101, 12)],
-						reporter: None,
+						reporters: Default::default(),
 						// 10% of the slash
 						payout: (50 + 12) / 10
 					}
@@ -1300,7 +1337,12 @@ fn cancel_all_slashes_with_100_percent() {
 #[test]
 fn proportional_slash_stop_slashing_if_remaining_zero() {
 	ExtBuilder::default().nominate(true).build_and_execute(|| {
-		let c = |era, value| UnlockChunk::<Balance> { era, value };
+		let c = |era, value| UnlockChunk::<Balance, AccountId> {
+			era,
+			value,
+			dest: None,
+			withdrawable_era: None,
+		};
 
 		// we have some chunks, but they are not affected.
 		let unlocking = bounded_vec![c(1, 10), c(2, 10)];
@@ -1321,7 +1363,12 @@ fn proportional_slash_stop_slashing_if_remaining_zero() {
 #[test]
 fn proportional_ledger_slash_works() {
 	ExtBuilder::default().nominate(true).build_and_execute(|| {
-		let c = |era, value| UnlockChunk::<Balance> { era, value };
+		let c = |era, value| UnlockChunk::<Balance, AccountId> {
+			era,
+			value,
+			dest: None,
+			withdrawable_era: None,
+		};
 		// Given
 		let mut ledger = StakingLedger::<T>::new(123, 10);
 		assert_eq!(BondingDuration::get(), 3);
@@ -1600,10 +1647,10 @@ fn withdrawals_are_blocked_for_unprocessed_and_unapplied_slashes() {
 			assert_eq!(active_era(), 6);
 
 			// Ensure unbonding chunks can all be withdrawn by era 6.
-			let expected_chunks: BoundedVec<UnlockChunk<Balance>, MaxUnlockingChunks> = bounded_vec![
+			let expected_chunks: BoundedVec<UnlockChunk<Balance, AccountId>, MaxUnlockingChunks> = bounded_vec![
 				// era is unbond_era + bonding_duration, starting from era 2 + 3.
-				UnlockChunk { era: 5, value: 100 },
-				UnlockChunk { era: 6, value: 150 },
+				UnlockChunk { era: 5, value: 100, dest: None, withdrawable_era: None },
+				UnlockChunk { era: 6, value: 150, dest: None, withdrawable_era: None },
 			];
 			assert_eq!(Ledger::<T>::get(nominator).unwrap().unlocking, expected_chunks);
 
@@ -1738,7 +1785,7 @@ mod paged_slashing {
 				assert_eq!(
 					OffenceQueue::<T>::get(2, 11).unwrap(),
 					slashing::OffenceRecord {
-						reporter: None,
+						reporters: Default::default(),
 						reported_era: 2,
 						// first page to be marked for processing.
 						exposure_page: expected_page_count - 1,
@@ -1784,7 +1831,7 @@ mod paged_slashing {
 						2,
 						11,
 						OffenceRecord {
-							reporter: None,
+							reporters: Default::default(),
 							reported_era: 2,
 							// page 3 is processed, next page to be processed is 2.
 							exposure_page: 2,
@@ -1825,7 +1872,7 @@ mod paged_slashing {
 						2,
 						11,
 						OffenceRecord {
-							reporter: None,
+							reporters: Default::default(),
 							reported_era: 2,
 							// page 2 is processed, next page to be processed is 1.
 							exposure_page: 1,
@@ -2124,3 +2171,239 @@ mod paged_slashing {
 		});
 	}
 }
+
+#[test]
+fn simulate_slash_accounts_for_prior_slash_in_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		let era = active_era();
+		let exposure = Staking::eras_stakers(era, &11);
+		let slash_of =
+			|p: Perbill| exposure.others.iter().fold(p * exposure.own, |acc, n| acc + p * n.value);
+
+		// no prior slash, the full slash is projected.
+		assert_eq!(
+			slashing::simulate_slash::<T>(era, &11, Perbill::from_percent(10)),
+			slash_of(Perbill::from_percent(10))
+		);
+
+		// when
+		add_slash_in_era(11, era, Perbill::from_percent(10));
+		assert_eq!(
+			ValidatorSlashInEra::<T>::get(era, &11).map(|(f, _)| f),
+			Some(Perbill::from_percent(10))
+		);
+
+		// then a smaller or equal slash in the same era projects nothing.
+		assert_eq!(slashing::simulate_slash::<T>(era, &11, Perbill::from_percent(5)), 0);
+		assert_eq!(slashing::simulate_slash::<T>(era, &11, Perbill::from_percent(10)), 0);
+
+		// and a bigger one only projects the marginal increase.
+		assert_eq!(
+			slashing::simulate_slash::<T>(era, &11, Perbill::from_percent(20)),
+			slash_of(Perbill::from_percent(20)) - slash_of(Perbill::from_percent(10))
+		);
+	});
+}
+
+#[test]
+fn post_slash_dust_threshold_chills_below() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given thresholds just above what 11 and 101 keep after a 10% slash.
+		ValidatorDustThreshold::set(901);
+		NominatorDustThreshold::set(476);
+
+		// when
+		add_slash(11);
+		Session::roll_next();
+
+		// then both are chilled.
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 900);
+		assert_eq!(Staking::ledger(101.into()).unwrap().active, 475);
+		assert!(!Validators::<Test>::contains_key(11));
+		assert!(!Nominators::<Test>::contains_key(101));
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::Chilled { stash: 11 }));
+		assert!(events.contains(&Event::Chilled { stash: 101 }));
+	});
+}
+
+#[test]
+fn post_slash_dust_threshold_keeps_above() {
+	ExtBuilder::default().build_and_execute(|| {
+		// given thresholds exactly at what 11 and 101 keep after a 10% slash.
+		ValidatorDustThreshold::set(900);
+		NominatorDustThreshold::set(475);
+
+		// when
+		add_slash(11);
+		Session::roll_next();
+
+		// then neither is chilled, even though the validator one is above the nominator's.
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 900);
+		assert_eq!(Staking::ledger(101.into()).unwrap().active, 475);
+		assert!(Validators::<Test>::contains_key(11));
+		assert!(Nominators::<Test>::contains_key(101));
+		assert!(!staking_events_since_last_call()
+			.iter()
+			.any(|e| matches!(e, Event::Chilled { .. })));
+	});
+}
+
+#[test]
+fn slashed_nominations_are_suppressed_until_renominated() {
+	ExtBuilder::default().build_and_execute(|| {
+		SuppressSlashedNominations::set(true);
+		let voters = || {
+			<Staking as ElectionDataProvider>::electing_voters(DataProviderBounds::default(), 0)
+				.unwrap()
+				.into_iter()
+				.map(|(who, _, _)| who)
+				.collect::<Vec<_>>()
+		};
+		let backs = |era: EraIndex, who: AccountId| {
+			era_exposures(era).iter().any(|(_, e)| e.others.iter().any(|i| i.who == who))
+		};
+		assert!(voters().contains(&101));
+
+		// when 101 is slashed through 11.
+		add_slash(11);
+		Session::roll_next();
+
+		// then its nominations are suppressed and left out of the next election.
+		assert!(Nominators::<Test>::get(101).unwrap().suppressed);
+		assert!(!voters().contains(&101));
+		Session::roll_until_active_era(active_era() + 1);
+		assert!(!backs(active_era(), 101));
+
+		// until it nominates again.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11, 21]));
+		assert!(!Nominators::<Test>::get(101).unwrap().suppressed);
+		assert!(voters().contains(&101));
+		Session::roll_until_active_era(active_era() + 1);
+		assert!(backs(active_era(), 101));
+	});
+}
+
+#[test]
+fn slashed_nominations_are_not_suppressed_by_default() {
+	ExtBuilder::default().build_and_execute(|| {
+		add_slash(11);
+		Session::roll_next();
+
+		assert!(!Nominators::<Test>::get(101).unwrap().suppressed);
+		Session::roll_until_active_era(active_era() + 1);
+		assert!(era_exposures(active_era())
+			.iter()
+			.any(|(_, e)| e.others.iter().any(|i| i.who == 101)));
+	});
+}
+
+#[test]
+fn slash_reward_is_clamped_to_max_slash_reward() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		// given a cap well below 10% of a full slash of 11 and 101.
+		MaxSlashReward::set(50);
+
+		// when
+		add_slash_with_percent(11, 100);
+		Session::roll_next();
+
+		// then
+		assert_eq!(
+			UnappliedSlashes::<T>::iter_prefix(&3).collect::<Vec<_>>(),
+			vec![(
+				(11, Perbill::from_percent(100), 0),
+				UnappliedSlash {
+					validator: 11,
+					own: 1000,
+					others: bounded_vec![(101, 250)],
+					reporters: Default::default(),
+					// 10% of the slash would be 125.
+					payout: 50
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn max_slash_reward_clamps_all_pages_of_an_offender_together() {
+	ExtBuilder::default()
+		.has_stakers(false)
+		.slash_defer_duration(3)
+		.build_and_execute(|| {
+			// given 11 with 200 nominators, i.e. 4 exposure pages of 64.
+			bond_validator(11, 1000);
+			for i in 0..200 {
+				bond_nominator(1000 + i, 1000 + i as Balance, vec![11]);
+			}
+			Session::roll_until_active_era(2);
+			assert_eq!(Eras::<T>::exposure_page_count(2, &11), 4);
+
+			// and a cap above the reward of any single page, but well below their sum.
+			MaxSlashReward::set(1000);
+
+			// when all pages of the offence are computed.
+			add_slash(11);
+			for _ in 0..4 {
+				Session::roll_next();
+			}
+			assert!(ProcessingOffence::<T>::get().is_none());
+
+			// then the rewards of all pages sum up to the cap.
+			let payouts = UnappliedSlashes::<T>::iter_prefix(&5)
+				.map(|((_, _, page), slash)| (page, slash.payout))
+				.collect::<BTreeMap<_, _>>();
+			assert_eq!(payouts.len(), 4);
+			assert!(payouts.values().all(|payout| *payout < 1000));
+			assert_eq!(payouts.values().sum::<Balance>(), 1000);
+			// the first page, computed last, gets nothing.
+			assert_eq!(payouts[&0], 0);
+			assert_eq!(ErasSlashReward::<T>::get(2, 11), 1000);
+
+			// a higher slash in the same era gets no more reward either.
+			add_slash_with_percent(11, 20);
+			for _ in 0..4 {
+				Session::roll_next();
+			}
+			let payouts = UnappliedSlashes::<T>::iter_prefix(&5)
+				.filter(|((_, fraction, _), _)| *fraction == Perbill::from_percent(20))
+				.map(|(_, slash)| slash.payout)
+				.collect::<Vec<_>>();
+			assert_eq!(payouts, vec![0; 4]);
+			assert_eq!(ErasSlashReward::<T>::get(2, 11), 1000);
+		});
+}
+
+#[test]
+fn deferred_slashes_are_applied_in_batches() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		// given three slashes deferred to era 3, more than we can apply in one block.
+		MaxSlashesPerBlock::set(2);
+		add_slash(11);
+		add_slash(21);
+		Session::roll_until_active_era(2);
+		add_slash_in_era(11, 1, Perbill::from_percent(15));
+		Session::roll_next();
+		assert_eq!(era_unapplied_slash_count(3), 3);
+
+		// when era 3 starts, nothing is applied until the next block.
+		Session::roll_until_active_era(3);
+		assert_eq!(era_unapplied_slash_count(3), 3);
+		let _ = staking_events_since_last_call();
+
+		// then two slashes are applied in the first block.
+		Session::roll_next();
+		assert_eq!(era_unapplied_slash_count(3), 1);
+		let slashed = |events: Vec<Event<T>>| {
+			events.into_iter().filter(|e| matches!(e, Event::Slashed { .. })).count()
+		};
+		// each slash hits the validator and its nominator.
+		assert_eq!(slashed(staking_events_since_last_call()), 4);
+
+		// and the last one in the next block.
+		Session::roll_next();
+		assert_eq!(era_unapplied_slash_count(3), 0);
+		assert_eq!(slashed(staking_events_since_last_call()), 2);
+	});
+}
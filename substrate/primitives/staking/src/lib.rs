@@ -62,7 +62,7 @@ impl<AccountId> From<AccountId> for StakingAccount<AccountId> {
 }
 
 /// Representation of the status of a staker.
-#[derive(RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone))]
 pub enum StakerStatus<AccountId> {
 	/// Chilling.
@@ -73,6 +73,121 @@ pub enum StakerStatus<AccountId> {
 	Nominator(Vec<AccountId>),
 }
 
+/// The reason a stash cannot nominate a set of targets.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum NominateError {
+	/// The account is not bonded as a stash.
+	NotStash,
+	/// The active bond is below the minimum nominator bond.
+	InsufficientBond,
+	/// The maximum number of nominators has been reached.
+	TooManyNominators,
+	/// No targets were given.
+	EmptyTargets,
+	/// More targets were given than the nominations quota of the stash allows.
+	TooManyTargets,
+	/// A target is not a validator, or is blocked and not already nominated.
+	BadTarget,
+	/// A target is not already nominated, and has the maximum number of nominators.
+	ValidatorFull,
+	/// The stash is not a nominator yet, and changed its role too recently.
+	RoleChangeTooSoon,
+}
+
+/// The phase of an ongoing (multi-block) election, as reported to off-chain clients.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ElectionPhase<BlockNumber> {
+	/// No election is ongoing.
+	Off,
+	/// The snapshot is being created, with the given number of pages left to fetch.
+	Snapshot { remaining_pages: u32 },
+	/// Signed solutions can be submitted.
+	Signed { remaining_blocks: BlockNumber },
+	/// Signed solutions are being verified.
+	SignedValidation { remaining_blocks: BlockNumber },
+	/// Unsigned solutions can be submitted.
+	Unsigned { remaining_blocks: BlockNumber },
+	/// The election is done, waiting to be exported.
+	Done,
+	/// The result is being exported.
+	Export,
+	/// The election failed, and only governance can resolve it.
+	Emergency,
+}
+
+/// Where the rewards of an era went, as reported to off-chain clients.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	Default,
+)]
+pub struct RewardBreakdown<Balance> {
+	/// Rewards paid out, or still claimable by, validators and nominators.
+	pub stakers: Balance,
+	/// Rewards sent to the reward remainder, including anything above a staker reward cap.
+	pub remainder: Balance,
+	/// Staker rewards that were never minted, e.g. since the staker has no reward destination.
+	pub burned: Balance,
+}
+
+/// The effects that paying out a page of an era's rewards would have, as reported to off-chain
+/// clients.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	Default,
+)]
+pub struct PayoutDryRun {
+	/// The number of nominators of the page that would receive a reward transfer.
+	pub nominator_payouts: u32,
+	/// Whether the page would be newly marked as claimed, i.e. whether the payout would succeed.
+	pub newly_claimed: bool,
+}
+
+/// A slash that is queued to be applied in a later era, as reported to off-chain clients.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UnappliedSlash<AccountId, Balance> {
+	/// The stash of the offending validator.
+	pub validator: AccountId,
+	/// The validator's own slash.
+	pub own: Balance,
+	/// All other slashed stakers and amounts.
+	pub others: Vec<(AccountId, Balance)>,
+	/// Unique reporters of the offence.
+	pub reporters: Vec<AccountId>,
+	/// The amount paid out to the reporters.
+	pub payout: Balance,
+}
+
+/// Where the rewards of a staker are paid, as reported to off-chain clients.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RewardDestination<AccountId> {
+	/// Paid into the stash account, increasing the amount at stake.
+	Staked,
+	/// Paid into the stash account, not increasing the amount at stake.
+	Stash,
+	/// Paid into the given account.
+	Account(AccountId),
+	/// Not paid at all.
+	None,
+}
+
 /// A struct that reflects stake that an account has in the staking system. Provides a set of
 /// methods to operate on it's properties. Aimed at making `StakingInterface` more concise.
 #[derive(RuntimeDebug, Clone, Copy, Eq, PartialEq, Default)]
@@ -86,6 +86,16 @@ pub trait Offence<Offender> {
 	fn slash_fraction(&self, offenders_count: u32) -> Perbill;
 }
 
+/// The slash fraction of an equivocation, i.e. `min((3k / n)^2, 1)`, where `k` is the number of
+/// `offenders_count` in the same time slot and `n` is `validator_set_count`.
+///
+/// A single equivocation is thus slashed lightly, while a third of the validator set equivocating
+/// together is slashed fully.
+pub fn equivocation_slash_fraction(offenders_count: u32, validator_set_count: u32) -> Perbill {
+	// Perbill type domain is [0, 1] by definition
+	Perbill::from_rational(offenders_count.saturating_mul(3), validator_set_count).square()
+}
+
 /// Errors that may happen on offence reports.
 #[derive(PartialEq, sp_runtime::RuntimeDebug)]
 pub enum OffenceError {
@@ -295,3 +305,26 @@ impl Ord for OffenceSeverity {
 		self.0.cmp(&other.0)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn equivocation_slash_fraction_works() {
+		let fraction = |k, n| equivocation_slash_fraction(k, n);
+
+		// a single offender.
+		assert_eq!(fraction(1, 10), Perbill::from_percent(9));
+		assert_eq!(fraction(1, 100), Perbill::from_parts(900_000));
+		assert_eq!(fraction(1, 1000), Perbill::from_parts(9_000));
+
+		// it scales with the number of offenders.
+		assert_eq!(fraction(10, 300), Perbill::from_percent(1));
+		assert_eq!(fraction(50, 300), Perbill::from_percent(25));
+
+		// and saturates at a third of the validator set.
+		assert_eq!(fraction(100, 300), Perbill::one());
+		assert_eq!(fraction(200, 300), Perbill::one());
+	}
+}